@@ -0,0 +1,355 @@
+use crate::parse_case;
+use convert_case::{Case, Casing};
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse_macro_input, Data, DeriveInput, Lit, Meta, NestedMeta};
+
+/// Options given through `#[attribute_value(...)]` on the enum itself.
+#[derive(Default)]
+struct EnumOptions {
+    case: Option<Case>,
+    case_sensitive: bool,
+}
+
+/// Options given through `#[attribute_value(...)]` on a variant.
+#[derive(Default)]
+struct VariantOptions {
+    rename: Option<String>,
+    custom: bool,
+}
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let DeriveInput {
+        ident, data, attrs, ..
+    } = input;
+
+    let options = enum_options(&attrs)?;
+    let variants = match data {
+        Data::Enum(data) => data.variants,
+        Data::Struct(data) => {
+            return Err(syn::Error::new_spanned(
+                data.struct_token,
+                "AttributeValue can only be derived for enums",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "AttributeValue can only be derived for enums",
+            ))
+        }
+    };
+
+    let mut keywords = Vec::new();
+    let mut custom = None;
+    for variant in variants {
+        let variant_options = variant_options(&variant.attrs)?;
+        let variant_ident = variant.ident;
+
+        if variant_options.custom {
+            match &variant.fields {
+                syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => (),
+                fields => {
+                    return Err(syn::Error::new_spanned(
+                        &variant_ident,
+                        match fields {
+                            syn::Fields::Unit => "the custom variant must hold a single String",
+                            _ => {
+                                "the custom variant must be a tuple variant holding a single String"
+                            }
+                        },
+                    ))
+                }
+            }
+            if custom.is_some() {
+                return Err(syn::Error::new_spanned(
+                    variant_ident,
+                    "only one variant can be marked as custom",
+                ));
+            }
+            custom = Some(variant_ident);
+            continue;
+        }
+
+        if !matches!(variant.fields, syn::Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant.fields,
+                "only the variant marked `#[attribute_value(custom)]` can hold a value",
+            ));
+        }
+
+        let keyword = match (variant_options.rename, options.case) {
+            (Some(rename), _) => rename,
+            (None, Some(case)) => variant_ident.to_string().to_case(case),
+            (None, None) => variant_ident.to_string(),
+        };
+        keywords.push((variant_ident, keyword));
+    }
+
+    let clone_arms = keywords.iter().map(|(variant, _)| {
+        quote! { #ident::#variant => #ident::#variant }
+    });
+    let custom_clone_arm = custom.as_ref().map(|variant| {
+        quote! { #ident::#variant(value) => #ident::#variant(::std::clone::Clone::clone(value)) }
+    });
+    let matches = if options.case_sensitive {
+        quote! { keyword == s }
+    } else {
+        quote! { keyword.eq_ignore_ascii_case(s) }
+    };
+
+    // A custom value spelling a keyword renders the same as that keyword, so the two
+    // are compared and hashed by the keyword.
+    let eq_and_hash = match &custom {
+        Some(variant) => {
+            let keyword_arms = keywords.iter().map(|(_, keyword)| {
+                quote! {
+                    let keyword = #keyword;
+                    if #matches {
+                        return keyword;
+                    }
+                }
+            });
+            let canonical = quote! {
+                #[allow(unused_variables)]
+                fn canonical(value: &#ident) -> &str {
+                    if let #ident::#variant(s) = value {
+                        #(#keyword_arms)*
+                    }
+                    ::std::convert::AsRef::<str>::as_ref(value)
+                }
+            };
+
+            quote! {
+                impl ::std::cmp::PartialEq for #ident {
+                    fn eq(&self, other: &Self) -> bool {
+                        #canonical
+                        canonical(self) == canonical(other)
+                    }
+                }
+
+                impl ::std::hash::Hash for #ident {
+                    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                        #canonical
+                        ::std::hash::Hash::hash(canonical(self), state);
+                    }
+                }
+            }
+        }
+        None => {
+            let eq_arms = keywords.iter().map(|(variant, _)| {
+                quote! { (#ident::#variant, #ident::#variant) => true }
+            });
+
+            quote! {
+                impl ::std::cmp::PartialEq for #ident {
+                    #[allow(unreachable_patterns)]
+                    fn eq(&self, other: &Self) -> bool {
+                        match (self, other) {
+                            #(#eq_arms,)*
+                            _ => false,
+                        }
+                    }
+                }
+
+                impl ::std::hash::Hash for #ident {
+                    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                        ::std::hash::Hash::hash(&::std::mem::discriminant(self), state);
+                    }
+                }
+            }
+        }
+    };
+
+    let as_ref_arms = keywords.iter().map(|(variant, keyword)| {
+        quote! { #ident::#variant => #keyword }
+    });
+    let custom_as_ref_arm = custom.as_ref().map(|variant| {
+        quote! { #ident::#variant(value) => value.as_str() }
+    });
+
+    let from_str_arms = keywords.iter().map(|(variant, keyword)| {
+        quote! {
+            let keyword = #keyword;
+            if #matches {
+                return ::std::result::Result::Ok(#ident::#variant);
+            }
+        }
+    });
+    let fallback = match &custom {
+        Some(variant) => quote! {
+            if s.is_empty() {
+                return ::std::result::Result::Err(::domatt::values::ValueError::new(s, "Custom values may not be empty."));
+            }
+            ::std::result::Result::Ok(#ident::#variant(::std::borrow::ToOwned::to_owned(s)))
+        },
+        None => quote! {
+            ::std::result::Result::Err(::domatt::values::ValueError::new(s, "Invalid keyword."))
+        },
+    };
+
+    // Without a custom variant every value is a keyword, so it can be borrowed for the
+    // lifetime of the program.
+    let static_str = if custom.is_none() {
+        let static_arms = keywords.iter().map(|(variant, keyword)| {
+            quote! { #ident::#variant => #keyword }
+        });
+
+        quote! {
+            impl ::std::convert::From<#ident> for &'static str {
+                fn from(value: #ident) -> Self {
+                    match value {
+                        #(#static_arms,)*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        impl ::std::clone::Clone for #ident {
+            fn clone(&self) -> Self {
+                match self {
+                    #(#clone_arms,)*
+                    #custom_clone_arm
+                }
+            }
+        }
+
+        #eq_and_hash
+
+        impl ::std::cmp::Eq for #ident {}
+
+        impl ::std::convert::AsRef<str> for #ident {
+            fn as_ref(&self) -> &str {
+                match self {
+                    #(#as_ref_arms,)*
+                    #custom_as_ref_arm
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(::std::convert::AsRef::<str>::as_ref(self))
+            }
+        }
+
+        impl ::std::str::FromStr for #ident {
+            type Err = ::domatt::values::ValueError;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                #(#from_str_arms)*
+                #fallback
+            }
+        }
+
+        impl ::domatt::values::ToAttrValue for #ident {
+            fn to_attr_value(&self) -> ::std::string::String {
+                ::std::string::ToString::to_string(::std::convert::AsRef::<str>::as_ref(self))
+            }
+        }
+
+        impl ::domatt::values::FromAttrValue for #ident {
+            fn from_attr_value(value: &str) -> ::std::result::Result<Self, ::domatt::values::ValueError> {
+                ::std::str::FromStr::from_str(value)
+            }
+        }
+
+        #static_str
+    })
+}
+
+fn enum_options(attrs: &[syn::Attribute]) -> syn::Result<EnumOptions> {
+    let mut options = EnumOptions::default();
+    for meta in attribute_value_metas(attrs)? {
+        match meta {
+            Meta::NameValue(name_value) if name_value.path.is_ident("case") => {
+                let case = match name_value.lit {
+                    Lit::Str(case) => case,
+                    lit => return Err(syn::Error::new_spanned(lit, "`case` must be a string")),
+                };
+                options.case = Some(parse_case(&case.value()).ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        case,
+                        "unknown case, expected \"camelCase\", \"kebab-case\" or \"lowercase\"",
+                    )
+                })?);
+            }
+            Meta::Path(path) if path.is_ident("case_sensitive") => {
+                options.case_sensitive = true;
+            }
+            meta => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    "unknown option, expected `case = \"...\"` or `case_sensitive`",
+                ))
+            }
+        }
+    }
+
+    Ok(options)
+}
+
+fn variant_options(attrs: &[syn::Attribute]) -> syn::Result<VariantOptions> {
+    let mut options = VariantOptions::default();
+    for meta in attribute_value_metas(attrs)? {
+        match meta {
+            Meta::NameValue(name_value) if name_value.path.is_ident("rename") => {
+                options.rename = match name_value.lit {
+                    Lit::Str(rename) => Some(rename.value()),
+                    lit => return Err(syn::Error::new_spanned(lit, "`rename` must be a string")),
+                };
+            }
+            Meta::Path(path) if path.is_ident("custom") => options.custom = true,
+            meta => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    "unknown option, expected `rename = \"...\"` or `custom`",
+                ))
+            }
+        }
+    }
+
+    Ok(options)
+}
+
+fn attribute_value_metas(attrs: &[syn::Attribute]) -> syn::Result<Vec<Meta>> {
+    let mut metas = Vec::new();
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("attribute_value"))
+    {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    "expected `#[attribute_value(...)]`",
+                ))
+            }
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(meta) => metas.push(meta),
+                NestedMeta::Lit(lit) => {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "unexpected literal in `#[attribute_value(...)]`",
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(metas)
+}
@@ -0,0 +1,118 @@
+use crate::option_argument;
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse_macro_input, Data, DeriveInput, Meta, NestedMeta};
+
+/// Options given through `#[attr(...)]` on a field.
+#[derive(Default)]
+struct FieldOptions {
+    skip: bool,
+    flatten: bool,
+}
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = input;
+
+    let fields = match data {
+        Data::Struct(data) => data.fields,
+        Data::Enum(data) => {
+            return Err(syn::Error::new_spanned(
+                data.enum_token,
+                "Attributes can only be derived for structs",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "Attributes can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut chained = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let options = field_options(&field.attrs)?;
+        if options.skip {
+            continue;
+        }
+
+        let member = match &field.ident {
+            Some(ident) => quote! { #ident },
+            None => {
+                let index = syn::Index::from(index);
+                quote! { #index }
+            }
+        };
+        chained.push(match (options.flatten, option_argument(&field.ty)) {
+            (false, None) => quote! {
+                ::std::iter::once(&self.#member as &dyn ::domatt::attributes::Attribute)
+            },
+            (false, Some(_)) => quote! {
+                self.#member
+                    .as_ref()
+                    .map(|attribute| attribute as &dyn ::domatt::attributes::Attribute)
+            },
+            (true, None) => quote! {
+                ::domatt::attributes::Attributes::attributes(&self.#member)
+            },
+            (true, Some(_)) => quote! {
+                self.#member
+                    .iter()
+                    .flat_map(::domatt::attributes::Attributes::attributes)
+            },
+        });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::domatt::attributes::Attributes for #ident #ty_generics #where_clause {
+            fn attributes(
+                &self,
+            ) -> ::std::boxed::Box<
+                dyn ::std::iter::Iterator<Item = &dyn ::domatt::attributes::Attribute> + '_,
+            > {
+                let attributes = ::std::iter::empty::<&dyn ::domatt::attributes::Attribute>();
+                #(let attributes = ::std::iter::Iterator::chain(attributes, #chained);)*
+                ::std::boxed::Box::new(attributes)
+            }
+        }
+    })
+}
+
+fn field_options(attrs: &[syn::Attribute]) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("attr")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => return Err(syn::Error::new_spanned(meta, "expected `#[attr(...)]`")),
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => options.skip = true,
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("flatten") => {
+                    options.flatten = true
+                }
+                nested => {
+                    return Err(syn::Error::new_spanned(
+                        nested,
+                        "unknown option, expected `skip` or `flatten`",
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(options)
+}
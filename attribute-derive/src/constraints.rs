@@ -0,0 +1,234 @@
+use crate::type_ident;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::Token;
+
+/// Character classes accepted by `chars(...)`, each checked with the `char` method of
+/// the same name prefixed with `is_`.
+const CHAR_CLASSES: &[&str] = &[
+    "alphabetic",
+    "alphanumeric",
+    "ascii_alphabetic",
+    "ascii_alphanumeric",
+    "ascii_digit",
+    "ascii_graphic",
+    "ascii_hexdigit",
+    "ascii_lowercase",
+    "ascii_punctuation",
+    "ascii_uppercase",
+    "numeric",
+];
+
+/// Types that `range(...)` can be applied to.
+const NUMERIC_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize", "f32", "f64",
+];
+
+mod kw {
+    syn::custom_keyword!(range);
+    syn::custom_keyword!(non_empty);
+    syn::custom_keyword!(no_whitespace);
+    syn::custom_keyword!(chars);
+}
+
+/// Constraints on the value of an attribute, checked by the generated `try_new`.
+#[derive(Default)]
+pub struct Constraints {
+    /// The value must be in one of the ranges.
+    ranges: Vec<syn::ExprRange>,
+    /// The serialized value may not be empty.
+    non_empty: bool,
+    /// The serialized value may not contain ASCII whitespace.
+    no_whitespace: bool,
+    /// Every character of the serialized value must belong to one of the classes.
+    chars: Vec<CharClass>,
+    /// The first constraint, to point errors at.
+    first: Option<proc_macro2::Span>,
+}
+
+enum CharClass {
+    Named(syn::Ident),
+    Literal(syn::LitChar),
+}
+
+impl Constraints {
+    pub fn is_declared(&self) -> bool {
+        self.first.is_some()
+    }
+
+    /// Returns an error pointing at the first constraint.
+    pub fn error(&self, message: &str) -> syn::Error {
+        syn::Error::new(
+            self.first.unwrap_or_else(proc_macro2::Span::call_site),
+            message,
+        )
+    }
+
+    /// Parses a constraint if the input starts with one, returning whether it did.
+    pub fn parse_next(&mut self, input: ParseStream) -> syn::Result<bool> {
+        let span = input.span();
+        if input.peek(kw::range) && input.peek2(syn::token::Paren) {
+            input.parse::<kw::range>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let ranges = Punctuated::<syn::ExprRange, Token![,]>::parse_terminated(&content)?;
+            if ranges.is_empty() {
+                return Err(syn::Error::new(
+                    span,
+                    "`range(...)` needs at least one range",
+                ));
+            }
+            self.ranges.extend(ranges);
+        } else if input.peek(kw::chars) && input.peek2(syn::token::Paren) {
+            input.parse::<kw::chars>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            while !content.is_empty() {
+                let class = if content.peek(syn::LitChar) {
+                    CharClass::Literal(content.parse()?)
+                } else {
+                    let ident: syn::Ident = content.parse()?;
+                    if !CHAR_CLASSES.contains(&ident.to_string().as_str()) {
+                        return Err(syn::Error::new_spanned(
+                            ident,
+                            format!(
+                                "unknown character class, expected a char literal or one of {}",
+                                CHAR_CLASSES.join(", ")
+                            ),
+                        ));
+                    }
+                    CharClass::Named(ident)
+                };
+                self.chars.push(class);
+                if !content.is_empty() {
+                    content.parse::<Token![,]>()?;
+                }
+            }
+        } else if input.peek(kw::non_empty) {
+            input.parse::<kw::non_empty>()?;
+            self.non_empty = true;
+        } else if input.peek(kw::no_whitespace) {
+            input.parse::<kw::no_whitespace>()?;
+            self.no_whitespace = true;
+        } else {
+            return Ok(false);
+        }
+
+        self.first.get_or_insert(span);
+        Ok(true)
+    }
+
+    /// Returns the `try_new` constructor, or nothing if there are no constraints.
+    /// `input_type` is the type taken by `new`, and `fallible` tells whether `new`
+    /// already returns a `Result`.
+    pub fn try_new(
+        &self,
+        input_type: Option<&syn::Type>,
+        fallible: bool,
+    ) -> syn::Result<TokenStream2> {
+        let span = match self.first {
+            Some(span) => span,
+            None => return Ok(quote! {}),
+        };
+        let input_type = input_type.ok_or_else(|| {
+            syn::Error::new(span, "constraints need a value type, such as `String`")
+        })?;
+
+        let range_check = if self.ranges.is_empty() {
+            quote! {}
+        } else {
+            let numeric = type_ident(input_type)
+                .is_some_and(|ident| NUMERIC_TYPES.contains(&ident.to_string().as_str()));
+            if !numeric {
+                return Err(syn::Error::new_spanned(
+                    &self.ranges[0],
+                    format!(
+                        "`range(...)` needs a numeric type, one of {}",
+                        NUMERIC_TYPES.join(", ")
+                    ),
+                ));
+            }
+
+            let ranges = &self.ranges;
+            let message = format!(
+                "Value is out of range ({}).",
+                ranges
+                    .iter()
+                    .map(|range| quote!(#range).to_string().replace(' ', ""))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            quote! {
+                if !(#((#ranges).contains(&val))||*) {
+                    return ::std::result::Result::Err(::domatt::values::ValueError::new(&val.to_string(), #message));
+                }
+            }
+        };
+
+        let non_empty_check = self.non_empty.then(|| {
+            quote! {
+                if serialized.is_empty() {
+                    return ::std::result::Result::Err(::domatt::values::ValueError::new(serialized, "Value may not be empty."));
+                }
+            }
+        });
+        let no_whitespace_check = self.no_whitespace.then(|| {
+            quote! {
+                if serialized.contains(|c: char| c.is_ascii_whitespace()) {
+                    return ::std::result::Result::Err(::domatt::values::ValueError::new(serialized, "Value may not contain whitespace."));
+                }
+            }
+        });
+        let chars_check = (!self.chars.is_empty()).then(|| {
+            let tests = self.chars.iter().map(|class| match class {
+                CharClass::Named(ident) => {
+                    let method = format_ident!("is_{}", ident);
+                    quote! { c.#method() }
+                }
+                CharClass::Literal(lit) => quote! { c == #lit },
+            });
+            let message = format!(
+                "Value may only contain {}.",
+                self.chars
+                    .iter()
+                    .map(|class| match class {
+                        CharClass::Named(ident) => ident.to_string().replace('_', " "),
+                        CharClass::Literal(lit) => format!("'{}'", lit.value()),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            quote! {
+                if !serialized.chars().all(|c| #(#tests)||*) {
+                    return ::std::result::Result::Err(::domatt::values::ValueError::new(serialized, #message));
+                }
+            }
+        });
+
+        // Optional values are only checked when they are present.
+        let value_checks =
+            (self.non_empty || self.no_whitespace || !self.chars.is_empty()).then(|| {
+                quote! {
+                    if let ::std::option::Option::Some(serialized) =
+                        ::domatt::attributes::Attribute::get_val(&attribute)
+                    {
+                        #non_empty_check
+                        #no_whitespace_check
+                        #chars_check
+                    }
+                }
+            });
+        let question = fallible.then(|| quote! { ? });
+        Ok(quote! {
+            /// Like `new`, but fails if the value breaks the constraints the spec puts on
+            /// this attribute.
+            pub fn try_new(val: #input_type) -> ::std::result::Result<Self, ::domatt::values::ValueError> {
+                #range_check
+                let attribute = Self::new(val)#question;
+                #value_checks
+                ::std::result::Result::Ok(attribute)
+            }
+        })
+    }
+}
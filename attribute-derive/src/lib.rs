@@ -5,117 +5,118 @@ extern crate quote;
 
 use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use syn::parse::{Parse, ParseStream};
 use syn::{parse_macro_input, Data, DeriveInput, Token};
 
+mod attribute_value;
+mod attributes;
+mod constraints;
+
+use constraints::Constraints;
+
+/// Derives `Attribute` for a tuple struct holding the serialized value, or for a unit
+/// struct representing a boolean attribute.
+///
+/// The key is either derived from the struct name with a case rule, as in
+/// `#[attribute("kebab-case", String)]`, or given literally, as in
+/// `#[attribute(key = "xml:lang", String)]`. The optional type is the one taken by the
+/// generated `new` constructor. If the struct holds a `String`, the value is serialized
+/// with `ToAttrValue`; otherwise the struct holds the value itself, which must implement
+/// `AsRef<str>`. The value type must also implement `FromAttrValue`, which the generated
+/// `FromStr` uses to parse the attribute's value. `f32` and `f64` go through `Number`,
+/// so their `new` fails for `NaN` and infinities.
+///
+/// An attribute whose value may be left out, such as `download`, takes and holds an
+/// `Option`, as in `#[attribute("lowercase", Option<String>)]` on
+/// `struct Download(Option<String>)`. `None` gives the bare attribute, whose `get_val`
+/// is `None`, and parsing an empty value gives `None` back.
+///
+/// Constraints from the spec are declared after the type and checked by a generated
+/// `try_new`: `range(1..)` for numbers, where several ranges may be listed, and
+/// `non_empty`, `no_whitespace` and `chars(ascii_alphanumeric, '-')` for the serialized
+/// value. `chars` takes char literals and the names of `char::is_*` methods.
+///
+/// `Clone`, `PartialEq`, `Eq`, `Hash` and `Display`, which renders `key="value"`, are
+/// implemented as well, along with `AsRef<str>` for the value and `From` for the type
+/// taken by `new`.
+///
+/// `elements(...)` lists the element modules whose attribute trait is implemented for
+/// the attribute, such as `elements(input, select)`. `elements(global)` implements the
+/// trait of every element. It only works inside this crate; other crates implement the
+/// element traits by hand.
 #[proc_macro_derive(Attribute, attributes(attribute))]
 pub fn attribute(input: TokenStream) -> TokenStream {
-    // Construct a string representation of the type definition
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_attribute(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_attribute(input: DeriveInput) -> syn::Result<TokenStream2> {
     let DeriveInput {
         ident, data, attrs, ..
-    } = parse_macro_input!(input);
-
-    let attribute = get_attribute(&attrs);
+    } = input;
 
-    let AttributeParams(case, input_type, generic) =
-        syn::parse2(attribute.tokens.clone()).expect("Invalid attribute!");
+    let attribute = get_attribute(&attrs, &ident)?;
+    let AttributeParams {
+        key,
+        input_type,
+        elements,
+        constraints,
+    } = attribute.parse_args()?;
 
-    let is_unit = is_unit(data);
-
-    let case = match case.value().as_str() {
-        "camelCase" => Some(Case::Camel),
-        "kebab-case" => Some(Case::Kebab),
-        "lowercase" => Some(Case::Lower),
-        _ => None,
-    };
+    let field_type = field_type(&data)?;
+    let is_unit = field_type.is_none();
+    let optional = field_type.and_then(option_argument).is_some();
 
-    let serial = if let Some(case) = case {
-        ident.to_string().to_case(case)
-    } else {
-        ident.to_string()
+    let serial = match key {
+        Key::Case(case) => match parse_case(&case.value()) {
+            Some(case) => ident.to_string().to_case(case),
+            None => {
+                return Err(syn::Error::new_spanned(
+                    case,
+                    "unknown case, expected \"camelCase\", \"kebab-case\" or \"lowercase\"; use `key = \"...\"` for a literal key",
+                ))
+            }
+        },
+        Key::Literal(key) => key.value(),
     };
 
-    let constructor = if !is_unit && input_type.is_some() {
-        let input_type = input_type.unwrap();
-
-        let converter = match input_type.to_string().as_str() {
-            "Option" => match generic {
-                Some(option_type) => {
-                    let args = option_type.args;
-                    quote! {
-                        pub fn new(val: Option<#args>) -> Self {
-                            match val {
-                                Some(val) => Self(val),
-                                None => Self(String::from(""))
-                            }
-                        }
-                    }
-                }
-                None => panic!("Need a generic type"),
-            },
-            "Vec" => match generic {
-                Some(generic) => {
-                    let args = generic.args;
-                    let arg = args.first().unwrap();
-
-                    let type_ = match arg {
-                        syn::GenericArgument::Type(type_) => match type_ {
-                            syn::Type::Path(type_) => type_,
-
-                            _ => panic!("Invalid generic type"),
-                        },
-                        _ => panic!("Invalid generic parameter"),
-                    };
-                    let generic_ident = type_.path.get_ident().unwrap().to_string();
-                    if generic_ident == "char" {
-                        quote! {
-                            pub fn new(val: Vec<#arg>) -> Self {
-                                Self(val.iter()
-                                    .map(char::to_string)
-                                    .collect::<Vec<String>>()
-                                    .join(" ")
-                                )
-                            }
-                        }
-                    } else {
-                        quote! {
-                           pub fn new(val: Vec<#arg>) -> Self {
-                               Self(val.iter()
-                               .map(|item| item.as_ref())
-                               .collect::<Vec<&str>>()
-                               .join(" "))
-                           }
-                        }
-                    }
-                }
-                None => panic!("Need a generic type"),
-            },
-            "Url" | "String" | "u16" | "u8" | "i16" | "bool" | "SvgLength" | "NumberOrString"
-            | "f32" => {
-                quote! {
-                    pub fn new(val: #input_type) -> Self {
-                        Self(val.to_string())
-                    }
-                }
+    let mut conversion = quote! {};
+    let constructor = match (input_type, field_type) {
+        (Some(input_type), Some(field_type)) => {
+            if optional != option_argument(&input_type).is_some() {
+                return Err(syn::Error::new_spanned(
+                    field_type,
+                    "an attribute with an optional value must take and hold an `Option`, such as `Option<String>`",
+                ));
             }
-            _ => quote! {
-                pub fn new(val: #input_type) -> Self {
-                    Self(val)
-                }
-            },
-        };
 
-        quote! {
-            impl #ident {
-                pub const KEY: &'static str = #serial;
+            let fallible = is_float(&input_type);
+            let try_new = constraints.try_new(Some(&input_type), fallible)?;
+            let converter = converter(&input_type, field_type, fallible, optional);
+            conversion = conversion_impl(&ident, &input_type, fallible, constraints.is_declared());
+
+            quote! {
+                impl #ident {
+                    pub const KEY: &'static str = #serial;
+
+                    #converter
 
-                #converter
+                    #try_new
+                }
             }
         }
-    } else {
-        quote! {
-            impl #ident {
-                pub const KEY: &'static str = #serial;
+        _ if is_unit && constraints.is_declared() => {
+            return Err(constraints.error("boolean attributes have no value to constrain"))
+        }
+        _ => {
+            constraints.try_new(None, false)?;
+            quote! {
+                impl #ident {
+                    pub const KEY: &'static str = #serial;
+                }
             }
         }
     };
@@ -126,6 +127,12 @@ pub fn attribute(input: TokenStream) -> TokenStream {
                 None
             }
         }
+    } else if optional {
+        quote! {
+            fn get_val(&self) -> Option<&str> {
+                self.0.as_ref().map(|val| ::std::convert::AsRef::<str>::as_ref(val))
+            }
+        }
     } else {
         quote! {
             fn get_val(&self) -> Option<&str> {
@@ -134,10 +141,22 @@ pub fn attribute(input: TokenStream) -> TokenStream {
         }
     };
 
-    let output = quote! {
+    // The element traits are implemented by a macro in the `attributes` module, which
+    // knows every element and the feature that enables it.
+    let element_impls = elements.iter().map(|element| {
+        quote! {
+            crate::attributes::impl_element_attribute!(#element, #ident);
+        }
+    });
+
+    let standard_impls = standard_impls(&ident, is_unit, optional);
+
+    Ok(quote! {
         #constructor
+        #standard_impls
+        #conversion
 
-        impl Attribute for #ident {
+        impl ::domatt::attributes::Attribute for #ident {
             #get_val
 
             fn get_key(&self) -> &str {
@@ -145,52 +164,343 @@ pub fn attribute(input: TokenStream) -> TokenStream {
             }
         }
 
+        #(#element_impls)*
+    })
+}
+
+/// Returns `Clone`, `PartialEq`, `Eq`, `Hash` and `Display` for an attribute, and
+/// `AsRef<str>` for the value of attributes that always have one. `Display` renders the
+/// attribute as it appears in a start tag, such as `name="value"`.
+fn standard_impls(ident: &syn::Ident, is_unit: bool, optional: bool) -> TokenStream2 {
+    let display = quote! {
+        impl ::std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(&::domatt::attributes::render_attribute(self))
+            }
+        }
+
+        impl ::std::cmp::Eq for #ident {}
     };
 
-    output.into()
+    let as_ref = (!optional).then(|| {
+        quote! {
+            impl ::std::convert::AsRef<str> for #ident {
+                fn as_ref(&self) -> &str {
+                    ::std::convert::AsRef::<str>::as_ref(&self.0)
+                }
+            }
+        }
+    });
+
+    if is_unit {
+        return quote! {
+            impl ::std::clone::Clone for #ident {
+                fn clone(&self) -> Self {
+                    Self
+                }
+            }
+
+            impl ::std::cmp::PartialEq for #ident {
+                fn eq(&self, _other: &Self) -> bool {
+                    true
+                }
+            }
+
+            impl ::std::hash::Hash for #ident {
+                fn hash<H: ::std::hash::Hasher>(&self, _state: &mut H) {}
+            }
+
+            #display
+        };
+    }
+
+    quote! {
+        impl ::std::clone::Clone for #ident {
+            fn clone(&self) -> Self {
+                Self(::std::clone::Clone::clone(&self.0))
+            }
+        }
+
+        impl ::std::cmp::PartialEq for #ident {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl ::std::hash::Hash for #ident {
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                ::std::hash::Hash::hash(&self.0, state);
+            }
+        }
+
+        #as_ref
+        #display
+    }
+}
+
+/// Returns `From` for the type taken by `new`, or `TryFrom` if `new` is fallible, and
+/// `FromStr`, which parses the value with `FromAttrValue` and checks it with `try_new`
+/// if the attribute has constraints.
+fn conversion_impl(
+    ident: &syn::Ident,
+    input_type: &syn::Type,
+    fallible: bool,
+    constrained: bool,
+) -> TokenStream2 {
+    let from = if fallible {
+        quote! {
+            impl ::std::convert::TryFrom<#input_type> for #ident {
+                type Error = ::domatt::values::ValueError;
+
+                fn try_from(val: #input_type) -> ::std::result::Result<Self, Self::Error> {
+                    Self::new(val)
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl ::std::convert::From<#input_type> for #ident {
+                fn from(val: #input_type) -> Self {
+                    Self::new(val)
+                }
+            }
+        }
+    };
+
+    let construct = if constrained {
+        quote! { Self::try_new(val) }
+    } else if fallible {
+        quote! { Self::new(val) }
+    } else {
+        quote! { ::std::result::Result::Ok(Self::new(val)) }
+    };
+
+    quote! {
+        #from
+
+        impl ::std::str::FromStr for #ident {
+            type Err = ::domatt::values::ValueError;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                let val = <#input_type as ::domatt::values::FromAttrValue>::from_attr_value(s)?;
+                #construct
+            }
+        }
+    }
+}
+
+/// Returns the `new` constructor for an attribute taking `input_type` and holding
+/// `field_type`. An optional value is serialized only when it is present.
+fn converter(
+    input_type: &syn::Type,
+    field_type: &syn::Type,
+    fallible: bool,
+    optional: bool,
+) -> TokenStream2 {
+    // Floats go through `Number` so that non-finite values are rejected and the
+    // value is serialized the same way JavaScript would.
+    if fallible {
+        return quote! {
+            pub fn new(val: #input_type) -> ::std::result::Result<Self, ::domatt::values::ValueError> {
+                ::domatt::values::Number::new(f64::from(val))
+                    .map(|val| Self(::domatt::values::ToAttrValue::to_attr_value(&val)))
+            }
+        };
+    }
+
+    if quote!(#input_type).to_string() == quote!(#field_type).to_string() {
+        quote! {
+            pub fn new(val: #input_type) -> Self {
+                Self(val)
+            }
+        }
+    } else if optional {
+        quote! {
+            pub fn new(val: #input_type) -> Self {
+                Self(val.map(|val| ::domatt::values::ToAttrValue::to_attr_value(&val)))
+            }
+        }
+    } else {
+        quote! {
+            pub fn new(val: #input_type) -> Self {
+                Self(::domatt::values::ToAttrValue::to_attr_value(&val))
+            }
+        }
+    }
+}
+
+/// Returns the name of a type written as a single identifier, such as `u8`.
+fn type_ident(ty: &syn::Type) -> Option<&syn::Ident> {
+    match ty {
+        syn::Type::Path(ty) if ty.qself.is_none() => ty.path.get_ident(),
+        _ => None,
+    }
+}
+
+/// Returns `T` if the type is `Option<T>`.
+fn option_argument(ty: &syn::Type) -> Option<&syn::Type> {
+    let segment = match ty {
+        syn::Type::Path(ty) if ty.qself.is_none() => ty.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_float(ty: &syn::Type) -> bool {
+    type_ident(ty).is_some_and(|ident| ident == "f32" || ident == "f64")
+}
+
+/// Derives `Clone`, `PartialEq`, `Eq`, `Hash`, `AsRef<str>`, `Display` and `FromStr` for
+/// an enum of attribute keywords.
+///
+/// Keywords are the variant names converted with `#[attribute_value(case = "...")]`,
+/// unless a variant is given `#[attribute_value(rename = "...")]`. One tuple variant
+/// holding a `String` may be marked `#[attribute_value(custom)]`; it serializes its
+/// payload and catches every value that isn't a keyword when parsing, except the empty
+/// string. A custom value that spells a keyword is equal to that keyword. Keywords are
+/// matched ASCII case-insensitively unless the enum is marked `case_sensitive`.
+#[proc_macro_derive(AttributeValue, attributes(attribute_value))]
+pub fn attribute_value(input: TokenStream) -> TokenStream {
+    attribute_value::derive(input)
+}
+
+/// Derives `Attributes` for a struct whose fields are attributes, such as the props
+/// of a component.
+///
+/// The attributes are yielded in field order. A field holding an `Option` of an
+/// attribute is left out when it is `None`. A field marked `#[attr(flatten)]` holds
+/// another set of attributes, or an `Option` of one, whose attributes are yielded in
+/// its place, and a field marked `#[attr(skip)]` is left out.
+#[proc_macro_derive(Attributes, attributes(attr))]
+pub fn attributes(input: TokenStream) -> TokenStream {
+    attributes::derive(input)
+}
+
+/// Maps the name of a case rule to its conversion. `lowercase` removes word
+/// boundaries, so `FormAction` becomes `formaction`.
+fn parse_case(case: &str) -> Option<Case> {
+    match case {
+        "camelCase" => Some(Case::Camel),
+        "kebab-case" => Some(Case::Kebab),
+        "lowercase" => Some(Case::Flat),
+        _ => None,
+    }
+}
+
+/// How the key of an attribute is written.
+enum Key {
+    /// A case rule applied to the struct name.
+    Case(syn::LitStr),
+    /// The key itself, for keys that no case rule produces, such as `xml:lang`.
+    Literal(syn::LitStr),
+}
+
+struct AttributeParams {
+    key: Key,
+    input_type: Option<syn::Type>,
+    elements: Vec<syn::Ident>,
+    constraints: Constraints,
 }
 
-struct AttributeParams(
-    syn::LitStr,
-    Option<syn::Ident>,
-    Option<syn::AngleBracketedGenericArguments>,
-);
 impl Parse for AttributeParams {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let content;
-        syn::parenthesized!(content in input);
-
-        let case = content.parse()?;
+        let key = if input.peek(kw::key) {
+            input.parse::<kw::key>()?;
+            input.parse::<Token![=]>()?;
+            Key::Literal(input.parse()?)
+        } else {
+            Key::Case(input.parse()?)
+        };
 
-        match content.parse::<Token![,]>() {
-            Ok(_) => (),
-            Err(_) => return Ok(AttributeParams(case, None, None)),
+        let mut params = AttributeParams {
+            key,
+            input_type: None,
+            elements: Vec::new(),
+            constraints: Constraints::default(),
         };
-        let input_type = content.parse()?;
 
-        match content.parse::<syn::AngleBracketedGenericArguments>() {
-            Ok(gen) => Ok(AttributeParams(case, Some(input_type), Some(gen))),
-            Err(_) => return Ok(AttributeParams(case, Some(input_type), None)),
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            if input.peek(kw::elements) && input.peek2(syn::token::Paren) {
+                input.parse::<kw::elements>()?;
+                let content;
+                syn::parenthesized!(content in input);
+                let elements = content.parse_terminated::<_, Token![,]>(syn::Ident::parse)?;
+                params.elements.extend(elements);
+            } else if params.constraints.parse_next(input)? {
+                continue;
+            } else if params.input_type.is_none()
+                && params.elements.is_empty()
+                && !params.constraints.is_declared()
+            {
+                params.input_type = Some(input.parse()?);
+            } else {
+                return Err(input.error("expected a constraint or `elements(...)`"));
+            }
         }
+
+        Ok(params)
     }
 }
 
-fn get_attribute<'a>(attrs: &'a Vec<syn::Attribute>) -> &'a syn::Attribute {
-    &attrs
+mod kw {
+    syn::custom_keyword!(key);
+    syn::custom_keyword!(elements);
+}
+
+fn get_attribute<'a>(
+    attrs: &'a [syn::Attribute],
+    ident: &syn::Ident,
+) -> syn::Result<&'a syn::Attribute> {
+    attrs
         .iter()
-        .filter(|a| a.path.segments.len() == 1 && a.path.segments[0].ident == "attribute")
-        .nth(0)
-        .expect("'attribute' attribute required for deriving Attribute!")
+        .find(|a| a.path.is_ident("attribute"))
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                ident,
+                "missing `#[attribute(\"case\", Type)]`, which is required for deriving Attribute",
+            )
+        })
 }
 
-fn is_unit(data: Data) -> bool {
+/// Returns the type held by a tuple struct, or `None` for a unit struct.
+fn field_type(data: &Data) -> syn::Result<Option<&syn::Type>> {
     match data {
-        syn::Data::Struct(struct_data) => match struct_data.fields {
-            syn::Fields::Named(_) => false,
-            syn::Fields::Unnamed(_) => false,
-            syn::Fields::Unit => true,
+        syn::Data::Struct(struct_data) => match &struct_data.fields {
+            syn::Fields::Named(fields) => Err(syn::Error::new_spanned(
+                fields,
+                "Attribute can only be derived for tuple or unit structs, not structs with named fields",
+            )),
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                Ok(Some(&fields.unnamed[0].ty))
+            }
+            syn::Fields::Unnamed(fields) => Err(syn::Error::new_spanned(
+                fields,
+                "Attribute can only be derived for a tuple struct with a single field holding the value",
+            )),
+            syn::Fields::Unit => Ok(None),
         },
-        syn::Data::Enum(_) => panic!("Cannot implement Attribute for an Enum"),
-        syn::Data::Union(_) => panic!("Cannot implement Attribute for a Union"),
+        syn::Data::Enum(data) => Err(syn::Error::new_spanned(
+            data.enum_token,
+            "Attribute can't be derived for enums, derive AttributeValue for the value instead",
+        )),
+        syn::Data::Union(data) => Err(syn::Error::new_spanned(
+            data.union_token,
+            "Attribute can't be derived for unions",
+        )),
     }
 }
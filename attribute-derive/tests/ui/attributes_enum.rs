@@ -0,0 +1,9 @@
+use attribute_derive::Attributes;
+
+#[derive(Attributes)]
+pub enum Props {
+    Link,
+    Button,
+}
+
+fn main() {}
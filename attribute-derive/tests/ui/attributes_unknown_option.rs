@@ -0,0 +1,9 @@
+use attribute_derive::Attributes;
+
+#[derive(Attributes)]
+pub struct Props {
+    #[attr(rename = "title")]
+    title: String,
+}
+
+fn main() {}
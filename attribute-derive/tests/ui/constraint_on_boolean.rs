@@ -0,0 +1,7 @@
+use attribute_derive::Attribute;
+
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", non_empty)]
+pub struct Hidden;
+
+fn main() {}
@@ -0,0 +1,7 @@
+use attribute_derive::Attribute;
+
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", String, range(1..))]
+pub struct Title(String);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use attribute_derive::Attribute;
+
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", String, chars(ascii_alphanumeric, letters))]
+pub struct Title(String);
+
+fn main() {}
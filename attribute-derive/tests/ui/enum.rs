@@ -0,0 +1,10 @@
+use attribute_derive::Attribute;
+
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", String)]
+pub enum Title {
+    Short,
+    Long,
+}
+
+fn main() {}
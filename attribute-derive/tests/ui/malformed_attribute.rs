@@ -0,0 +1,7 @@
+use attribute_derive::Attribute;
+
+#[derive(Debug, Attribute)]
+#[attribute(String)]
+pub struct Title(String);
+
+fn main() {}
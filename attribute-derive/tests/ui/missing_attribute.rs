@@ -0,0 +1,6 @@
+use attribute_derive::Attribute;
+
+#[derive(Debug, Attribute)]
+pub struct Title(String);
+
+fn main() {}
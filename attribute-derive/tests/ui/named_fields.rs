@@ -0,0 +1,9 @@
+use attribute_derive::Attribute;
+
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", String)]
+pub struct Title {
+    value: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use attribute_derive::Attribute;
+
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", Option<String>)]
+pub struct Download(String);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use attribute_derive::Attribute;
+
+#[derive(Attribute)]
+#[attribute("lowercase", String)]
+pub union Title {
+    value: u32,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use attribute_derive::AttributeValue;
+
+#[derive(AttributeValue)]
+pub struct Mode(String);
+
+fn main() {}
@@ -0,0 +1,13 @@
+use attribute_derive::AttributeValue;
+
+#[derive(AttributeValue)]
+#[attribute_value(case = "lowercase")]
+pub enum Mode {
+    Open,
+    #[attribute_value(custom)]
+    Other(String),
+    #[attribute_value(custom)]
+    Unknown(String),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use attribute_derive::AttributeValue;
+
+#[derive(AttributeValue)]
+#[attribute_value(case = "SCREAMING")]
+pub enum Mode {
+    Open,
+    Closed,
+}
+
+fn main() {}
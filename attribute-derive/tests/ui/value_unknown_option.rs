@@ -0,0 +1,11 @@
+use attribute_derive::AttributeValue;
+
+#[derive(AttributeValue)]
+#[attribute_value(case = "lowercase")]
+pub enum Mode {
+    #[attribute_value(alias = "shut")]
+    Closed,
+    Open,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use attribute_derive::AttributeValue;
+
+#[derive(AttributeValue)]
+#[attribute_value(case = "lowercase")]
+pub enum Mode {
+    Open,
+    Closed(u8),
+}
+
+fn main() {}
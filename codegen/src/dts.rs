@@ -0,0 +1,527 @@
+//! Compares the attribute interfaces of React's type declarations with domatt.
+//!
+//! `AriaAttributes`, `SVGAttributes`, `HTMLAttributes` and the element interfaces such
+//! as `AnchorHTMLAttributes` are read from a local copy of `@types/react`. Each is
+//! matched with the attribute module of the same name, or `aria`, `svg` and `global`,
+//! and the report lists the attributes that domatt lacks or that don't apply to the
+//! module, and the keywords of string literal unions that its value enums lack.
+
+use crate::inventory::{type_name, AttributeInfo, Inventory};
+use crate::spec::Modules;
+use convert_case::{Case, Casing};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Props that React handles itself instead of setting them as attributes.
+const REACT_ONLY: &[&str] = &[
+    "defaultChecked",
+    "defaultValue",
+    "suppressContentEditableWarning",
+    "suppressHydrationWarning",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// An identifier, keyword or number.
+    Ident(String),
+    Str(String),
+    /// `=>`, kept apart from `>` so that it doesn't close a type argument list.
+    Arrow,
+    Punct(char),
+}
+
+/// The interfaces and type aliases of one or more declaration files.
+#[derive(Debug, Default)]
+pub struct Declarations {
+    interfaces: Vec<Interface>,
+    aliases: HashMap<String, Vec<Token>>,
+}
+
+#[derive(Debug)]
+struct Interface {
+    name: String,
+    extends: Vec<String>,
+    members: Vec<Member>,
+}
+
+#[derive(Debug)]
+struct Member {
+    name: String,
+    ty: Vec<Token>,
+}
+
+impl Declarations {
+    /// Adds the declarations of a `.d.ts` file.
+    pub fn parse(&mut self, source: &str) -> Result<(), String> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        while let Some(token) = parser.peek(0) {
+            match (token, parser.peek(1)) {
+                (Token::Ident(keyword), Some(Token::Ident(_))) if keyword == "interface" => {
+                    self.interfaces.push(parser.interface()?);
+                }
+                (Token::Ident(keyword), Some(Token::Ident(_)))
+                    if keyword == "type"
+                        && matches!(parser.peek(2), Some(Token::Punct('=' | '<'))) =>
+                {
+                    let (name, ty) = parser.alias()?;
+                    self.aliases.insert(name, ty);
+                }
+                _ => parser.pos += 1,
+            }
+        }
+        Ok(())
+    }
+
+    fn interface(&self, name: &str) -> Option<&Interface> {
+        self.interfaces
+            .iter()
+            .find(|interface| interface.name == name)
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                loop {
+                    match chars.next() {
+                        Some('/') if previous == '*' => break,
+                        Some(c) => previous = c,
+                        None => return Err("unterminated comment".to_owned()),
+                    }
+                }
+            }
+            '"' | '\'' | '`' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some('\\') => value.extend(chars.next()),
+                        Some(end) if end == c => break,
+                        Some(c) => value.push(c),
+                        None => return Err("unterminated string".to_owned()),
+                    }
+                }
+                tokens.push(Token::Str(value));
+            }
+            '=' if chars.peek() == Some(&'>') => {
+                chars.next();
+                tokens.push(Token::Arrow);
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '$' => {
+                let mut ident = String::from(c);
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_' || c == '$') {
+                        break;
+                    }
+                    ident.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            c => tokens.push(Token::Punct(c)),
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset)
+    }
+
+    fn ident(&mut self) -> Result<String, String> {
+        match self.peek(0) {
+            Some(Token::Ident(ident)) => {
+                let ident = ident.clone();
+                self.pos += 1;
+                Ok(ident)
+            }
+            token => Err(format!("expected an identifier, found {token:?}")),
+        }
+    }
+
+    fn expect(&mut self, punct: char) -> Result<(), String> {
+        match self.peek(0) {
+            Some(Token::Punct(c)) if *c == punct => {
+                self.pos += 1;
+                Ok(())
+            }
+            token => Err(format!("expected `{punct}`, found {token:?}")),
+        }
+    }
+
+    /// Takes the tokens up to one of `ends` outside of any brackets, without taking the
+    /// end itself. A closing bracket outside of the taken tokens ends them as well.
+    fn take_until(&mut self, ends: &[char]) -> Vec<Token> {
+        let start = self.pos;
+        let mut depth = 0usize;
+        while let Some(token) = self.peek(0) {
+            match token {
+                Token::Punct(c) if depth == 0 && ends.contains(c) => break,
+                Token::Punct('(' | '[' | '{' | '<') => depth += 1,
+                Token::Punct(')' | ']' | '}' | '>') if depth == 0 => break,
+                Token::Punct(')' | ']' | '}' | '>') => depth -= 1,
+                _ => {}
+            }
+            self.pos += 1;
+        }
+        self.tokens[start..self.pos].to_vec()
+    }
+
+    /// Skips `<...>` if it follows.
+    fn skip_type_parameters(&mut self) -> Result<(), String> {
+        if self.peek(0) == Some(&Token::Punct('<')) {
+            self.pos += 1;
+            self.take_until(&[]);
+            self.expect('>')?;
+        }
+        Ok(())
+    }
+
+    fn interface(&mut self) -> Result<Interface, String> {
+        self.pos += 1;
+        let name = self.ident()?;
+        self.skip_type_parameters()?;
+
+        let mut extends = Vec::new();
+        if self.peek(0) == Some(&Token::Ident("extends".to_owned())) {
+            self.pos += 1;
+            loop {
+                extends.push(self.ident()?);
+                self.skip_type_parameters()?;
+                if self.peek(0) != Some(&Token::Punct(',')) {
+                    break;
+                }
+                self.pos += 1;
+            }
+        }
+
+        self.expect('{')
+            .map_err(|err| format!("interface `{name}`: {err}"))?;
+        let mut members = Vec::new();
+        loop {
+            let member = match self.peek(0) {
+                Some(Token::Punct('}')) => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(Token::Punct(';' | ',')) => {
+                    self.pos += 1;
+                    continue;
+                }
+                Some(Token::Ident(name) | Token::Str(name)) => name.clone(),
+                Some(_) => {
+                    // An index signature or something else that isn't a property.
+                    if self.take_until(&[';', ',']).is_empty() {
+                        self.pos += 1;
+                    }
+                    continue;
+                }
+                None => return Err(format!("interface `{name}` isn't closed")),
+            };
+
+            self.pos += 1;
+            if member == "readonly" && matches!(self.peek(0), Some(Token::Ident(_) | Token::Str(_)))
+            {
+                continue;
+            }
+            if self.peek(0) == Some(&Token::Punct('?')) {
+                self.pos += 1;
+            }
+            if self.peek(0) == Some(&Token::Punct(':')) {
+                self.pos += 1;
+                let ty = self.take_until(&[';', ',']);
+                members.push(Member { name: member, ty });
+            } else {
+                // A method.
+                self.take_until(&[';', ',']);
+            }
+        }
+
+        Ok(Interface {
+            name,
+            extends,
+            members,
+        })
+    }
+
+    fn alias(&mut self) -> Result<(String, Vec<Token>), String> {
+        self.pos += 1;
+        let name = self.ident()?;
+        self.skip_type_parameters()?;
+        self.expect('=')
+            .map_err(|err| format!("type `{name}`: {err}"))?;
+        let ty = self.take_until(&[';']);
+        Ok((name, ty))
+    }
+}
+
+/// The string literals of a union type.
+#[derive(Debug, Default)]
+struct Union {
+    keywords: Vec<String>,
+    /// Whether the union takes other strings or numbers as well.
+    open: bool,
+}
+
+impl Union {
+    fn of(ty: &[Token], declarations: &Declarations) -> Self {
+        let mut union = Union::default();
+        union.add(ty, declarations, 0);
+        union
+    }
+
+    fn add(&mut self, ty: &[Token], declarations: &Declarations, depth: usize) {
+        let mut nesting = 0usize;
+        let mut parts = vec![Vec::new()];
+        for token in ty {
+            match token {
+                Token::Punct('|') if nesting == 0 => parts.push(Vec::new()),
+                token => {
+                    match token {
+                        Token::Punct('(' | '[' | '{' | '<') => nesting += 1,
+                        Token::Punct(')' | ']' | '}' | '>') => nesting = nesting.saturating_sub(1),
+                        _ => {}
+                    }
+                    parts.last_mut().unwrap().push(token.clone());
+                }
+            }
+        }
+
+        for part in parts {
+            match part.as_slice() {
+                [] => {}
+                [Token::Str(keyword)] => {
+                    if !self.keywords.contains(keyword) {
+                        self.keywords.push(keyword.clone());
+                    }
+                }
+                [Token::Ident(ident)]
+                    if matches!(
+                        ident.as_str(),
+                        "undefined" | "null" | "boolean" | "true" | "false"
+                    ) => {}
+                [Token::Ident(ident)] if depth < 8 && declarations.aliases.contains_key(ident) => {
+                    self.add(&declarations.aliases[ident], declarations, depth + 1);
+                }
+                [Token::Punct('('), inner @ .., Token::Punct(')')] if depth < 8 => {
+                    self.add(inner, declarations, depth + 1);
+                }
+                _ => self.open = true,
+            }
+        }
+    }
+}
+
+/// The attribute module an interface corresponds to.
+fn module_name(interface: &str) -> String {
+    match interface {
+        "AriaAttributes" => "aria".to_owned(),
+        "SVGAttributes" => "svg".to_owned(),
+        "HTMLAttributes" => "global".to_owned(),
+        name => name
+            .strip_suffix("HTMLAttributes")
+            .unwrap_or(name)
+            .to_case(Case::Snake),
+    }
+}
+
+fn is_attribute_interface(name: &str) -> bool {
+    name == "AriaAttributes"
+        || name == "SVGAttributes"
+        || (name.ends_with("HTMLAttributes") && name != "AllHTMLAttributes")
+}
+
+/// Reduces a key to the letters and digits that React's prop names share with it, so
+/// that `acceptCharset` matches `accept-charset` and `xlinkHref` matches `xlink:href`.
+fn normalize(key: &str) -> String {
+    let key = match key {
+        "className" => "class",
+        "htmlFor" => "for",
+        key => key,
+    };
+    key.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Returns the report, with a line for each finding under the interface it is about.
+pub fn report(declarations: &Declarations, inventory: &Inventory, modules: &Modules) -> String {
+    let has_module = |name: &str| modules.modules.iter().any(|module| module.name == name);
+    let mut by_key: HashMap<String, Vec<&AttributeInfo>> = HashMap::new();
+    for attribute in &inventory.attributes {
+        by_key
+            .entry(normalize(&attribute.key))
+            .or_default()
+            .push(attribute);
+    }
+
+    let interfaces: Vec<&Interface> = declarations
+        .interfaces
+        .iter()
+        .filter(|interface| is_attribute_interface(&interface.name))
+        .collect();
+    // Interfaces such as `MediaHTMLAttributes` only exist to be extended; their members
+    // are reported with the interfaces that extend them.
+    let is_base = |name: &str| {
+        interfaces
+            .iter()
+            .any(|interface| interface.extends.iter().any(|extended| extended == name))
+    };
+
+    let mut out = String::new();
+    let (mut missing, mut misplaced, mut keywords) = (0, 0, 0);
+    for interface in &interfaces {
+        let module = module_name(&interface.name);
+        if !has_module(&module) && is_base(&interface.name) {
+            continue;
+        }
+
+        let mut findings = Vec::new();
+        for member in members(interface, declarations, &has_module) {
+            if REACT_ONLY.contains(&member.name.as_str()) {
+                continue;
+            }
+            let name = match member.name.as_str() {
+                "className" => "class",
+                "htmlFor" => "for",
+                name => name,
+            };
+
+            let candidates = by_key
+                .get(&normalize(name))
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let attribute = match candidates
+                .iter()
+                .find(|attribute| attribute.elements.contains(&module))
+            {
+                Some(attribute) => attribute,
+                None => {
+                    match candidates.first() {
+                        Some(other) => {
+                            misplaced += 1;
+                            findings.push(format!(
+                                "`{name}` is `{}::{}` in domatt, which doesn't apply to `{module}`",
+                                other.module, other.name
+                            ));
+                        }
+                        None => {
+                            missing += 1;
+                            findings.push(format!("missing attribute `{name}`"));
+                        }
+                    }
+                    continue;
+                }
+            };
+
+            let union = Union::of(&member.ty, declarations);
+            if union.keywords.is_empty() || attribute.value.is_none() {
+                continue;
+            }
+            match inventory.value_enum(attribute) {
+                Some(value) => {
+                    let lacking: Vec<String> = union
+                        .keywords
+                        .iter()
+                        .filter(|keyword| {
+                            !value
+                                .keywords
+                                .iter()
+                                .any(|known| match value.case_sensitive {
+                                    true => known == *keyword,
+                                    false => known.eq_ignore_ascii_case(keyword),
+                                })
+                        })
+                        .map(|keyword| format!("\"{keyword}\""))
+                        .collect();
+                    if !lacking.is_empty() {
+                        keywords += 1;
+                        findings.push(format!(
+                            "`{}` lacks the keywords {} in `{}`",
+                            attribute.key,
+                            lacking.join(", "),
+                            value.name
+                        ));
+                    }
+                }
+                None if !union.open => {
+                    keywords += 1;
+                    let listed: Vec<String> = union
+                        .keywords
+                        .iter()
+                        .map(|keyword| format!("\"{keyword}\""))
+                        .collect();
+                    findings.push(format!(
+                        "`{}` takes `{}` in domatt, React has the keywords {}",
+                        attribute.key,
+                        attribute.value.as_ref().map(type_name).unwrap_or_default(),
+                        listed.join(", ")
+                    ));
+                }
+                None => {}
+            }
+        }
+
+        if findings.is_empty() {
+            continue;
+        }
+        if has_module(&module) {
+            writeln!(out, "{}, module `{module}`:", interface.name).unwrap();
+        } else {
+            writeln!(out, "{}, no module `{module}`:", interface.name).unwrap();
+        }
+        for finding in findings {
+            writeln!(out, "    {finding}").unwrap();
+        }
+    }
+
+    writeln!(
+        out,
+        "{missing} missing attributes, {misplaced} that don't apply, {keywords} with missing keywords"
+    )
+    .unwrap();
+    out
+}
+
+/// Returns the members of an interface, including those of the interfaces it extends
+/// that have no module of their own.
+fn members<'a>(
+    interface: &'a Interface,
+    declarations: &'a Declarations,
+    has_module: &dyn Fn(&str) -> bool,
+) -> Vec<&'a Member> {
+    let mut members: Vec<&Member> = interface.members.iter().collect();
+    for extended in &interface.extends {
+        if !is_attribute_interface(extended) || has_module(&module_name(extended)) {
+            continue;
+        }
+        if let Some(extended) = declarations.interface(extended) {
+            for member in self::members(extended, declarations, has_module) {
+                if !members.iter().any(|known| known.name == member.name) {
+                    members.push(member);
+                }
+            }
+        }
+    }
+    members
+}
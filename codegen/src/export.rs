@@ -0,0 +1,475 @@
+//! Exports the attributes and keyword enums as TypeScript declarations and a JSON
+//! Schema, so that TypeScript code and validators accept the same values as the Rust
+//! types.
+//!
+//! Each enum becomes a union of its keywords, and each attribute module an interface
+//! or object schema named after its trait, with a property for every attribute that
+//! applies to it. In TypeScript, `AriaAttributes` is extended by `GlobalAttributes`,
+//! which is extended by the interface of each element; the object schemas repeat the
+//! inherited properties instead.
+
+use crate::inventory::{AttributeInfo, Constraint, Inventory, ValueInfo};
+use crate::spec::{ModuleEntry, Modules};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+
+/// What a value type accepts, as far as TypeScript and JSON Schema can tell.
+enum Kind<'a> {
+    Boolean,
+    Integer,
+    Number,
+    NumberOrString,
+    String,
+    Keywords(&'a ValueInfo),
+    /// `KeywordOr<K, V>`: one of the keywords or a value of another kind.
+    KeywordsOr(&'a ValueInfo, Box<Kind<'a>>),
+    /// `Option<T>`: the attribute may also be set without a value, written as `true`.
+    Optional(Box<Kind<'a>>),
+}
+
+fn kind<'a>(inventory: &'a Inventory, ty: &syn::Type, depth: usize) -> Kind<'a> {
+    let segment = match ty {
+        syn::Type::Path(path) if depth < 8 => match path.path.segments.last() {
+            Some(segment) => segment,
+            None => return Kind::String,
+        },
+        _ => return Kind::String,
+    };
+    let name = segment.ident.to_string();
+    if let Some(value) = inventory.value_named(&name) {
+        return Kind::Keywords(value);
+    }
+    match name.as_str() {
+        "KeywordOr" => {
+            let mut args = match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => args.args.iter(),
+                _ => return Kind::String,
+            };
+            match (args.next(), args.next()) {
+                (
+                    Some(syn::GenericArgument::Type(keywords)),
+                    Some(syn::GenericArgument::Type(ty)),
+                ) => match kind(inventory, keywords, depth + 1) {
+                    Kind::Keywords(keywords) => {
+                        Kind::KeywordsOr(keywords, Box::new(kind(inventory, ty, depth + 1)))
+                    }
+                    _ => Kind::String,
+                },
+                _ => Kind::String,
+            }
+        }
+        "Option" => match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) => match args.args.first() {
+                Some(syn::GenericArgument::Type(ty)) => {
+                    Kind::Optional(Box::new(kind(inventory, ty, depth + 1)))
+                }
+                _ => Kind::String,
+            },
+            _ => Kind::String,
+        },
+        "bool" => Kind::Boolean,
+        "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize" => {
+            Kind::Integer
+        }
+        "f32" | "f64" | "Number" => Kind::Number,
+        "NumberOrString" | "InputBound" | "NumberOptionalNumber" => Kind::NumberOrString,
+        name => match inventory.alias(name) {
+            Some(target) => kind(inventory, target, depth + 1),
+            None => Kind::String,
+        },
+    }
+}
+
+fn attribute_kind<'a>(inventory: &'a Inventory, attribute: &AttributeInfo) -> Kind<'a> {
+    match &attribute.value {
+        Some(ty) => kind(inventory, ty, 0),
+        None if attribute.boolean => Kind::Boolean,
+        // A hand-written `new` that serializes the value itself.
+        None => Kind::String,
+    }
+}
+
+/// An attribute module as an interface, with the properties it doesn't inherit.
+struct Interface<'a> {
+    name: String,
+    base: Option<String>,
+    /// Its properties, leaving out those it inherits with the same schema.
+    properties: BTreeMap<&'a str, &'a AttributeInfo>,
+    /// The inherited properties that it gives another schema, which the base has to
+    /// leave out.
+    narrowed: Vec<&'a str>,
+}
+
+/// Returns an interface for every attribute module, bases first.
+fn interfaces<'a>(inventory: &'a Inventory, modules: &'a Modules) -> Vec<Interface<'a>> {
+    let mut entries: Vec<&ModuleEntry> = modules.modules.iter().collect();
+    entries.sort_by_key(|entry| (entry.name != "aria", entry.name != "global", &entry.name));
+    let name = |module: &str| -> Option<String> {
+        modules
+            .modules
+            .iter()
+            .find(|entry| entry.name == module)
+            .map(|entry| format!("{}s", entry.trait_name))
+    };
+
+    let mut interfaces: Vec<Interface> = Vec::new();
+    for entry in entries {
+        let base = match entry.name.as_str() {
+            "aria" => None,
+            "global" => name("aria"),
+            _ => name("global"),
+        };
+        let inherited = base
+            .as_ref()
+            .map(|base| all_properties(base, &interfaces))
+            .unwrap_or_default();
+
+        let mut seen = BTreeSet::new();
+        let mut properties = BTreeMap::new();
+        let mut narrowed = Vec::new();
+        for attribute in &inventory.attributes {
+            let key = attribute.key.as_str();
+            if !attribute.elements.contains(&entry.name) || !seen.insert(key) {
+                continue;
+            }
+            match inherited.get(key) {
+                Some(base)
+                    if value_schema(inventory, base) == value_schema(inventory, attribute) =>
+                {
+                    continue;
+                }
+                Some(_) => narrowed.push(key),
+                None => {}
+            }
+            properties.insert(key, attribute);
+        }
+        narrowed.sort_unstable();
+
+        interfaces.push(Interface {
+            name: format!("{}s", entry.trait_name),
+            base,
+            properties,
+            narrowed,
+        });
+    }
+    interfaces
+}
+
+fn find<'i, 'a>(interfaces: &'i [Interface<'a>], name: &str) -> &'i Interface<'a> {
+    interfaces
+        .iter()
+        .find(|interface| interface.name == name)
+        .expect("bases to come first")
+}
+
+/// Returns the properties of an interface along with those it inherits.
+fn all_properties<'a>(
+    name: &str,
+    interfaces: &[Interface<'a>],
+) -> BTreeMap<&'a str, &'a AttributeInfo> {
+    let interface = find(interfaces, name);
+    let mut properties = match &interface.base {
+        Some(base) => all_properties(base, interfaces),
+        None => BTreeMap::new(),
+    };
+    properties.extend(&interface.properties);
+    properties
+}
+
+fn sorted_values(inventory: &Inventory) -> Vec<&ValueInfo> {
+    let mut values: Vec<&ValueInfo> = inventory.values.iter().collect();
+    values.sort_by(|a, b| a.name.cmp(&b.name));
+    values
+}
+
+/// Returns the TypeScript declarations.
+pub fn typescript(inventory: &Inventory, modules: &Modules) -> String {
+    let mut out = String::from(
+        "// @generated by `cargo run -p codegen` from the attributes and enums in src/.\n",
+    );
+
+    for value in sorted_values(inventory) {
+        let mut members: Vec<String> = value
+            .keywords
+            .iter()
+            .map(|keyword| json!(keyword).to_string())
+            .collect();
+        if value.custom {
+            members.push("(string & {})".to_owned());
+        }
+
+        out.push('\n');
+        if let Some(mdn) = &value.mdn {
+            writeln!(out, "/** {mdn} */").unwrap();
+        }
+        let line = format!("export type {} = {};", value.name, members.join(" | "));
+        if line.len() <= 100 {
+            writeln!(out, "{line}").unwrap();
+        } else {
+            writeln!(out, "export type {} =", value.name).unwrap();
+            for (i, member) in members.iter().enumerate() {
+                let end = if i + 1 == members.len() { ";" } else { "" };
+                writeln!(out, "    | {member}{end}").unwrap();
+            }
+        }
+    }
+
+    for interface in interfaces(inventory, modules) {
+        out.push('\n');
+        write!(out, "export interface {}", interface.name).unwrap();
+        if let Some(base) = &interface.base {
+            if interface.narrowed.is_empty() {
+                write!(out, " extends {base}").unwrap();
+            } else {
+                let narrowed: Vec<String> = interface
+                    .narrowed
+                    .iter()
+                    .map(|key| json!(key).to_string())
+                    .collect();
+                write!(out, " extends Omit<{base}, {}>", narrowed.join(" | ")).unwrap();
+            }
+        }
+        if interface.properties.is_empty() {
+            out.push_str(" {}\n");
+            continue;
+        }
+
+        out.push_str(" {\n");
+        for (key, attribute) in &interface.properties {
+            if let Some(mdn) = &attribute.mdn {
+                writeln!(out, "    /** {mdn} */").unwrap();
+            }
+            let ty = ts_type(&attribute_kind(inventory, attribute));
+            writeln!(out, "    {}?: {ty};", json!(key)).unwrap();
+        }
+        out.push_str("}\n");
+    }
+    out
+}
+
+fn ts_type(kind: &Kind) -> String {
+    match kind {
+        Kind::Boolean => "boolean".to_owned(),
+        Kind::Integer | Kind::Number => "number".to_owned(),
+        Kind::NumberOrString => "number | string".to_owned(),
+        Kind::String => "string".to_owned(),
+        Kind::Keywords(value) => value.name.clone(),
+        Kind::KeywordsOr(value, kind) => format!("{} | {}", value.name, ts_type(kind)),
+        Kind::Optional(kind) => format!("{} | true", ts_type(kind)),
+    }
+}
+
+/// Returns the JSON Schema, with a definition for every enum and attribute module.
+///
+/// An object schema refers to the one of its base, like an interface extends it. The
+/// properties that an element narrows can't be left out of a reference, so a base with
+/// such properties has a `common` definition without them, which the elements refer to
+/// instead while repeating the properties they don't narrow.
+pub fn json_schema(inventory: &Inventory, modules: &Modules) -> String {
+    let mut definitions = Map::new();
+    for value in sorted_values(inventory) {
+        let keywords = json!({ "enum": value.keywords });
+        let mut schema = if value.custom {
+            json!({ "anyOf": [keywords, { "type": "string" }] })
+        } else {
+            keywords
+        };
+        if let Some(mdn) = &value.mdn {
+            schema["description"] = json!(mdn);
+        }
+        definitions.insert(value.name.clone(), schema);
+    }
+
+    let interfaces = interfaces(inventory, modules);
+    let mut common: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+    for interface in &interfaces {
+        if let Some(base) = &interface.base {
+            common.entry(base).or_default().extend(&interface.narrowed);
+        }
+    }
+
+    let properties = |properties: &mut dyn Iterator<Item = (&&str, &&AttributeInfo)>| {
+        let mut map = Map::new();
+        for (key, attribute) in properties {
+            let mut schema = value_schema(inventory, attribute);
+            if let Some(mdn) = &attribute.mdn {
+                schema["description"] = json!(mdn);
+            }
+            map.insert((*key).to_owned(), schema);
+        }
+        Value::Object(map)
+    };
+
+    for interface in &interfaces {
+        let mut own = interface.properties.clone();
+        let base = interface.base.as_ref().map(|base| {
+            if interface.narrowed.is_empty() {
+                return json!({ "$ref": format!("#/$defs/{base}") });
+            }
+            // The properties that only other elements narrow are left out of `common` as
+            // well, so they are repeated here.
+            let base_properties = all_properties(base, &interfaces);
+            for key in &common[base.as_str()] {
+                if !interface.narrowed.contains(key) {
+                    own.insert(key, base_properties[key]);
+                }
+            }
+            json!({ "$ref": format!("#/$defs/{base}/$defs/common") })
+        });
+
+        let narrowed = common.get(interface.name.as_str());
+        let mut schema = json!({ "type": "object" });
+        match narrowed {
+            Some(narrowed) if !narrowed.is_empty() => {
+                let mut shared = json!({
+                    "type": "object",
+                    "properties": properties(
+                        &mut own.iter().filter(|(key, _)| !narrowed.contains(*key))
+                    ),
+                });
+                if let Some(base) = base {
+                    shared["allOf"] = json!([base]);
+                }
+                schema["$defs"] = json!({ "common": shared });
+                schema["allOf"] =
+                    json!([{ "$ref": format!("#/$defs/{}/$defs/common", interface.name) }]);
+                schema["properties"] =
+                    properties(&mut own.iter().filter(|(key, _)| narrowed.contains(*key)));
+            }
+            _ => {
+                if let Some(base) = base {
+                    schema["allOf"] = json!([base]);
+                }
+                schema["properties"] = properties(&mut own.iter());
+            }
+        }
+        definitions.insert(interface.name.clone(), schema);
+    }
+
+    let schema = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$comment": "@generated by `cargo run -p codegen` from the attributes and enums in src/.",
+        "$defs": definitions,
+    });
+    let mut out = serde_json::to_string_pretty(&schema).expect("the schema to serialize");
+    out.push('\n');
+    out
+}
+
+/// Returns the schema of an attribute's value, without its description.
+fn value_schema(inventory: &Inventory, attribute: &AttributeInfo) -> Value {
+    schema(
+        &attribute_kind(inventory, attribute),
+        &attribute.constraints,
+    )
+}
+
+fn schema(kind: &Kind, constraints: &[Constraint]) -> Value {
+    let mut schema = match kind {
+        Kind::Boolean => json!({ "type": "boolean" }),
+        Kind::Integer => json!({ "type": "integer" }),
+        Kind::Number => json!({ "type": "number" }),
+        Kind::NumberOrString => json!({ "type": ["number", "string"] }),
+        Kind::String => json!({ "type": "string" }),
+        Kind::Keywords(value) => json!({ "$ref": format!("#/$defs/{}", value.name) }),
+        Kind::KeywordsOr(value, kind) => {
+            return json!({
+                "anyOf": [
+                    { "$ref": format!("#/$defs/{}", value.name) },
+                    schema(kind, constraints),
+                ]
+            });
+        }
+        Kind::Optional(kind) => {
+            return json!({ "anyOf": [schema(kind, constraints), { "const": true }] });
+        }
+    };
+
+    let mut patterns = Vec::new();
+    for constraint in constraints {
+        match constraint {
+            Constraint::Range(ranges) => {
+                let ranges: Vec<Value> = ranges
+                    .iter()
+                    .map(|range| {
+                        let mut bounds = Map::new();
+                        if let Some(start) = range.start {
+                            bounds.insert("minimum".to_owned(), number(start));
+                        }
+                        if let Some(end) = range.end {
+                            let bound = if range.inclusive {
+                                "maximum"
+                            } else {
+                                "exclusiveMaximum"
+                            };
+                            bounds.insert(bound.to_owned(), number(end));
+                        }
+                        Value::Object(bounds)
+                    })
+                    .collect();
+                match ranges.as_slice() {
+                    [Value::Object(bounds)] => {
+                        let bounds = bounds.clone();
+                        schema.as_object_mut().unwrap().extend(bounds);
+                    }
+                    _ => schema["anyOf"] = json!(ranges),
+                }
+            }
+            Constraint::NonEmpty => schema["minLength"] = json!(1),
+            Constraint::NoWhitespace => patterns.push("^[^\\t\\n\\f\\r ]*$".to_owned()),
+            Constraint::Chars(classes, literals) => {
+                let mut class = String::new();
+                for name in classes {
+                    class.push_str(char_class(name));
+                }
+                for c in literals {
+                    if matches!(c, '\\' | ']' | '[' | '^' | '-') {
+                        class.push('\\');
+                    }
+                    class.push(*c);
+                }
+                patterns.push(format!("^[{class}]*$"));
+            }
+        }
+    }
+    match patterns.len() {
+        0 => {}
+        1 => schema["pattern"] = json!(patterns[0]),
+        _ => {
+            let patterns: Vec<Value> = patterns
+                .iter()
+                .map(|pattern| json!({ "pattern": pattern }))
+                .collect();
+            schema["allOf"] = json!(patterns);
+        }
+    }
+    schema
+}
+
+/// Writes whole numbers without a fraction, as they appear in the source.
+fn number(value: f64) -> Value {
+    if value.fract() == 0.0 {
+        json!(value as i64)
+    } else {
+        json!(value)
+    }
+}
+
+/// The regular expression character class matching a `char::is_*` method.
+fn char_class(name: &str) -> &'static str {
+    match name {
+        "alphabetic" => "\\p{Alphabetic}",
+        "alphanumeric" => "\\p{Alphabetic}\\p{N}",
+        "numeric" => "\\p{N}",
+        "ascii_alphabetic" => "A-Za-z",
+        "ascii_alphanumeric" => "0-9A-Za-z",
+        "ascii_digit" => "0-9",
+        "ascii_graphic" => "!-~",
+        "ascii_hexdigit" => "0-9A-Fa-f",
+        "ascii_lowercase" => "a-z",
+        "ascii_punctuation" => "!-/:-@\\[-`{-~",
+        "ascii_uppercase" => "A-Z",
+        _ => "",
+    }
+}
@@ -0,0 +1,233 @@
+//! The value grammars of the dataset, and the types they map to.
+//!
+//! Grammars are written in the value definition syntax of the CSS and SVG specs, such
+//! as `auto | <length>`. Productions that domatt has a type for map to that type.
+//! Keywords map to the enum in the module's `values` named after the attribute, such as
+//! `FillRuleOption` for `FillRule`, or to a `KeywordOr` of that enum and a production.
+//! Any other grammar takes a string, through `NumberOrString` if it allows numbers.
+
+use crate::render::parse_case;
+use crate::spec::{Module, ValueItem, Variant, VariantSpec};
+use convert_case::Casing;
+
+/// The path of the value types in `crate::values`.
+const VALUES: &str = "crate::values";
+/// The path of the attribute module's parent, `crate::attributes`.
+const ATTRIBUTES: &str = "super";
+
+/// The productions domatt has a type for, with the type and the path it is imported
+/// from.
+const PRODUCTIONS: &[(&str, &str, Option<&str>)] = &[
+    ("<string>", "String", None),
+    ("<integer>", "i32", None),
+    ("<number>", "Number", Some(VALUES)),
+    ("<number>+", "NumberList", Some(VALUES)),
+    ("<number>{4}", "ViewBoxRect", Some(VALUES)),
+    ("<length>", "Length", Some(VALUES)),
+    ("<length-percentage>", "LengthPercentage", Some(VALUES)),
+    ("<length-percentage>+", "LengthPercentageList", Some(VALUES)),
+    (
+        "<number-optional-number>",
+        "NumberOptionalNumber",
+        Some(VALUES),
+    ),
+    ("<id-reference-list>", "TokenList", Some(VALUES)),
+];
+
+/// Productions whose values are numbers, which make an untyped grammar take
+/// `NumberOrString`.
+const NUMERIC: &[&str] = &[
+    "number",
+    "integer",
+    "percentage",
+    "length",
+    "length-percentage",
+    "angle",
+    "clock-value",
+    "coordinate",
+];
+
+/// A Rust type, and the items it needs imported as `(path, item)`.
+#[derive(Debug)]
+pub struct ValueType {
+    pub name: String,
+    pub imports: Vec<(&'static str, &'static str)>,
+}
+
+impl ValueType {
+    fn new(name: &'static str, path: Option<&'static str>) -> Self {
+        Self {
+            name: name.to_owned(),
+            imports: path.into_iter().map(|path| (path, name)).collect(),
+        }
+    }
+}
+
+/// Returns the type of the value of the attribute `attribute` of `module`, whose
+/// grammar is `grammar`.
+pub fn value_type(grammar: &str, attribute: &str, module: &Module) -> Result<ValueType, String> {
+    let alternatives = alternatives(grammar);
+    match alternatives[..] {
+        ["true", "false"] => return Ok(ValueType::new("bool", None)),
+        ["<number>", "<percentage>"] => {
+            return Ok(ValueType::new("NumberOrPercentage", Some(VALUES)))
+        }
+        _ => {}
+    }
+
+    let (keywords, others): (Vec<&str>, Vec<&str>) = alternatives
+        .iter()
+        .partition(|alternative| is_keyword(alternative));
+    if let Some((name, enum_keywords)) = keyword_enum(attribute, module)? {
+        let mut keywords: Vec<&str> = keywords.iter().map(|keyword| unquote(keyword)).collect();
+        let mut enum_keywords: Vec<&str> = enum_keywords.iter().map(String::as_str).collect();
+        keywords.sort_unstable();
+        enum_keywords.sort_unstable();
+        if keywords != enum_keywords {
+            return Err(format!(
+                "the keywords of `{name}`, {enum_keywords:?}, don't match `{grammar}`"
+            ));
+        }
+
+        return match others[..] {
+            [] => Ok(ValueType {
+                name: name.to_owned(),
+                imports: Vec::new(),
+            }),
+            [other] => {
+                let value = production(other).ok_or_else(|| {
+                    format!("`{other}` has no type to go with the keywords of `{name}`")
+                })?;
+                let mut imports = vec![(VALUES, "KeywordOr")];
+                imports.extend(value.imports);
+                Ok(ValueType {
+                    name: format!("KeywordOr<{name}, {}>", value.name),
+                    imports,
+                })
+            }
+            _ => Err(format!(
+                "`{grammar}` has more than one production besides the keywords of `{name}`"
+            )),
+        };
+    }
+
+    if let [alternative] = alternatives[..] {
+        if let Some(value) = production(alternative) {
+            return Ok(value);
+        }
+    }
+
+    Ok(if allows_numbers(grammar) {
+        ValueType::new("NumberOrString", Some(ATTRIBUTES))
+    } else {
+        ValueType::new("String", None)
+    })
+}
+
+/// Splits a grammar into its top-level alternatives, the parts separated by `|`.
+fn alternatives(grammar: &str) -> Vec<&str> {
+    let bytes = grammar.as_bytes();
+    let mut alternatives = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, byte) in bytes.iter().enumerate() {
+        match byte {
+            b'[' => depth += 1,
+            b']' => depth -= 1,
+            // `||` combines its operands in any order, so it doesn't separate
+            // alternatives.
+            b'|' if depth == 0
+                && bytes.get(i + 1) != Some(&b'|')
+                && (i == 0 || bytes[i - 1] != b'|') =>
+            {
+                alternatives.push(grammar[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    alternatives.push(grammar[start..].trim());
+    alternatives
+}
+
+/// Whether an alternative is a keyword, such as `auto`, `additions text` or `""`.
+fn is_keyword(alternative: &str) -> bool {
+    !alternative.contains(['<', '[', '{'])
+}
+
+fn unquote(keyword: &str) -> &str {
+    keyword
+        .strip_prefix('"')
+        .and_then(|keyword| keyword.strip_suffix('"'))
+        .unwrap_or(keyword)
+}
+
+fn production(alternative: &str) -> Option<ValueType> {
+    PRODUCTIONS
+        .iter()
+        .find(|(production, ..)| *production == alternative)
+        .map(|(_, name, path)| ValueType::new(name, *path))
+}
+
+fn allows_numbers(grammar: &str) -> bool {
+    grammar.split('<').skip(1).any(|production| {
+        let name = production
+            .split(|c: char| c == '>' || c.is_ascii_whitespace())
+            .next()
+            .unwrap_or_default();
+        NUMERIC.contains(&name)
+    })
+}
+
+/// Returns the enum or alias of `module` named `<attribute>Option` or
+/// `<attribute>Keyword`, and its keywords.
+fn keyword_enum<'a>(
+    attribute: &str,
+    module: &'a Module,
+) -> Result<Option<(&'a str, Vec<String>)>, String> {
+    let named = |name: &str| {
+        ["Option", "Keyword"]
+            .iter()
+            .any(|suffix| name.eq_ignore_ascii_case(&format!("{attribute}{suffix}")))
+    };
+
+    for item in &module.values {
+        let (name, target) = match item {
+            ValueItem::Enum(spec) => (&spec.name, &spec.name),
+            ValueItem::Alias(spec) => (&spec.alias, &spec.target),
+        };
+        if !named(name) {
+            continue;
+        }
+
+        let spec = module
+            .values
+            .iter()
+            .find_map(|item| match item {
+                ValueItem::Enum(spec) if &spec.name == target => Some(spec),
+                _ => None,
+            })
+            .ok_or_else(|| format!("`{name}` is an alias of an unknown enum `{target}`"))?;
+        let case = match &spec.case {
+            Some(case) => Some(parse_case(case).ok_or_else(|| format!("unknown case `{case}`"))?),
+            None => None,
+        };
+        let keywords = spec
+            .variants
+            .iter()
+            .map(|variant| match (variant, case) {
+                (
+                    Variant::Full(VariantSpec {
+                        rename: Some(rename),
+                        ..
+                    }),
+                    _,
+                ) => rename.clone(),
+                (variant, Some(case)) => variant.name().to_case(case),
+                (variant, None) => variant.name().to_owned(),
+            })
+            .collect();
+        return Ok(Some((name, keywords)));
+    }
+    Ok(None)
+}
@@ -0,0 +1,611 @@
+//! The attributes and keyword enums that domatt defines, read from the source in `src/`.
+//!
+//! Hand-written and generated modules are read alike, by looking for
+//! `#[derive(Attribute)]` structs, `#[derive(AttributeValue)]` enums and the ways an
+//! attribute is made to apply to an element: `elements(...)`, `pub use` of an attribute
+//! of another module, an `impl` of an element trait and `impl_element_attribute!`.
+
+use crate::render::parse_case;
+use crate::spec::Modules;
+use convert_case::Casing;
+use quote::ToTokens;
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::Path;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Item, Token, UseTree};
+
+mod kw {
+    syn::custom_keyword!(key);
+    syn::custom_keyword!(elements);
+    syn::custom_keyword!(range);
+    syn::custom_keyword!(non_empty);
+    syn::custom_keyword!(no_whitespace);
+    syn::custom_keyword!(chars);
+}
+
+pub struct Inventory {
+    pub attributes: Vec<AttributeInfo>,
+    pub values: Vec<ValueInfo>,
+    /// The `pub type` aliases, by name.
+    aliases: HashMap<String, syn::Type>,
+}
+
+/// A struct deriving `Attribute`.
+pub struct AttributeInfo {
+    /// The path of the defining module below the crate root, such as `attributes::anchor`.
+    pub module: String,
+    pub name: String,
+    pub key: String,
+    /// The type taken by the generated `new`, if there is one.
+    pub value: Option<syn::Type>,
+    /// Whether the attribute is a unit struct, which is set without a value.
+    pub boolean: bool,
+    pub constraints: Vec<Constraint>,
+    pub mdn: Option<String>,
+    /// The attribute modules the attribute applies to. `global` is included for
+    /// attributes of every element, along with each element module.
+    pub elements: BTreeSet<String>,
+}
+
+/// A constraint checked by the generated `try_new`.
+pub enum Constraint {
+    /// The value is in one of the ranges.
+    Range(Vec<Range>),
+    NonEmpty,
+    NoWhitespace,
+    /// Every character belongs to one of the classes, given by the name of a `char`
+    /// method without `is_`, or is one of the literal characters.
+    Chars(Vec<String>, Vec<char>),
+}
+
+pub struct Range {
+    pub start: Option<f64>,
+    pub end: Option<f64>,
+    /// Whether the range is written with `..=`.
+    pub inclusive: bool,
+}
+
+/// An enum deriving `AttributeValue`.
+#[derive(Debug)]
+pub struct ValueInfo {
+    pub name: String,
+    pub mdn: Option<String>,
+    pub keywords: Vec<String>,
+    /// Whether a variant marked `custom` accepts other values as well.
+    pub custom: bool,
+    pub case_sensitive: bool,
+}
+
+impl Inventory {
+    /// Reads every module in `src/`.
+    pub fn read(root: &Path, modules: &Modules) -> Result<Self, String> {
+        let mut files = Vec::new();
+        collect_files(&root.join("src"), &mut Vec::new(), &mut files)?;
+
+        let mut reader = Reader::default();
+        let mut errors = Vec::new();
+        for (module, path) in &files {
+            let source = fs::read_to_string(path)
+                .map_err(|err| format!("couldn't read {}: {err}", path.display()))?;
+            let file = syn::parse_file(&source)
+                .map_err(|err| format!("couldn't parse {}: {err}", path.display()))?;
+            for item in &file.items {
+                if let Err(err) = reader.item(module, item) {
+                    errors.push(format!("{}: {err}", path.display()));
+                }
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors.join("\nerror: "));
+        }
+
+        Ok(reader.finish(modules))
+    }
+
+    /// Returns the keyword enum taken by an attribute, looking through aliases and
+    /// containers such as `Option` and `CommaList`.
+    pub fn value_enum(&self, attribute: &AttributeInfo) -> Option<&ValueInfo> {
+        self.resolve(attribute.value.as_ref()?, 0)
+    }
+
+    /// Returns the type a `pub type` alias stands for.
+    pub fn alias(&self, name: &str) -> Option<&syn::Type> {
+        self.aliases.get(name)
+    }
+
+    /// Returns the keyword enum of the given name, looking through aliases.
+    pub fn value_named(&self, name: &str) -> Option<&ValueInfo> {
+        let mut name = name.to_owned();
+        for _ in 0..8 {
+            if let Some(value) = self.values.iter().find(|value| value.name == name) {
+                return Some(value);
+            }
+            match self.aliases.get(&name) {
+                Some(syn::Type::Path(path)) if path.path.segments.len() == 1 => {
+                    name = path.path.segments[0].ident.to_string();
+                }
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    fn resolve(&self, ty: &syn::Type, depth: usize) -> Option<&ValueInfo> {
+        let segment = match ty {
+            syn::Type::Path(path) if depth < 8 => path.path.segments.last()?,
+            _ => return None,
+        };
+        let name = segment.ident.to_string();
+        if let Some(value) = self.values.iter().find(|value| value.name == name) {
+            return Some(value);
+        }
+        if let Some(target) = self.aliases.get(&name) {
+            return self.resolve(target, depth + 1);
+        }
+        match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0]
+            {
+                syn::GenericArgument::Type(ty) => self.resolve(ty, depth + 1),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Writes a type as in the source, such as `Option<String>`.
+pub fn type_name(ty: &syn::Type) -> String {
+    ty.to_token_stream().to_string().replace(' ', "")
+}
+
+/// Lists the `.rs` files below `dir` with the path of their module.
+fn collect_files(
+    dir: &Path,
+    module: &mut Vec<String>,
+    files: &mut Vec<(String, std::path::PathBuf)>,
+) -> Result<(), String> {
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .map_err(|err| format!("couldn't read {}: {err}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        let stem = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .to_owned();
+        if path.is_dir() {
+            module.push(stem);
+            collect_files(&path, module, files)?;
+            module.pop();
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            let mut segments = module.clone();
+            if !matches!(stem.as_str(), "mod" | "lib" | "main") {
+                segments.push(stem);
+            }
+            files.push((segments.join("::"), path));
+        }
+    }
+    Ok(())
+}
+
+/// The items found so far, before it is known which elements the attributes apply to.
+#[derive(Default)]
+struct Reader {
+    attributes: Vec<AttributeInfo>,
+    values: Vec<ValueInfo>,
+    aliases: HashMap<String, syn::Type>,
+    /// `pub use` of an item of another module: the module, the target module and name.
+    reexports: Vec<(String, String, String)>,
+    /// An element trait or `impl_element_attribute!` element applied to a name, along
+    /// with the module it was written in.
+    applied: Vec<(String, Applied, String)>,
+}
+
+enum Applied {
+    Trait(String),
+    Element(String),
+}
+
+impl Reader {
+    fn item(&mut self, module: &str, item: &Item) -> syn::Result<()> {
+        match item {
+            Item::Struct(item) if derives(&item.attrs, "Attribute") => {
+                let attr = item
+                    .attrs
+                    .iter()
+                    .find(|attr| attr.path.is_ident("attribute"));
+                if let Some(attr) = attr {
+                    let name = item.ident.to_string();
+                    let mut attribute = attr.parse_args_with(|input: ParseStream| {
+                        parse_attribute(input, module, &name)
+                    })?;
+                    attribute.boolean = matches!(item.fields, syn::Fields::Unit);
+                    attribute.mdn = mdn(&item.attrs);
+                    self.attributes.push(attribute);
+                }
+            }
+            Item::Enum(item) if derives(&item.attrs, "AttributeValue") => {
+                self.values.push(parse_value(item)?);
+            }
+            Item::Type(item) if matches!(item.vis, syn::Visibility::Public(_)) => {
+                self.aliases
+                    .insert(item.ident.to_string(), (*item.ty).clone());
+            }
+            Item::Use(item) if matches!(item.vis, syn::Visibility::Public(_)) => {
+                let mut path = Vec::new();
+                self.reexport(module, &item.tree, &mut path);
+            }
+            Item::Impl(item) if item.items.is_empty() => {
+                let trait_name = item
+                    .trait_
+                    .as_ref()
+                    .and_then(|(_, path, _)| path.segments.last());
+                let self_name = match &*item.self_ty {
+                    syn::Type::Path(path) => path.path.segments.last(),
+                    _ => None,
+                };
+                if let (Some(trait_name), Some(self_name)) = (trait_name, self_name) {
+                    self.applied.push((
+                        module.to_owned(),
+                        Applied::Trait(trait_name.ident.to_string()),
+                        self_name.ident.to_string(),
+                    ));
+                }
+            }
+            Item::Macro(item) if item.mac.path.is_ident("impl_element_attribute") => {
+                let args = item
+                    .mac
+                    .parse_body_with(Punctuated::<syn::Ident, Token![,]>::parse_terminated)?;
+                if let [element, name] = args.iter().collect::<Vec<_>>()[..] {
+                    self.applied.push((
+                        module.to_owned(),
+                        Applied::Element(element.to_string()),
+                        name.to_string(),
+                    ));
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn reexport(&mut self, module: &str, tree: &UseTree, path: &mut Vec<String>) {
+        match tree {
+            UseTree::Path(segment) => {
+                path.push(segment.ident.to_string());
+                self.reexport(module, &segment.tree, path);
+                path.pop();
+            }
+            UseTree::Name(name) => {
+                if let Some(target) = resolve_module(module, path) {
+                    self.reexports
+                        .push((module.to_owned(), target, name.ident.to_string()));
+                }
+            }
+            UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.reexport(module, tree, path);
+                }
+            }
+            UseTree::Rename(_) | UseTree::Glob(_) => {}
+        }
+    }
+
+    fn finish(self, modules: &Modules) -> Inventory {
+        let Reader {
+            mut attributes,
+            values,
+            aliases,
+            reexports,
+            applied,
+        } = self;
+
+        // The attributes visible in each module: its own and those it re-exports.
+        let mut visible: HashMap<(String, String), usize> = HashMap::new();
+        for (index, attribute) in attributes.iter().enumerate() {
+            visible.insert((attribute.module.clone(), attribute.name.clone()), index);
+        }
+        for (module, target, name) in &reexports {
+            if let Some(&index) = visible.get(&(target.clone(), name.clone())) {
+                visible.insert((module.clone(), name.clone()), index);
+            }
+        }
+
+        let elements = |element: &str| -> Vec<String> {
+            if element != "global" {
+                return vec![element.to_owned()];
+            }
+            let mut all: Vec<String> = modules
+                .modules
+                .iter()
+                .filter(|module| module.element)
+                .map(|module| module.name.clone())
+                .collect();
+            all.push("global".to_owned());
+            all
+        };
+
+        for attribute in &mut attributes {
+            if let Some(name) = attribute.module.strip_prefix("attributes::") {
+                attribute.elements.insert(name.to_owned());
+            }
+            let named: Vec<String> = attribute.elements.iter().cloned().collect();
+            for element in named {
+                attribute.elements.extend(elements(&element));
+            }
+        }
+        for (module, target, name) in &reexports {
+            if let (Some(element), Some(&index)) = (
+                module.strip_prefix("attributes::"),
+                visible.get(&(target.clone(), name.clone())),
+            ) {
+                attributes[index].elements.insert(element.to_owned());
+            }
+        }
+        for (module, applied, name) in &applied {
+            let index = match visible.get(&(module.clone(), name.clone())) {
+                Some(&index) => index,
+                None => continue,
+            };
+            let element = match applied {
+                Applied::Element(element) => element.clone(),
+                Applied::Trait(trait_name) => {
+                    match modules
+                        .modules
+                        .iter()
+                        .find(|module| &module.trait_name == trait_name)
+                    {
+                        Some(module) => module.name.clone(),
+                        None => continue,
+                    }
+                }
+            };
+            attributes[index].elements.extend(elements(&element));
+        }
+
+        Inventory {
+            attributes,
+            values,
+            aliases,
+        }
+    }
+}
+
+/// Resolves the module part of a `use` path written in `module`.
+fn resolve_module(module: &str, path: &[String]) -> Option<String> {
+    let mut resolved: Vec<&str> = module.split("::").filter(|s| !s.is_empty()).collect();
+    let mut segments = path.iter().map(String::as_str);
+    match segments.next()? {
+        "crate" => resolved.clear(),
+        "self" => {}
+        "super" => {
+            resolved.pop()?;
+        }
+        _ => return None,
+    }
+    for segment in segments {
+        if segment == "super" {
+            resolved.pop()?;
+        } else {
+            resolved.push(segment);
+        }
+    }
+    Some(resolved.join("::"))
+}
+
+fn derives(attrs: &[syn::Attribute], derive: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|path| path.segments.last().is_some_and(|s| s.ident == derive))
+}
+
+/// Parses `#[attribute(...)]` the way the derive does.
+fn parse_attribute(input: ParseStream, module: &str, name: &str) -> syn::Result<AttributeInfo> {
+    let key = if input.peek(kw::key) {
+        input.parse::<kw::key>()?;
+        input.parse::<Token![=]>()?;
+        input.parse::<syn::LitStr>()?.value()
+    } else {
+        let case: syn::LitStr = input.parse()?;
+        let rule = parse_case(&case.value())
+            .ok_or_else(|| syn::Error::new(case.span(), "unknown case"))?;
+        name.to_case(rule)
+    };
+
+    let mut attribute = AttributeInfo {
+        module: module.to_owned(),
+        name: name.to_owned(),
+        key,
+        value: None,
+        boolean: false,
+        constraints: Vec::new(),
+        mdn: None,
+        elements: BTreeSet::new(),
+    };
+    while !input.is_empty() {
+        input.parse::<Token![,]>()?;
+        if input.is_empty() {
+            break;
+        }
+
+        if input.peek(kw::elements) && input.peek2(syn::token::Paren) {
+            input.parse::<kw::elements>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let elements = content.parse_terminated::<_, Token![,]>(syn::Ident::parse)?;
+            attribute
+                .elements
+                .extend(elements.iter().map(ToString::to_string));
+        } else if input.peek(kw::range) && input.peek2(syn::token::Paren) {
+            input.parse::<kw::range>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let ranges = Punctuated::<syn::ExprRange, Token![,]>::parse_terminated(&content)?;
+            let ranges = ranges
+                .iter()
+                .map(|range| {
+                    Ok(Range {
+                        start: range.from.as_deref().map(bound).transpose()?,
+                        end: range.to.as_deref().map(bound).transpose()?,
+                        inclusive: matches!(range.limits, syn::RangeLimits::Closed(_)),
+                    })
+                })
+                .collect::<syn::Result<_>>()?;
+            attribute.constraints.push(Constraint::Range(ranges));
+        } else if input.peek(kw::chars) && input.peek2(syn::token::Paren) {
+            input.parse::<kw::chars>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let (mut classes, mut literals) = (Vec::new(), Vec::new());
+            while !content.is_empty() {
+                if content.peek(syn::LitChar) {
+                    literals.push(content.parse::<syn::LitChar>()?.value());
+                } else {
+                    classes.push(content.parse::<syn::Ident>()?.to_string());
+                }
+                if !content.is_empty() {
+                    content.parse::<Token![,]>()?;
+                }
+            }
+            attribute
+                .constraints
+                .push(Constraint::Chars(classes, literals));
+        } else if input.peek(kw::non_empty) {
+            input.parse::<kw::non_empty>()?;
+            attribute.constraints.push(Constraint::NonEmpty);
+        } else if input.peek(kw::no_whitespace) {
+            input.parse::<kw::no_whitespace>()?;
+            attribute.constraints.push(Constraint::NoWhitespace);
+        } else {
+            attribute.value = Some(input.parse()?);
+        }
+    }
+    Ok(attribute)
+}
+
+/// Reads a numeric bound of a range, such as `-1`.
+fn bound(expr: &syn::Expr) -> syn::Result<f64> {
+    let (negative, lit) = match expr {
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => (true, &**expr),
+        expr => (false, expr),
+    };
+    let value = match lit {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse::<f64>()?,
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Float(lit),
+            ..
+        }) => lit.base10_parse::<f64>()?,
+        expr => return Err(syn::Error::new_spanned(expr, "expected a number")),
+    };
+    Ok(if negative { -value } else { value })
+}
+
+/// Returns the MDN link of the doc comment, written as `<https://developer.mozilla.org/...>`.
+fn mdn(attrs: &[syn::Attribute]) -> Option<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(doc),
+                ..
+            })) => Some(doc.value()),
+            _ => None,
+        })
+        .find_map(|doc| {
+            let start = doc.find("<https://developer.mozilla.org/")?;
+            let end = doc[start..].find('>')?;
+            Some(doc[start + 1..start + end].to_owned())
+        })
+}
+
+/// Reads the keywords of an enum the way the `AttributeValue` derive produces them.
+fn parse_value(item: &syn::ItemEnum) -> syn::Result<ValueInfo> {
+    let mut case = None;
+    let mut case_sensitive = false;
+    for meta in options(&item.attrs)? {
+        match meta {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(meta)) if meta.path.is_ident("case") => {
+                if let syn::Lit::Str(lit) = &meta.lit {
+                    case = Some(
+                        parse_case(&lit.value())
+                            .ok_or_else(|| syn::Error::new(lit.span(), "unknown case"))?,
+                    );
+                }
+            }
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("case_sensitive") => {
+                case_sensitive = true;
+            }
+            _ => {}
+        }
+    }
+
+    let mut value = ValueInfo {
+        mdn: mdn(&item.attrs),
+        name: item.ident.to_string(),
+        keywords: Vec::new(),
+        custom: false,
+        case_sensitive,
+    };
+    for variant in &item.variants {
+        let mut rename = None;
+        for meta in options(&variant.attrs)? {
+            match meta {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(meta))
+                    if meta.path.is_ident("rename") =>
+                {
+                    if let syn::Lit::Str(lit) = &meta.lit {
+                        rename = Some(lit.value());
+                    }
+                }
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("custom") => {
+                    value.custom = true;
+                }
+                _ => {}
+            }
+        }
+        if !matches!(variant.fields, syn::Fields::Unit) {
+            continue;
+        }
+
+        let ident = variant.ident.to_string();
+        value.keywords.push(match (rename, case) {
+            (Some(rename), _) => rename,
+            (None, Some(case)) => ident.to_case(case),
+            (None, None) => ident,
+        });
+    }
+    Ok(value)
+}
+
+/// Returns the options of the `#[attribute_value(...)]` attributes.
+fn options(attrs: &[syn::Attribute]) -> syn::Result<Vec<syn::NestedMeta>> {
+    let mut options = Vec::new();
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("attribute_value"))
+    {
+        if let syn::Meta::List(list) = attr.parse_meta()? {
+            options.extend(list.nested);
+        }
+    }
+    Ok(options)
+}
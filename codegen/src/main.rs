@@ -0,0 +1,286 @@
+//! Generates attribute modules and their wiring from the dataset in `spec/`.
+//!
+//! `spec/modules.json` lists every attribute module with the trait of its element and
+//! the features it needs, from which the features in `Cargo.toml` and the module
+//! declarations and `impl_element_attribute!` arms in `src/attributes/mod.rs` are
+//! generated. A module that also has a `spec/attributes/<module>.json` file is generated
+//! as a whole; the others are written by hand.
+//!
+//! Run `cargo run -p codegen` after editing the dataset. With `--check`, nothing is
+//! written and the generator fails if any generated code is stale.
+//!
+//! The attributes and enums of every module are also exported to `schema/`, as
+//! TypeScript declarations and a JSON Schema.
+//!
+//! `cargo run -p codegen -- dts <file.d.ts>...` compares the attributes in a local copy
+//! of React's type declarations with those domatt defines, and prints what is missing.
+
+mod dts;
+mod export;
+mod grammar;
+mod inventory;
+mod render;
+mod spec;
+
+use dts::Declarations;
+use inventory::Inventory;
+use spec::{Module, Modules};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+
+/// A file and the contents the generator wants it to have.
+struct Output {
+    path: PathBuf,
+    contents: String,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the generator to be in the workspace");
+
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [] => update(root, false),
+        ["--check"] => update(root, true),
+        ["dts", ref paths @ ..] if !paths.is_empty() => compare_dts(root, paths),
+        _ => {
+            eprintln!("usage: codegen [--check | dts <file.d.ts>...]");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Writes the generated code, or only checks that it is up to date.
+fn update(root: &Path, check: bool) -> ExitCode {
+    type Phase = fn(&Path) -> Result<Vec<Output>, String>;
+
+    let mut stale = Vec::new();
+    // The export reads the attribute modules, so it runs after they are written.
+    for phase in [generate as Phase, export] {
+        let outputs = match phase(root) {
+            Ok(outputs) => outputs,
+            Err(err) => {
+                eprintln!("error: {err}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        for output in outputs {
+            let current = fs::read_to_string(&output.path).unwrap_or_default();
+            if current == output.contents {
+                continue;
+            }
+
+            let relative = output.path.strip_prefix(root).unwrap_or(&output.path);
+            if check {
+                stale.push(relative.display().to_string());
+                continue;
+            }
+            let written = match output.path.parent() {
+                Some(dir) => fs::create_dir_all(dir),
+                None => Ok(()),
+            }
+            .and_then(|()| fs::write(&output.path, &output.contents));
+            if let Err(err) = written {
+                eprintln!("error: couldn't write {}: {err}", relative.display());
+                return ExitCode::FAILURE;
+            }
+            println!("updated {}", relative.display());
+        }
+    }
+
+    if stale.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+    eprintln!("generated code is stale, run `cargo run -p codegen` to update:");
+    for path in stale {
+        eprintln!("    {path}");
+    }
+    ExitCode::FAILURE
+}
+
+/// Prints what domatt lacks compared to React's type declarations.
+fn compare_dts(root: &Path, paths: &[&str]) -> ExitCode {
+    let report = || -> Result<String, String> {
+        let modules: Modules = read_json(&root.join("spec/modules.json"))?;
+        let inventory = Inventory::read(root, &modules)?;
+        let mut declarations = Declarations::default();
+        for path in paths {
+            declarations
+                .parse(&read(Path::new(path))?)
+                .map_err(|err| format!("{path}: {err}"))?;
+        }
+        Ok(dts::report(&declarations, &inventory, &modules))
+    };
+
+    match report() {
+        Ok(report) => {
+            print!("{report}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate(root: &Path) -> Result<Vec<Output>, String> {
+    let modules: Modules = read_json(&root.join("spec/modules.json"))?;
+    let mut outputs = Vec::new();
+
+    let spec_dir = root.join("spec/attributes");
+    let mut specs: Vec<PathBuf> = fs::read_dir(&spec_dir)
+        .map_err(|err| format!("couldn't read {}: {err}", spec_dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    specs.sort();
+
+    // Every module is rendered before giving up, so that all mistakes in the dataset
+    // are reported at once.
+    let mut errors = Vec::new();
+    for path in specs {
+        let name = path
+            .file_stem()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        let output = generate_module(root, name, &path, &modules);
+        match output {
+            Ok(output) => outputs.push(output),
+            Err(err) => errors.push(err),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors.join("\nerror: "));
+    }
+
+    let mod_rs = root.join("src/attributes/mod.rs");
+    let contents = read(&mod_rs)?;
+    let contents = splice(
+        &contents,
+        "//",
+        "modules",
+        &render::module_declarations(&modules),
+    )?;
+    let contents = splice(
+        &contents,
+        "//",
+        "elements",
+        &render::element_macro(&modules),
+    )?;
+    outputs.push(Output {
+        path: mod_rs,
+        contents,
+    });
+
+    let manifest = root.join("Cargo.toml");
+    let contents = splice(
+        &read(&manifest)?,
+        "#",
+        "features",
+        &render::features(&modules),
+    )?;
+    outputs.push(Output {
+        path: manifest,
+        contents,
+    });
+
+    Ok(outputs)
+}
+
+/// Returns the TypeScript declarations and JSON Schema of the attributes in `src/`.
+fn export(root: &Path) -> Result<Vec<Output>, String> {
+    let modules: Modules = read_json(&root.join("spec/modules.json"))?;
+    let inventory = Inventory::read(root, &modules)?;
+    Ok(vec![
+        Output {
+            path: root.join("schema/domatt.d.ts"),
+            contents: export::typescript(&inventory, &modules),
+        },
+        Output {
+            path: root.join("schema/domatt.schema.json"),
+            contents: export::json_schema(&inventory, &modules),
+        },
+    ])
+}
+
+fn generate_module(
+    root: &Path,
+    name: &str,
+    path: &Path,
+    modules: &Modules,
+) -> Result<Output, String> {
+    let entry = modules
+        .modules
+        .iter()
+        .find(|entry| entry.name == name)
+        .ok_or_else(|| format!("{name}: not listed in spec/modules.json"))?;
+    let module: Module = read_json(path)?;
+    let source = render::module(name, entry, &module, modules)?;
+
+    Ok(Output {
+        path: root.join(format!("src/attributes/{name}.rs")),
+        contents: rustfmt(&source).map_err(|err| format!("{name}: {err}"))?,
+    })
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("couldn't read {}: {err}", path.display()))
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, String> {
+    serde_json::from_str(&read(path)?).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Replaces the lines between the `@generated begin: <name>` and `@generated end: <name>`
+/// comments of a hand-written file.
+fn splice(source: &str, comment: &str, name: &str, generated: &str) -> Result<String, String> {
+    let begin = format!("{comment} @generated begin: {name}\n");
+    let end = format!("{comment} @generated end: {name}\n");
+    let start = source
+        .find(&begin)
+        .map(|index| index + begin.len())
+        .ok_or_else(|| format!("missing `{}`", begin.trim_end()))?;
+    let stop = source[start..]
+        .find(&end)
+        .map(|index| start + index)
+        .ok_or_else(|| format!("missing `{}`", end.trim_end()))?;
+
+    Ok(format!(
+        "{}{generated}{}",
+        &source[..start],
+        &source[stop..]
+    ))
+}
+
+/// Formats generated source, so that `cargo fmt` leaves it as it is.
+fn rustfmt(source: &str) -> Result<String, String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("couldn't run rustfmt: {err}"))?;
+    child
+        .stdin
+        .take()
+        .expect("stdin to be piped")
+        .write_all(source.as_bytes())
+        .map_err(|err| format!("couldn't run rustfmt: {err}"))?;
+    let output = child
+        .wait_with_output()
+        .map_err(|err| format!("couldn't run rustfmt: {err}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "rustfmt failed on the generated code:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    String::from_utf8(output.stdout).map_err(|err| err.to_string())
+}
@@ -0,0 +1,351 @@
+//! Turns the dataset into Rust and TOML source.
+
+use crate::grammar::{self, ValueType};
+use crate::spec::{
+    AliasSpec, AttributeItem, AttributeSpec, EnumSpec, Module, ModuleEntry, Modules, ValueItem,
+    Variant,
+};
+use convert_case::{Case, Casing};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// The case rules understood by `#[attribute(...)]` and `#[attribute_value(...)]`.
+pub fn parse_case(case: &str) -> Option<Case> {
+    match case {
+        "camelCase" => Some(Case::Camel),
+        "kebab-case" => Some(Case::Kebab),
+        "lowercase" => Some(Case::Flat),
+        _ => None,
+    }
+}
+
+/// Returns the source of a generated attribute module, before formatting.
+pub fn module(
+    name: &str,
+    entry: &ModuleEntry,
+    module: &Module,
+    modules: &Modules,
+) -> Result<String, String> {
+    let mut imports = BTreeMap::new();
+    let mut import = |path: &'static str, item: &'static str| {
+        imports
+            .entry(path)
+            .or_insert_with(BTreeSet::new)
+            .insert(item);
+    };
+    import("super", "Attribute");
+
+    let mut body = format!("\npub trait {}: Attribute {{}}\n", entry.trait_name);
+    let mut errors = Vec::new();
+    for item in &module.attributes {
+        body.push('\n');
+        match item {
+            AttributeItem::Attribute(spec) => match attribute(&mut body, spec, module, modules) {
+                Ok(value) => {
+                    for (path, item) in value.into_iter().flat_map(|value| value.imports) {
+                        import(path, item);
+                    }
+                }
+                Err(err) => errors.push(format!("{name}: attribute `{}`: {err}", spec.name)),
+            },
+            AttributeItem::Reexport { reexport } => {
+                let ident = reexport.rsplit("::").next().unwrap_or(reexport);
+                writeln!(body, "pub use {reexport};").unwrap();
+                writeln!(body, "impl {} for {ident} {{}}", entry.trait_name).unwrap();
+            }
+        }
+    }
+
+    for item in &module.values {
+        body.push('\n');
+        match item {
+            ValueItem::Enum(spec) => {
+                import("super", "AttributeValue");
+                if spec.enumerated.is_some() {
+                    import("crate::values", "EnumeratedValue");
+                }
+                if let Err(err) = value_enum(&mut body, spec) {
+                    errors.push(format!("{name}: enum `{}`: {err}", spec.name));
+                }
+            }
+            ValueItem::Alias(spec) => alias(&mut body, spec),
+        }
+    }
+
+    let mut out = format!(
+        "// @generated by `cargo run -p codegen` from spec/attributes/{name}.json. Edit the\n\
+         // dataset instead and run the generator again.\n\n"
+    );
+    for (path, items) in imports {
+        let items: Vec<&str> = items.into_iter().collect();
+        match items[..] {
+            [item] => writeln!(out, "use {path}::{item};").unwrap(),
+            _ => writeln!(out, "use {path}::{{{}}};", items.join(", ")).unwrap(),
+        }
+    }
+    out.push_str(&body);
+
+    if errors.is_empty() {
+        Ok(out)
+    } else {
+        Err(errors.join("\nerror: "))
+    }
+}
+
+fn docs(out: &mut String, doc: &[String], mdn: Option<&str>) {
+    for line in doc {
+        if line.is_empty() {
+            out.push_str("///\n");
+        } else {
+            writeln!(out, "/// {line}").unwrap();
+        }
+    }
+    if let Some(mdn) = mdn {
+        if !doc.is_empty() {
+            out.push_str("///\n");
+        }
+        writeln!(out, "/// <{mdn}>").unwrap();
+    }
+}
+
+/// Writes an attribute struct, and returns the type of its value.
+fn attribute(
+    out: &mut String,
+    spec: &AttributeSpec,
+    module: &Module,
+    modules: &Modules,
+) -> Result<Option<ValueType>, String> {
+    let key = match (&spec.case, &spec.key) {
+        (Some(case), None) => {
+            let rule = parse_case(case).ok_or_else(|| format!("unknown case `{case}`"))?;
+            check_mdn_key(spec.mdn.as_deref(), &spec.name.to_case(rule))?;
+            format!("\"{case}\"")
+        }
+        (None, Some(key)) => {
+            check_mdn_key(spec.mdn.as_deref(), key)?;
+            format!("key = \"{key}\"")
+        }
+        _ => return Err("needs exactly one of `case` and `key`".to_owned()),
+    };
+
+    for element in &spec.elements {
+        let known = element == "global"
+            || modules
+                .modules
+                .iter()
+                .any(|module| module.element && &module.name == element);
+        if !known {
+            return Err(format!("unknown element `{element}`"));
+        }
+    }
+
+    let value = spec
+        .value
+        .as_deref()
+        .map(|grammar| grammar::value_type(grammar, &spec.name, module))
+        .transpose()?;
+
+    let mut args = vec![key];
+    args.extend(value.as_ref().map(|value| value.name.clone()));
+    args.extend(spec.constraints.iter().cloned());
+    if !spec.elements.is_empty() {
+        args.push(format!("elements({})", spec.elements.join(", ")));
+    }
+
+    let field = match &value {
+        None => None,
+        Some(value) if spec.holds_value => Some(value.name.clone()),
+        Some(_) => Some("String".to_owned()),
+    };
+
+    docs(out, &spec.doc, spec.mdn.as_deref());
+    out.push_str("#[derive(Debug, Attribute)]\n");
+    writeln!(out, "#[attribute({})]", args.join(", ")).unwrap();
+    match field {
+        Some(field) => writeln!(out, "pub struct {}({field});", spec.name).unwrap(),
+        None => writeln!(out, "pub struct {};", spec.name).unwrap(),
+    }
+    for note in &spec.notes {
+        writeln!(out, "// {note}").unwrap();
+    }
+
+    Ok(value)
+}
+
+/// Checks that the key matches the page of the MDN link, which catches misspelled
+/// struct names.
+fn check_mdn_key(mdn: Option<&str>, key: &str) -> Result<(), String> {
+    let page = match mdn.and_then(|mdn| mdn.rsplit('/').next()) {
+        Some(page) => page,
+        None => return Ok(()),
+    };
+    let page = page.split('#').next().unwrap_or(page);
+    let page = page.strip_prefix("attr-").unwrap_or(page);
+    if page.eq_ignore_ascii_case(key) {
+        Ok(())
+    } else {
+        Err(format!("key `{key}` doesn't match the MDN page `{page}`"))
+    }
+}
+
+fn value_enum(out: &mut String, spec: &EnumSpec) -> Result<(), String> {
+    let mut options = Vec::new();
+    if let Some(case) = &spec.case {
+        parse_case(case).ok_or_else(|| format!("unknown case `{case}`"))?;
+        options.push(format!("case = \"{case}\""));
+    }
+    if spec.case_sensitive {
+        options.push("case_sensitive".to_owned());
+    }
+
+    let mut derives = vec!["Debug".to_owned(), "AttributeValue".to_owned()];
+    derives.extend(spec.derives.iter().cloned());
+
+    docs(out, &spec.doc, spec.mdn.as_deref());
+    writeln!(out, "#[derive({})]", derives.join(", ")).unwrap();
+    if !options.is_empty() {
+        writeln!(out, "#[attribute_value({})]", options.join(", ")).unwrap();
+    }
+    writeln!(out, "pub enum {} {{", spec.name).unwrap();
+    for variant in &spec.variants {
+        if let Variant::Full(variant) = variant {
+            for line in &variant.doc {
+                writeln!(out, "    /// {line}").unwrap();
+            }
+            if variant.default {
+                out.push_str("    #[default]\n");
+            }
+            if let Some(rename) = &variant.rename {
+                writeln!(out, "    #[attribute_value(rename = \"{rename}\")]").unwrap();
+            }
+        }
+        writeln!(out, "    {},", variant.name()).unwrap();
+    }
+    out.push_str("}\n");
+
+    if let Some(enumerated) = &spec.enumerated {
+        let variant = |name: &Option<String>| -> Result<String, String> {
+            match name {
+                Some(name) if spec.variants.iter().any(|v| v.name() == name) => {
+                    Ok(format!("Some(Self::{name})"))
+                }
+                Some(name) => Err(format!("unknown variant `{name}`")),
+                None => Ok("None".to_owned()),
+            }
+        };
+
+        writeln!(out, "\nimpl EnumeratedValue for {} {{", spec.name).unwrap();
+        out.push_str("    const KEYWORDS: &'static [(&'static str, Self)] = &[\n");
+        for (keyword, state) in &enumerated.keywords {
+            variant(&Some(state.clone()))?;
+            writeln!(out, "        (\"{keyword}\", Self::{state}),").unwrap();
+        }
+        out.push_str("    ];\n");
+        writeln!(
+            out,
+            "    const MISSING_VALUE_DEFAULT: Option<Self> = {};",
+            variant(&enumerated.missing)?
+        )
+        .unwrap();
+        if let Some(note) = &enumerated.invalid_note {
+            writeln!(out, "    // {note}").unwrap();
+        }
+        writeln!(
+            out,
+            "    const INVALID_VALUE_DEFAULT: Option<Self> = {};",
+            variant(&enumerated.invalid)?
+        )
+        .unwrap();
+        out.push_str("}\n");
+    }
+
+    Ok(())
+}
+
+fn alias(out: &mut String, spec: &AliasSpec) {
+    docs(out, &spec.doc, spec.mdn.as_deref());
+    writeln!(out, "pub type {} = {};", spec.alias, spec.target).unwrap();
+}
+
+/// Returns the `pub mod` declarations of `attributes/mod.rs`.
+pub fn module_declarations(modules: &Modules) -> String {
+    let mut out = String::new();
+    for (i, module) in sorted(modules).into_iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        writeln!(out, "#[cfg(feature = \"{}\")]", module.name).unwrap();
+        writeln!(out, "pub mod {};", module.name).unwrap();
+    }
+    out
+}
+
+/// Returns the `impl_element_attribute!` macro of `attributes/mod.rs`, with an arm for
+/// each element and a `global` arm for all of them.
+pub fn element_macro(modules: &Modules) -> String {
+    let elements: Vec<&ModuleEntry> = sorted(modules)
+        .into_iter()
+        .filter(|module| module.element)
+        .collect();
+
+    let mut out = String::from(
+        "/// Implements the attribute trait of an element module for an attribute type. This is\n\
+         /// what `#[attribute(..., elements(...))]` expands to; `global` stands for every\n\
+         /// element. The arms are generated from spec/modules.json.\n\
+         #[allow(unused_macros)]\n\
+         macro_rules! impl_element_attribute {\n    \
+             (global, $attr_struct:ty) => {\n",
+    );
+    for element in &elements {
+        writeln!(
+            out,
+            "        $crate::attributes::impl_element_attribute!({}, $attr_struct);",
+            element.name
+        )
+        .unwrap();
+    }
+    out.push_str("    };\n");
+    for element in &elements {
+        writeln!(out, "    ({}, $attr_struct:ty) => {{", element.name).unwrap();
+        writeln!(out, "        #[cfg(feature = \"{}\")]", element.name).unwrap();
+        writeln!(
+            out,
+            "        impl $crate::attributes::{}::{} for $attr_struct {{}}",
+            element.name, element.trait_name
+        )
+        .unwrap();
+        out.push_str("    };\n");
+    }
+    out.push_str("}\n\n#[allow(unused_imports)]\npub(crate) use impl_element_attribute;\n");
+    out
+}
+
+/// Returns the features of the attribute modules and `full`, for `Cargo.toml`.
+pub fn features(modules: &Modules) -> String {
+    let mut out = String::new();
+    let mut full = Vec::new();
+    for module in sorted(modules) {
+        let requires: Vec<String> = module
+            .requires
+            .iter()
+            .map(|feature| format!("\"{feature}\""))
+            .collect();
+        writeln!(out, "{} = [{}]", module.name, requires.join(", ")).unwrap();
+        full.push(module.name.as_str());
+    }
+
+    full.extend(modules.full.iter().map(String::as_str));
+    full.sort_unstable();
+    out.push_str("\nfull = [\n");
+    for feature in full {
+        writeln!(out, "    \"{feature}\",").unwrap();
+    }
+    out.push_str("]\n");
+    out
+}
+
+fn sorted(modules: &Modules) -> Vec<&ModuleEntry> {
+    let mut sorted: Vec<&ModuleEntry> = modules.modules.iter().collect();
+    sorted.sort_by(|a, b| a.name.cmp(&b.name));
+    sorted
+}
@@ -0,0 +1,158 @@
+//! The dataset in `spec/`, as read by the generator.
+
+use serde::Deserialize;
+
+/// `spec/modules.json`: every attribute module, whether it is generated or written by
+/// hand.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Modules {
+    /// Features outside of the attribute modules that `full` enables as well.
+    #[serde(default)]
+    pub full: Vec<String>,
+    pub modules: Vec<ModuleEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ModuleEntry {
+    /// The name of the module and of the feature that enables it.
+    pub name: String,
+    /// The trait implemented by the attributes that apply to the module's element.
+    #[serde(rename = "trait")]
+    pub trait_name: String,
+    /// Other features the module needs.
+    #[serde(default)]
+    pub requires: Vec<String>,
+    /// Whether the module stands for an element, which `elements(global)` includes.
+    #[serde(default = "default_true")]
+    pub element: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// `spec/attributes/<module>.json`: the contents of a generated module.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Module {
+    pub attributes: Vec<AttributeItem>,
+    #[serde(default)]
+    pub values: Vec<ValueItem>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum AttributeItem {
+    Attribute(Box<AttributeSpec>),
+    /// An attribute defined in another module that applies to this one as well, such
+    /// as `super::anchor::Href`.
+    Reexport {
+        reexport: String,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AttributeSpec {
+    /// The name of the struct.
+    pub name: String,
+    /// The case rule that turns the name into the key.
+    pub case: Option<String>,
+    /// The key, for keys that no case rule produces, such as `xml:lang`.
+    pub key: Option<String>,
+    /// The grammar of the value, such as `auto | <length>`, which `grammar::value_type`
+    /// maps to the type taken by `new`. Boolean attributes have none.
+    pub value: Option<String>,
+    /// Whether the struct holds the value itself instead of its serialization.
+    #[serde(default)]
+    pub holds_value: bool,
+    /// Constraints on the value, written as in `#[attribute(...)]`, such as `range(1..)`.
+    #[serde(default)]
+    pub constraints: Vec<String>,
+    /// The element modules the attribute applies to.
+    #[serde(default)]
+    pub elements: Vec<String>,
+    pub mdn: Option<String>,
+    #[serde(default)]
+    pub doc: Vec<String>,
+    /// Comments written after the struct.
+    #[serde(default)]
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ValueItem {
+    Enum(Box<EnumSpec>),
+    Alias(AliasSpec),
+}
+
+/// An enum of keywords, derived with `AttributeValue`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EnumSpec {
+    pub name: String,
+    #[serde(default)]
+    pub doc: Vec<String>,
+    pub mdn: Option<String>,
+    /// Derives besides `Debug` and `AttributeValue`, such as `Copy`.
+    #[serde(default)]
+    pub derives: Vec<String>,
+    pub case: Option<String>,
+    #[serde(default)]
+    pub case_sensitive: bool,
+    pub variants: Vec<Variant>,
+    /// The states of an enumerated attribute, implemented with `EnumeratedValue`.
+    pub enumerated: Option<Enumerated>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Variant {
+    Name(String),
+    Full(VariantSpec),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VariantSpec {
+    pub name: String,
+    /// The keyword, if the case rule doesn't produce it.
+    pub rename: Option<String>,
+    #[serde(default)]
+    pub default: bool,
+    #[serde(default)]
+    pub doc: Vec<String>,
+}
+
+impl Variant {
+    pub fn name(&self) -> &str {
+        match self {
+            Variant::Name(name) => name,
+            Variant::Full(spec) => &spec.name,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Enumerated {
+    /// Each keyword and the variant for its state.
+    pub keywords: Vec<(String, String)>,
+    pub missing: Option<String>,
+    pub invalid: Option<String>,
+    /// A comment explaining the invalid value default.
+    pub invalid_note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AliasSpec {
+    pub alias: String,
+    pub target: String,
+    #[serde(default)]
+    pub doc: Vec<String>,
+    pub mdn: Option<String>,
+}
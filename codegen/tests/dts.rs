@@ -0,0 +1,50 @@
+//! Compares the trimmed React declarations in `fixtures/` with domatt.
+
+use std::process::Command;
+
+#[test]
+fn reports_what_domatt_lacks() {
+    let output = Command::new(env!("CARGO_BIN_EXE_codegen"))
+        .arg("dts")
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/react.d.ts"
+        ))
+        .output()
+        .expect("the generator to run");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+
+    let report = String::from_utf8(output.stdout).unwrap();
+    for line in [
+        "AriaAttributes, module `aria`:",
+        "    `aria-autocomplete` lacks the keywords \"sometimes\" in `AriaAutocompleteOption`",
+        "    missing attribute `aria-flavor`",
+        "    `contenteditable` lacks the keywords \"inherit\" in `ContentEditableOptions`",
+        // Inherited from `MediaHTMLAttributes`, which has no module of its own.
+        "AudioHTMLAttributes, module `audio`:",
+        "    missing attribute `mediaGroup`",
+        "IframeHTMLAttributes, no module `iframe`:",
+        "    `name` is `attributes::button::Name` in domatt, which doesn't apply to `iframe`",
+    ] {
+        assert!(
+            report.lines().any(|l| l == line),
+            "no `{line}` in:\n{report}"
+        );
+    }
+
+    // Props that React handles itself, attributes that domatt has, and keywords that
+    // its enums have aren't reported.
+    for absent in [
+        "defaultChecked",
+        "suppressHydrationWarning",
+        "accessKey",
+        "AnchorHTMLAttributes",
+        "MediaHTMLAttributes",
+        "AllHTMLAttributes",
+        "strokeLinecap",
+        "`dir`",
+    ] {
+        assert!(!report.contains(absent), "`{absent}` in:\n{report}");
+    }
+}
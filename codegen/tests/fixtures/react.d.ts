@@ -0,0 +1,107 @@
+// A trimmed copy of the attribute interfaces in @types/react, with some members made
+// up so that each kind of finding is covered.
+
+type Booleanish = boolean | "true" | "false";
+type HTMLAttributeAnchorTarget =
+    | "_self"
+    | "_blank"
+    | "_parent"
+    | "_top"
+    | (string & {});
+
+declare namespace React {
+    interface DOMAttributes<T> {
+        children?: ReactNode | undefined;
+        dangerouslySetInnerHTML?: {
+            __html: string | TrustedHTML;
+        } | undefined;
+        onClick?: MouseEventHandler<T> | undefined;
+    }
+
+    interface AriaAttributes {
+        /** Identifies the currently active element when DOM focus is on a composite widget. */
+        "aria-activedescendant"?: string | undefined;
+        /**
+         * Indicates whether inputting text could trigger display of one or more predictions.
+         */
+        "aria-autocomplete"?: "none" | "inline" | "list" | "both" | "sometimes" | undefined;
+        /** A made-up attribute. */
+        "aria-flavor"?: string | undefined;
+    }
+
+    type AriaRole =
+        | "alert"
+        | "button"
+        | "tablist"
+        | "textbox"
+        | (string & {});
+
+    interface HTMLAttributes<T> extends AriaAttributes, DOMAttributes<T> {
+        // React-specific Attributes
+        defaultChecked?: boolean | undefined;
+        suppressHydrationWarning?: boolean | undefined;
+
+        // Standard HTML Attributes
+        accessKey?: string | undefined;
+        contentEditable?: Booleanish | "inherit" | "plaintext-only" | undefined;
+        dir?: "ltr" | "rtl" | "auto" | undefined;
+        draggable?: Booleanish | undefined;
+        hidden?: boolean | undefined;
+        id?: string | undefined;
+        nonce?: string | undefined;
+        tabIndex?: number | undefined;
+
+        // WAI-ARIA
+        role?: AriaRole | undefined;
+
+        // RDFa Attributes
+        vocab?: string | undefined;
+    }
+
+    interface AnchorHTMLAttributes<T> extends HTMLAttributes<T> {
+        download?: any;
+        href?: string | undefined;
+        hrefLang?: string | undefined;
+        media?: string | undefined;
+        ping?: string | undefined;
+        target?: HTMLAttributeAnchorTarget | undefined;
+        type?: string | undefined;
+        referrerPolicy?: HTMLAttributeReferrerPolicy | undefined;
+    }
+
+    interface MediaHTMLAttributes<T> extends HTMLAttributes<T> {
+        autoPlay?: boolean | undefined;
+        controls?: boolean | undefined;
+        crossOrigin?: CrossOrigin;
+        loop?: boolean | undefined;
+        mediaGroup?: string | undefined;
+        muted?: boolean | undefined;
+        preload?: string | undefined;
+        src?: string | undefined;
+    }
+
+    interface AudioHTMLAttributes<T> extends MediaHTMLAttributes<T> {}
+
+    interface IframeHTMLAttributes<T> extends HTMLAttributes<T> {
+        allow?: string | undefined;
+        name?: string | undefined;
+    }
+
+    interface SVGAttributes<T> extends AriaAttributes, DOMAttributes<T> {
+        className?: string | undefined;
+        accumulate?: "none" | "sum" | undefined;
+        strokeLinecap?: "butt" | "round" | "square" | "inherit" | undefined;
+        xlinkHref?: string | undefined;
+        [key: string]: unknown;
+    }
+
+    interface AllHTMLAttributes<T> extends HTMLAttributes<T> {
+        accept?: string | undefined;
+    }
+}
+
+type HTMLAttributeReferrerPolicy =
+    | ""
+    | "no-referrer"
+    | "origin"
+    | "unsafe-url";
@@ -0,0 +1,24 @@
+//! Fails when the generated code doesn't match the dataset in `spec/`.
+//!
+//! The generator formats its output with `rustfmt`, so the test needs `rustfmt` on
+//! `PATH` (`rustup component add rustfmt`), and fails without it.
+
+use std::process::Command;
+
+#[test]
+fn generated_code_is_up_to_date() {
+    assert!(
+        Command::new("rustfmt").arg("--version").output().is_ok(),
+        "rustfmt is not on PATH; install it with `rustup component add rustfmt`"
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_codegen"))
+        .arg("--check")
+        .output()
+        .expect("the generator to run");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
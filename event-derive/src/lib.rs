@@ -1,4 +1,5 @@
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use syn::{
     parse::{Parse, ParseStream},
     parse_macro_input, Data, DeriveInput,
@@ -8,18 +9,25 @@ extern crate quote;
 
 #[proc_macro_derive(Event, attributes(event_type))]
 pub fn event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_event(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_event(input: DeriveInput) -> syn::Result<TokenStream2> {
     let DeriveInput {
         ident, data, attrs, ..
-    } = parse_macro_input!(input);
+    } = input;
 
-    let field_type = get_field_type(&data);
+    let field_type = get_field_type(&data)?;
 
-    let attribute = get_attribute(&attrs);
-    let EventType(event_type) = syn::parse2(attribute.tokens.clone()).expect("Invalid attribute!");
+    let attribute = get_attribute(&attrs, &ident)?;
+    let EventType(event_type) = attribute.parse_args()?;
 
     let serial = ident.to_string().to_lowercase();
 
-    let output = quote! {
+    Ok(quote! {
         impl #ident {
             pub const KEY: &'static str = #serial;
 
@@ -41,50 +49,58 @@ pub fn event(input: TokenStream) -> TokenStream {
                 self.0.clone()
             }
         }
-    };
-
-    output.into()
+    })
 }
 
-fn get_field_type(data: &Data) -> &syn::Type {
-    let field_type = match &data {
+fn get_field_type(data: &Data) -> syn::Result<&syn::Type> {
+    match &data {
         syn::Data::Struct(struct_data) => match &struct_data.fields {
-            syn::Fields::Named(_) => panic!(
-                "Can only implement Event on a tuple struct. This is a struct with named fields."
-            ),
-            syn::Fields::Unnamed(data) => &data.unnamed.first().expect("there to be a field").ty,
-            syn::Fields::Unit => {
-                panic!("Can only implement Event on a tuple struct. This is a struct unit struct.")
-            }
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(&fields.unnamed[0].ty),
+            syn::Fields::Unnamed(fields) => Err(syn::Error::new_spanned(
+                fields,
+                "Event can only be derived for a tuple struct with a single callback field",
+            )),
+            syn::Fields::Named(fields) => Err(syn::Error::new_spanned(
+                fields,
+                "Event can only be derived for a tuple struct, not a struct with named fields",
+            )),
+            syn::Fields::Unit => Err(syn::Error::new_spanned(
+                struct_data.struct_token,
+                "Event can only be derived for a tuple struct, not a unit struct",
+            )),
         },
-        syn::Data::Enum(_) => panic!(
-            "Can only implement Event for a struct. You are attempting to implement it on an Enum"
-        ),
-        syn::Data::Union(_) => panic!(
-            "Can only implement Event for a struct. You are attempting to implement it on a Union"
-        ),
-    };
-
-    field_type
+        syn::Data::Enum(data) => Err(syn::Error::new_spanned(
+            data.enum_token,
+            "Event can only be derived for a tuple struct, not an enum",
+        )),
+        syn::Data::Union(data) => Err(syn::Error::new_spanned(
+            data.union_token,
+            "Event can only be derived for a tuple struct, not a union",
+        )),
+    }
 }
 
 struct EventType(syn::Path);
 
 impl Parse for EventType {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let content;
-        syn::parenthesized!(content in input);
-
-        let event_type: syn::Path = content.parse()?;
+        let event_type: syn::Path = input.parse()?;
 
         Ok(EventType(event_type))
     }
 }
 
-fn get_attribute<'a>(attrs: &'a Vec<syn::Attribute>) -> &'a syn::Attribute {
-    &attrs
+fn get_attribute<'a>(
+    attrs: &'a [syn::Attribute],
+    ident: &syn::Ident,
+) -> syn::Result<&'a syn::Attribute> {
+    attrs
         .iter()
-        .filter(|a| a.path.segments.len() == 1 && a.path.segments[0].ident == "event_type")
-        .nth(0)
-        .expect("'attribute' attribute required for deriving Attribute!")
+        .find(|a| a.path.is_ident("event_type"))
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                ident,
+                "missing `#[event_type(...)]`, which is required for deriving Event",
+            )
+        })
 }
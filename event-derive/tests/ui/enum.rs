@@ -0,0 +1,9 @@
+use event_derive::Event;
+
+#[derive(Event)]
+#[event_type(web_sys::MouseEvent)]
+pub enum OnClick {
+    Primary,
+}
+
+fn main() {}
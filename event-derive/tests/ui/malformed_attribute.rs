@@ -0,0 +1,7 @@
+use event_derive::Event;
+
+#[derive(Event)]
+#[event_type("MouseEvent")]
+pub struct OnClick(u8);
+
+fn main() {}
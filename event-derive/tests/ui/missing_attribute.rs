@@ -0,0 +1,6 @@
+use event_derive::Event;
+
+#[derive(Event)]
+pub struct OnClick(u8);
+
+fn main() {}
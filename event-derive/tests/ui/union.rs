@@ -0,0 +1,9 @@
+use event_derive::Event;
+
+#[derive(Event)]
+#[event_type(web_sys::MouseEvent)]
+pub union OnClick {
+    callback: u8,
+}
+
+fn main() {}
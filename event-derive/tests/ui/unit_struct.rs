@@ -0,0 +1,7 @@
+use event_derive::Event;
+
+#[derive(Event)]
+#[event_type(web_sys::MouseEvent)]
+pub struct OnClick;
+
+fn main() {}
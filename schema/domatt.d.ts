@@ -0,0 +1,1153 @@
+// @generated by `cargo run -p codegen` from the attributes and enums in src/.
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/accumulate */
+export type AccumulateOption = "none" | "sum";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/additive */
+export type AdditiveOption = "replace" | "sum";
+
+export type AddressType = "shipping" | "billing";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/alignment-baseline */
+export type AlignmentBaselineOption =
+    | "auto"
+    | "baseline"
+    | "before-edge"
+    | "text-before-edge"
+    | "middle"
+    | "central"
+    | "after-edge"
+    | "text-after-edge"
+    | "ideographic"
+    | "alphabetic"
+    | "hanging"
+    | "mathematical"
+    | "inherit";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/allow-reorder */
+export type AllowReorder = "no" | "yes";
+
+/** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/area#attr-shape */
+export type AreaShapeOption = "rect" | "circle" | "poly" | "default";
+
+/** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-autocomplete */
+export type AriaAutocompleteOption = "none" | "inline" | "list" | "both";
+
+/** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-checked */
+export type AriaCheckedOption = "false" | "mixed" | "true" | "undefined";
+
+/** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-current */
+export type AriaCurrentOption = "false" | "true" | "page" | "step" | "location" | "date" | "time";
+
+/** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-dropeffect */
+export type AriaDropEffectOption = "none" | "copy" | "execute" | "link" | "move" | "popup";
+
+/** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-haspopup */
+export type AriaHasPopupOption = "false" | "true" | "menu" | "listbox" | "tree" | "grid" | "dialog";
+
+/** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-invalid */
+export type AriaInvalidOption = "false" | "true" | "grammar" | "spelling";
+
+/** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-live */
+export type AriaLiveOption = "off" | "assertive" | "polite";
+
+/** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-orientation */
+export type AriaOrientationOption = "horizontal" | "vertical";
+
+/** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-relevant */
+export type AriaRelevantOption =
+    | "additions"
+    | "additions removals"
+    | "additions text"
+    | "all"
+    | "removals"
+    | "removals additions"
+    | "removals text"
+    | "text"
+    | "text additions"
+    | "text removals";
+
+/** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles */
+export type AriaRole =
+    | "alert"
+    | "alertdialog"
+    | "application"
+    | "article"
+    | "banner"
+    | "button"
+    | "cell"
+    | "checkbox"
+    | "columnheader"
+    | "combobox"
+    | "complementary"
+    | "contentinfo"
+    | "definition"
+    | "dialog"
+    | "directory"
+    | "document"
+    | "feed"
+    | "figure"
+    | "form"
+    | "grid"
+    | "gridcell"
+    | "group"
+    | "heading"
+    | "img"
+    | "link"
+    | "list"
+    | "listbox"
+    | "listitem"
+    | "log"
+    | "main"
+    | "marquee"
+    | "math"
+    | "menu"
+    | "menubar"
+    | "menuitem"
+    | "menuitemcheckbox"
+    | "menuitemradio"
+    | "navigation"
+    | "none"
+    | "note"
+    | "option"
+    | "presentation"
+    | "progressbar"
+    | "radio"
+    | "radiogroup"
+    | "region"
+    | "row"
+    | "rowgroup"
+    | "rowheader"
+    | "scrollbar"
+    | "search"
+    | "searchbox"
+    | "separator"
+    | "slider"
+    | "spinbutton"
+    | "status"
+    | "switch"
+    | "tab"
+    | "table"
+    | "tablist"
+    | "tabpanel"
+    | "term"
+    | "textbox"
+    | "timer"
+    | "toolbar"
+    | "tooltip"
+    | "tree"
+    | "treegrid"
+    | "treeitem"
+    | (string & {});
+
+/** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-sort */
+export type AriaSortOption = "none" | "ascending" | "descending" | "other";
+
+export type AudioCrossOriginOptions = "anonymous" | "use-credentials";
+
+export type AudioPreloadOptions = "none" | "metadata" | "auto";
+
+export type AutoCapitalizeOptions = "off" | "none" | "on" | "sentences" | "words" | "characters";
+
+export type AutofillField =
+    | "name"
+    | "honorific-prefix"
+    | "given-name"
+    | "additional-name"
+    | "family-name"
+    | "honorific-suffix"
+    | "nickname"
+    | "username"
+    | "new-password"
+    | "current-password"
+    | "one-time-code"
+    | "organization-title"
+    | "organization"
+    | "street-address"
+    | "address-line1"
+    | "address-line2"
+    | "address-line3"
+    | "address-level4"
+    | "address-level3"
+    | "address-level2"
+    | "address-level1"
+    | "country"
+    | "country-name"
+    | "postal-code"
+    | "cc-name"
+    | "cc-given-name"
+    | "cc-additional-name"
+    | "cc-family-name"
+    | "cc-number"
+    | "cc-exp"
+    | "cc-exp-month"
+    | "cc-exp-year"
+    | "cc-csc"
+    | "cc-type"
+    | "transaction-currency"
+    | "transaction-amount"
+    | "language"
+    | "bday"
+    | "bday-day"
+    | "bday-month"
+    | "bday-year"
+    | "sex"
+    | "url"
+    | "photo";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/baseline-shift */
+export type BaselineShiftKeyword = "baseline" | "sub" | "super";
+
+/** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-type */
+export type ButtonTypeOption = "submit" | "reset" | "button";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/clipPathUnits */
+export type ClipPathUnitsOption = "userSpaceOnUse" | "objectBoundingBox";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/clip-rule */
+export type ClipRuleOption = "nonzero" | "evenodd" | "inherit";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color-interpolation-filters */
+export type ColorInterpolationFiltersOption = "auto" | "sRGB" | "linearRGB" | "inherit";
+
+export type ContactField =
+    | "tel"
+    | "tel-country-code"
+    | "tel-national"
+    | "tel-area-code"
+    | "tel-local"
+    | "tel-local-prefix"
+    | "tel-local-suffix"
+    | "tel-extension"
+    | "email"
+    | "impp";
+
+export type ContactType = "home" | "work" | "mobile" | "fax" | "pager";
+
+export type ContentEditableOptions = "true" | "false" | "plaintext-only";
+
+/** https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/crossorigin */
+export type CrossOriginOption = "anonymous" | "use-credentials" | "";
+
+export type DirOptions = "ltr" | "rtl" | "auto";
+
+export type DraggableOption = "true" | "false";
+
+export type EnterKeyHintOption = "enter" | "done" | "go" | "next" | "previous" | "search" | "send";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule */
+export type FillRuleOption = "nonzero" | "evenodd" | "inherit";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/focusable */
+export type FocusableOption = "true" | "false" | "auto";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-size-adjust */
+export type FontSizeAdjustKeyword = "none";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-size */
+export type FontSizeKeyword =
+    | "xx-small"
+    | "x-small"
+    | "small"
+    | "medium"
+    | "large"
+    | "x-large"
+    | "xx-large"
+    | "xxx-large"
+    | "larger"
+    | "smaller"
+    | "math";
+
+export type FormAutocompleteOption = "on" | "off";
+
+/** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-type */
+export type FormEncTypeOption =
+    | "application/x-www-form-urlencoded"
+    | "multipart/form-data"
+    | "text/plain";
+
+/** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-type */
+export type FormMethodOption = "post" | "get";
+
+export type HashAlgorithm = "sha256" | "sha384" | "sha512";
+
+export type HiddenOption = "" | "until-found";
+
+export type InputModeOption =
+    | "none"
+    | "text"
+    | "decimal"
+    | "numeric"
+    | "tel"
+    | "search"
+    | "email"
+    | "url";
+
+/** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#input_types */
+export type InputTypeOption =
+    | "button"
+    | "checkbox"
+    | "color"
+    | "date"
+    | "datetime-local"
+    | "email"
+    | "file"
+    | "hidden"
+    | "image"
+    | "month"
+    | "number"
+    | "password"
+    | "radio"
+    | "range"
+    | "reset"
+    | "search"
+    | "submit"
+    | "tel"
+    | "text"
+    | "time"
+    | "url"
+    | "week";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/kerning */
+export type KerningKeyword = "auto";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/letter-spacing */
+export type LetterSpacingKeyword = "normal";
+
+export type LinkType =
+    | "alternate"
+    | "author"
+    | "bookmark"
+    | "canonical"
+    | "dns-prefetch"
+    | "expect"
+    | "external"
+    | "help"
+    | "icon"
+    | "license"
+    | "manifest"
+    | "modulepreload"
+    | "next"
+    | "nofollow"
+    | "noopener"
+    | "noreferrer"
+    | "opener"
+    | "pingback"
+    | "preconnect"
+    | "prefetch"
+    | "preload"
+    | "prev"
+    | "privacy-policy"
+    | "search"
+    | "stylesheet"
+    | "tag"
+    | "terms-of-service"
+    | (string & {});
+
+export type NonceDirective = "script-src" | "script-src-elem" | "style-src" | "style-src-elem";
+
+export type ReferrerPolicyOption =
+    | "no-referrer"
+    | "no-referrer-when-downgrade"
+    | "origin"
+    | "origin-when-cross-origin"
+    | "same-origin"
+    | "strict-origin"
+    | "strict-origin-when-cross-origin"
+    | "unsafe-url"
+    | "";
+
+export type ShadowRootModeOption = "open" | "closed";
+
+export type SpellcheckOption = "true" | "false";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-linecap */
+export type StrokeLinecapOption = "butt" | "round" | "square" | "inherit";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-linejoin */
+export type StrokeLinejoinOption = "miter" | "round" | "bevel" | "inherit";
+
+export type TargetOption = "_self" | "_blank" | "_parent" | "_top" | (string & {});
+
+/** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/translate */
+export type TranslateOption = "yes" | "no";
+
+export type Type =
+    | "AggregateRating"
+    | "Article"
+    | "BlogPosting"
+    | "Book"
+    | "BreadcrumbList"
+    | "Event"
+    | "ImageObject"
+    | "ListItem"
+    | "LocalBusiness"
+    | "Offer"
+    | "Organization"
+    | "Person"
+    | "Place"
+    | "PostalAddress"
+    | "Product"
+    | "Rating"
+    | "Recipe"
+    | "Review"
+    | "Thing"
+    | "VideoObject"
+    | "WebPage"
+    | "WebSite";
+
+/** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/word-spacing */
+export type WordSpacingKeyword = "normal";
+
+export interface AriaAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-activedescendant */
+    "aria-activedescendant"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-atomic */
+    "aria-atomic"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-autocomplete */
+    "aria-autocomplete"?: AriaAutocompleteOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-busy */
+    "aria-busy"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-checked */
+    "aria-checked"?: AriaCheckedOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-colcount */
+    "aria-colcount"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-colindex */
+    "aria-colindex"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-colspan */
+    "aria-colspan"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-controls */
+    "aria-controls"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-current */
+    "aria-current"?: AriaCurrentOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-describedby */
+    "aria-describedby"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-details */
+    "aria-details"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-disabled */
+    "aria-disabled"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-dropeffect */
+    "aria-dropeffect"?: AriaDropEffectOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-errormessage */
+    "aria-errormessage"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-expanded */
+    "aria-expanded"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-flowto */
+    "aria-flowto"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-grabbed */
+    "aria-grabbed"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-haspopup */
+    "aria-haspopup"?: AriaHasPopupOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-hidden */
+    "aria-hidden"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-invalid */
+    "aria-invalid"?: AriaInvalidOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-keyshortcuts */
+    "aria-keyshortcuts"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-label */
+    "aria-label"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-labelledby */
+    "aria-labelledby"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-level */
+    "aria-level"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-live */
+    "aria-live"?: AriaLiveOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-modal */
+    "aria-modal"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-multiline */
+    "aria-multiline"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-multiselectable */
+    "aria-multiselectable"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-orientation */
+    "aria-orientation"?: AriaOrientationOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-owns */
+    "aria-owns"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-placeholder */
+    "aria-placeholder"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-posinset */
+    "aria-posinset"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-pressed */
+    "aria-pressed"?: AriaCheckedOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-readonly */
+    "aria-readonly"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-relevant */
+    "aria-relevant"?: AriaRelevantOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-required */
+    "aria-required"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-roledescription */
+    "aria-roledescription"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-rowcount */
+    "aria-rowcount"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-rowindex */
+    "aria-rowindex"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-rowspan */
+    "aria-rowspan"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-selected */
+    "aria-selected"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-setsize */
+    "aria-setsize"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-sort */
+    "aria-sort"?: AriaSortOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-valuemax */
+    "aria-valuemax"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-valuemin */
+    "aria-valuemin"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-valuenow */
+    "aria-valuenow"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-valuetext */
+    "aria-valuetext"?: string;
+}
+
+export interface GlobalAttributes extends AriaAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/accesskey */
+    "accesskey"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/accumulate */
+    "accumulate"?: AccumulateOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/additive */
+    "additive"?: AdditiveOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/alignment-baseline */
+    "alignment-baseline"?: AlignmentBaselineOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/amplitude */
+    "amplitude"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/attributeName */
+    "attributeName"?: string;
+    "auto-reverse"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/autocapitalize */
+    "autocapitalize"?: AutoCapitalizeOptions;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/autofocus */
+    "autofocus"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/azimuth */
+    "azimuth"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/baseFrequency */
+    "baseFrequency"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/baseline-shift */
+    "baseline-shift"?: BaselineShiftKeyword | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/begin */
+    "begin"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/bias */
+    "bias"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/calcMode */
+    "calcMode"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/clip-rule */
+    "clip-rule"?: ClipRuleOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/clipPath */
+    "clipPath"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/clipPathUnits */
+    "clipPathUnits"?: ClipPathUnitsOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color */
+    "color"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color-interpolation */
+    "color-interpolation"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color-interpolation-filters */
+    "color-interpolation-filters"?: ColorInterpolationFiltersOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color-rendering */
+    "color-rendering"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/contenteditable */
+    "contenteditable"?: ContentEditableOptions;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/crossorigin */
+    "crossorigin"?: CrossOriginOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/cursor */
+    "cursor"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/cx */
+    "cx"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/cy */
+    "cy"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/d */
+    "d"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/decelerate */
+    "decelerate"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/diffuseConstant */
+    "diffuseConstant"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/dir */
+    "dir"?: DirOptions;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/direction */
+    "direction"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/display */
+    "display"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/divisor */
+    "divisor"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/dominant-baseline */
+    "dominant-baseline"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/draggable */
+    "draggable"?: DraggableOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/dur */
+    "dur"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/dx */
+    "dx"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/dy */
+    "dy"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/edgeMode */
+    "edgeMode"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/elevation */
+    "elevation"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/end */
+    "end"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/enterkeyhint */
+    "enterkeyhint"?: EnterKeyHintOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/exponent */
+    "exponent"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill */
+    "fill"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-opacity */
+    "fill-opacity"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule */
+    "fill-rule"?: FillRuleOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filter */
+    "filter"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filterUnits */
+    "filterUnits"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/flood-color */
+    "flood-color"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/flood-opacity */
+    "flood-opacity"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-family */
+    "font-family"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-size */
+    "font-size"?: FontSizeKeyword | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-size-adjust */
+    "font-size-adjust"?: FontSizeAdjustKeyword | number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-stretch */
+    "font-stretch"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-style */
+    "font-style"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-variant */
+    "font-variant"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-weight */
+    "font-weight"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fr */
+    "fr"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/from */
+    "from"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fx */
+    "fx"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fy */
+    "fy"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/gradientTransform */
+    "gradientTransform"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/gradientUnits */
+    "gradientUnits"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/height */
+    "height"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/hidden */
+    "hidden"?: HiddenOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/id */
+    "id"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/image-rendering */
+    "image-rendering"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/in */
+    "in"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/in2 */
+    "in2"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/inputmode */
+    "inputmode"?: InputModeOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/intercept */
+    "intercept"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/is */
+    "is"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/itemid */
+    "itemid"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/itemprop */
+    "itemprop"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/itemref */
+    "itemref"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/itemscope */
+    "itemscope"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/itemtype */
+    "itemtype"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k */
+    "k"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k1 */
+    "k1"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k2 */
+    "k2"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k3 */
+    "k3"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k4 */
+    "k4"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/kernelMatrix */
+    "kernelMatrix"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/kernelUnitLength */
+    "kernelUnitLength"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/kerning */
+    "kerning"?: KerningKeyword | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/keyPoints */
+    "keyPoints"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/keySplines */
+    "keySplines"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/keyTimes */
+    "keyTimes"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/lang */
+    "lang"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/lengthAdjust */
+    "lengthAdjust"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/letter-spacing */
+    "letter-spacing"?: LetterSpacingKeyword | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/lighting-color */
+    "lighting-color"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/limitingConeAngle */
+    "limitingConeAngle"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/local */
+    "local"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/marker-end */
+    "marker-end"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/marker-mid */
+    "marker-mid"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/marker-start */
+    "marker-start"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/markerHeight */
+    "markerHeight"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/markerUnits */
+    "markerUnits"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/markerWidth */
+    "markerWidth"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/mask */
+    "mask"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/maskContentUnits */
+    "maskContentUnits"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/maskUnits */
+    "maskUnits"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/mathematical */
+    "mathematical"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/max */
+    "max"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/media */
+    "media"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/method */
+    "method"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/min */
+    "min"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/mode */
+    "mode"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/name */
+    "name"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/nonce */
+    "nonce"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/numOctaves */
+    "numOctaves"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/offset */
+    "offset"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/opacity */
+    "opacity"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/operator */
+    "operator"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/order */
+    "order"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/orient */
+    "orient"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/orientation */
+    "orientation"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/origin */
+    "origin"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/overflow */
+    "overflow"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/overline-position */
+    "overline-position"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/overline-thickness */
+    "overline-thickness"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/paint-order */
+    "paint-order"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/panose-1 */
+    "panose-1"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/part */
+    "part"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/path */
+    "path"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pathLength */
+    "pathLength"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/patternContentUnits */
+    "patternContentUnits"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/patternTransform */
+    "patternTransform"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/patternUnits */
+    "patternUnits"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pointer-events */
+    "pointer-events"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/points */
+    "points"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pointsAtX */
+    "pointsAtX"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pointsAtY */
+    "pointsAtY"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pointsAtZ */
+    "pointsAtZ"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/preserveAlpha */
+    "preserveAlpha"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/preserveAspectRatio */
+    "preserveAspectRatio"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/primitiveUnits */
+    "primitiveUnits"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/r */
+    "r"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/radius */
+    "radius"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/refX */
+    "refX"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/refY */
+    "refY"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/rendering-intent */
+    "rendering-intent"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/repeatCount */
+    "repeatCount"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/repeatDur */
+    "repeatDur"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/requiredExtensions */
+    "requiredExtensions"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/requiredFeatures */
+    "requiredFeatures"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/restart */
+    "restart"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/result */
+    "result"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/role */
+    "role"?: AriaRole;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/rotate */
+    "rotate"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/rx */
+    "rx"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/ry */
+    "ry"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/scale */
+    "scale"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/seed */
+    "seed"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/shape-rendering */
+    "shape-rendering"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/slope */
+    "slope"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/slot */
+    "slot"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/spacing */
+    "spacing"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/specularConstant */
+    "specularConstant"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/specularExponent */
+    "specularExponent"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/speed */
+    "speed"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/spellcheck */
+    "spellcheck"?: SpellcheckOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/spreadMethod */
+    "spreadMethod"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/startOffset */
+    "startOffset"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stdDeviation */
+    "stdDeviation"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stemh */
+    "stemh"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stemv */
+    "stemv"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stitchTiles */
+    "stitchTiles"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stop-color */
+    "stop-color"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stop-opacity */
+    "stop-opacity"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/strikethrough-position */
+    "strikethrough-position"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke */
+    "stroke"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-dasharray */
+    "stroke-dasharray"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-dashoffset */
+    "stroke-dashoffset"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-linecap */
+    "stroke-linecap"?: StrokeLinecapOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-linejoin */
+    "stroke-linejoin"?: StrokeLinejoinOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-miterlimit */
+    "stroke-miterlimit"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-opacity */
+    "stroke-opacity"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-width */
+    "stroke-width"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/surfaceScale */
+    "surfaceScale"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/systemLanguage */
+    "systemLanguage"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/tabindex */
+    "tabindex"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/tableValues */
+    "tableValues"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/target */
+    "target"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/targetX */
+    "targetX"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/targetY */
+    "targetY"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/text-anchor */
+    "text-anchor"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/text-decoration */
+    "text-decoration"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/text-rendering */
+    "text-rendering"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/textLength */
+    "textLength"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/title */
+    "title"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/to */
+    "to"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/transform */
+    "transform"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/transform-origin */
+    "transform-origin"?: number | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/translate */
+    "translate"?: TranslateOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/type */
+    "type"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/u1 */
+    "u1"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/u2 */
+    "u2"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/underline-position */
+    "underline-position"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/underline-thickness */
+    "underline-thickness"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/unicode */
+    "unicode"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/unicode-bidi */
+    "unicode-bidi"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/unicode-range */
+    "unicode-range"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/units-per-em */
+    "units-per-em"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/values */
+    "values"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/vector-effect */
+    "vector-effect"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/viewBox */
+    "viewBox"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/visibility */
+    "visibility"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/width */
+    "width"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/word-spacing */
+    "word-spacing"?: WordSpacingKeyword | string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/writing-mode */
+    "writing-mode"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/x */
+    "x"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/x1 */
+    "x1"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/x2 */
+    "x2"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xChannelSelector */
+    "xChannelSelector"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xml:lang */
+    "xml:lang"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/y */
+    "y"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/y1 */
+    "y1"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/y2 */
+    "y2"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/yChannelSelector */
+    "yChannelSelector"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/z */
+    "z"?: number;
+}
+
+export interface AnchorAttributes extends Omit<GlobalAttributes, "target"> {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-download */
+    "download"?: string | true;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-href */
+    "href"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-hreflang */
+    "hreflang"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-ping */
+    "ping"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-referrerpolicy */
+    "referrerpolicy"?: ReferrerPolicyOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-rel */
+    "rel"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-target */
+    "target"?: TargetOption;
+}
+
+export interface AreaAttributes extends Omit<GlobalAttributes, "target"> {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/area#attr-alt */
+    "alt"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/area#attr-coords */
+    "coords"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-download */
+    "download"?: string | true;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-href */
+    "href"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-hreflang */
+    "hreflang"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-referrerpolicy */
+    "referrerpolicy"?: ReferrerPolicyOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/area#attr-rel */
+    "rel"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/area#attr-shape */
+    "shape"?: AreaShapeOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-target */
+    "target"?: TargetOption;
+}
+
+export interface AudioAttributes extends Omit<GlobalAttributes, "crossorigin"> {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/audio#attr-autoplay */
+    "autoplay"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/audio#attr-controls */
+    "controls"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/audio#attr-crossorigin */
+    "crossorigin"?: AudioCrossOriginOptions;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/audio#attr-disableremoteplayback */
+    "disableremoteplayback"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/audio#attr-loop */
+    "loop"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/audio#attr-muted */
+    "muted"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/audio#attr-preload */
+    "preload"?: AudioPreloadOptions;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-src */
+    "src"?: string;
+}
+
+export interface BaseAttributes extends Omit<GlobalAttributes, "target"> {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-href */
+    "href"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-target */
+    "target"?: TargetOption;
+}
+
+export interface BlockQuoteAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/blockquote#attr-cite */
+    "cite"?: string;
+}
+
+export interface ButtonAttributes extends Omit<GlobalAttributes, "type"> {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-disabled */
+    "disabled"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-form */
+    "form"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-formaction */
+    "formaction"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-formenctype */
+    "formenctype"?: FormEncTypeOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-formmethod */
+    "formmethod"?: FormMethodOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-formnovalidate */
+    "formnovalidate"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-formtarget */
+    "formtarget"?: TargetOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-type */
+    "type"?: ButtonTypeOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-value */
+    "value"?: string;
+}
+
+export interface CanvasAttributes extends Omit<GlobalAttributes, "height" | "width"> {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/canvas#attr-height */
+    "height"?: number;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/canvas#attr-width */
+    "width"?: number;
+}
+
+export interface ColAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/col#attr-span */
+    "span"?: number;
+}
+
+export interface ColGroupAttributes extends GlobalAttributes {}
+
+export interface DataAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/data#attr-value */
+    "value"?: string;
+}
+
+export interface DelAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/blockquote#attr-cite */
+    "cite"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/ins#attr-datetime */
+    "datetime"?: string;
+}
+
+export interface DetailsAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/details#attr-open */
+    "open"?: boolean;
+}
+
+export interface FormAttributes extends Omit<GlobalAttributes, "target"> {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form#attr-action */
+    "action"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form#attr-autocomplete */
+    "autocomplete"?: FormAutocompleteOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form#attr-rel */
+    "rel"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-target */
+    "target"?: TargetOption;
+}
+
+export interface InputAttributes extends Omit<GlobalAttributes, "type"> {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#attr-accept */
+    "accept"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/autocomplete */
+    "autocomplete"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#attr-type */
+    "type"?: InputTypeOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#attr-value */
+    "value"?: string;
+}
+
+export interface InsAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/blockquote#attr-cite */
+    "cite"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/ins#attr-datetime */
+    "datetime"?: string;
+}
+
+export interface LiAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/li#attr-value */
+    "value"?: number;
+}
+
+export interface LinkAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-href */
+    "href"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-hreflang */
+    "hreflang"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/link#attr-integrity */
+    "integrity"?: string;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-referrerpolicy */
+    "referrerpolicy"?: ReferrerPolicyOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/link#attr-rel */
+    "rel"?: string;
+}
+
+export interface ScriptAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/link#attr-integrity */
+    "integrity"?: string;
+}
+
+export interface SelectAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/autocomplete */
+    "autocomplete"?: string;
+}
+
+export interface SlotAttributes extends GlobalAttributes {}
+
+export interface SvgAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-href */
+    "href"?: string;
+}
+
+export interface TemplateAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/template#shadowrootclonable */
+    "shadowrootclonable"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/template#shadowrootdelegatesfocus */
+    "shadowrootdelegatesfocus"?: boolean;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/template#shadowrootmode */
+    "shadowrootmode"?: ShadowRootModeOption;
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/template#shadowrootserializable */
+    "shadowrootserializable"?: boolean;
+}
+
+export interface TextAreaAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/autocomplete */
+    "autocomplete"?: string;
+}
+
+export interface TimeAttributes extends GlobalAttributes {
+    /** https://developer.mozilla.org/en-US/docs/Web/HTML/Element/time#attr-datetime */
+    "datetime"?: string;
+}
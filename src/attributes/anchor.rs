@@ -1,21 +1,19 @@
 use super::{Attribute, ReferrerPolicyOption, TargetOption};
+use crate::values::{Href as HrefValue, LinkElement, LinkType, TokenList, ValueError};
 use std::fmt::Debug;
-use strum::{AsRefStr, IntoStaticStr};
-use url::Url;
-use yew::html::IntoPropValue;
 
 pub trait AnchorAttribute: Attribute {}
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-download>
 #[derive(Debug, Attribute)]
 #[attribute("lowercase", Option<String>)]
-pub struct Download(String);
+pub struct Download(Option<String>);
 impl AnchorAttribute for Download {}
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-href>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", String)] // TODO: Make a type definiton that better handles the options of the href attribute
-pub struct Href(String);
+#[attribute("lowercase", HrefValue)]
+pub struct Href(HrefValue);
 
 impl AnchorAttribute for Href {}
 
@@ -29,7 +27,7 @@ impl AnchorAttribute for HrefLang {}
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-ping>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", Vec<Url>)]
+#[attribute("lowercase", TokenList<HrefValue>)]
 pub struct Ping(String);
 impl AnchorAttribute for Ping {}
 
@@ -41,10 +39,19 @@ impl AnchorAttribute for ReferrerPolicy {}
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-rel>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", Vec<ATagRel>)]
+#[attribute("lowercase")]
 pub struct Rel(String);
 impl AnchorAttribute for Rel {}
 
+impl Rel {
+    /// Fails if one of the link types isn't allowed on `a` elements, such as
+    /// `stylesheet`.
+    pub fn new(val: TokenList<LinkType>) -> Result<Self, ValueError> {
+        LinkType::validate(&val, LinkElement::A)?;
+        Ok(Self(val.to_string()))
+    }
+}
+
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-target>
 #[derive(Debug, Attribute)]
 #[attribute("lowercase", TargetOption)]
@@ -58,38 +65,3 @@ impl AnchorAttribute for Target {}
 #[attribute("lowercase", String)]
 pub struct Type(String);
 impl AnchorAttribute for Type {}
-
-/// An enum defining the options for the rel attribute of a link tag.
-///
-/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Link_types>
-#[derive(Debug, AsRefStr, IntoStaticStr)]
-#[strum(serialize_all = "lowercase")]
-pub enum ATagRel {
-    Alternate,
-    Archives,
-    Author,
-    Bookmark,
-    External,
-    First,
-    Help,
-    Index,
-    Last,
-    License,
-    Me,
-    Next,
-    Nofollow,
-    Noopener,
-    Noreferrer,
-    Opener,
-    Prev,
-    Search,
-    Sidebar,
-    Tag,
-    Up,
-}
-
-impl IntoPropValue<&str> for ATagRel {
-    fn into_prop_value(self) -> &'static str {
-        self.into()
-    }
-}
@@ -1,4 +1,8 @@
-use strum::AsRefStr;
+use crate::values::{
+    format_number, impl_attr_value, CommaList, LinkElement, LinkType, Number, TokenList, ValueError,
+};
+use std::fmt::{self, Display};
+use std::str::FromStr;
 
 pub trait AreaAttribute: Attribute {}
 
@@ -8,10 +12,10 @@ pub trait AreaAttribute: Attribute {}
 pub struct Alt(String);
 impl AreaAttribute for Alt {}
 
-/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/area#attr-alt>
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/area#attr-coords>
 #[derive(Debug, Attribute)]
 #[attribute("lowercase", AreaTagShape)]
-pub struct Coords(AreaTagShape);
+pub struct Coords(String);
 impl AreaAttribute for Coords {}
 
 pub use super::anchor::Download;
@@ -26,112 +30,418 @@ impl AreaAttribute for HrefLang {}
 
 pub use super::anchor::ReferrerPolicy;
 
-use super::Attribute;
+use super::{Attribute, AttributeValue};
 impl AreaAttribute for ReferrerPolicy {}
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/area#attr-rel>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", Vec<AreaTagRel>)]
+#[attribute("lowercase")]
 pub struct Rel(String);
 impl AreaAttribute for Rel {}
 
-/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/area#attr-rel>
+impl Rel {
+    /// Fails if one of the link types isn't allowed on `area` elements, such as
+    /// `stylesheet`.
+    pub fn new(val: TokenList<LinkType>) -> Result<Self, ValueError> {
+        LinkType::validate(&val, LinkElement::Area)?;
+        Ok(Self(val.to_string()))
+    }
+}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/area#attr-shape>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", AreaTagShape)]
-pub struct Shape(AreaTagShape);
+#[attribute("lowercase", AreaShapeOption)]
+pub struct Shape(AreaShapeOption);
 impl AreaAttribute for Shape {}
 
 pub use super::anchor::Target;
 impl AreaAttribute for Target {}
 
-/// An enum representing the different options for the both the shape attribute of
-/// an area tag as well as the coordinates.
+/// An enum representing the keywords of the `shape` attribute of an area tag. Use
+/// [AreaTagShape::shape] to get the keyword matching a set of coordinates.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/area#attr-shape>
+#[derive(Debug, AttributeValue, Copy)]
+#[attribute_value(case = "lowercase")]
+pub enum AreaShapeOption {
+    Rect,
+    Circle,
+    Poly,
+    /// The area covers the whole image and has no coordinates.
+    Default,
+}
+
+/// An enum representing the geometry of an area tag. It serializes to the value of the
+/// `coords` attribute, and [AreaTagShape::shape] gives the matching `shape` keyword.
+///
+/// Coordinates are in CSS pixels relative to the image, and may be fractional or
+/// negative. Use [AreaTagShape::scale_to] to recompute them for an image rendered at a
+/// different size than its natural one.
 ///
 /// Shape tag: <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/area#attr-shape>
 ///
 /// Coord tag: <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/area#attr-coords>
-#[derive(Debug, AsRefStr)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AreaTagShape {
     Rect(Rect),
     Circle(Circle),
     Poly(Poly),
 }
 
+impl AreaTagShape {
+    /// Returns the keyword for the `shape` attribute.
+    pub fn shape(&self) -> AreaShapeOption {
+        match self {
+            AreaTagShape::Rect(_) => AreaShapeOption::Rect,
+            AreaTagShape::Circle(_) => AreaShapeOption::Circle,
+            AreaTagShape::Poly(_) => AreaShapeOption::Poly,
+        }
+    }
+
+    /// Checks whether the point at `x`, `y` is inside the shape.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        match self {
+            AreaTagShape::Rect(rect) => rect.contains(x, y),
+            AreaTagShape::Circle(circle) => circle.contains(x, y),
+            AreaTagShape::Poly(poly) => poly.contains(x, y),
+        }
+    }
+
+    /// Returns the smallest rectangle containing the shape.
+    pub fn bounding_box(&self) -> Rect {
+        match self {
+            AreaTagShape::Rect(rect) => rect.bounding_box(),
+            AreaTagShape::Circle(circle) => circle.bounding_box(),
+            AreaTagShape::Poly(poly) => poly.bounding_box(),
+        }
+    }
+
+    /// Scales the shape from an image's natural size to the size it is rendered at.
+    pub fn scale_to(&self, natural: (f64, f64), rendered: (f64, f64)) -> Self {
+        match self {
+            AreaTagShape::Rect(rect) => AreaTagShape::Rect(rect.scale_to(natural, rendered)),
+            AreaTagShape::Circle(circle) => {
+                AreaTagShape::Circle(circle.scale_to(natural, rendered))
+            }
+            AreaTagShape::Poly(poly) => AreaTagShape::Poly(poly.scale_to(natural, rendered)),
+        }
+    }
+}
+
+impl Display for AreaTagShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AreaTagShape::Rect(rect) => rect.fmt(f),
+            AreaTagShape::Circle(circle) => circle.fmt(f),
+            AreaTagShape::Poly(poly) => poly.fmt(f),
+        }
+    }
+}
+
+impl FromStr for AreaTagShape {
+    type Err = ValueError;
+
+    /// Parses the value of the `coords` attribute. The shape is told apart by the number
+    /// of coordinates: three for a circle, whose radius may not be negative, four for a
+    /// rectangle and an even number of at least six for a polygon.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords: CommaList<Number> = s.parse()?;
+        match coords.len() {
+            3 if coord_at(&coords, 2) >= 0.0 => Ok(AreaTagShape::Circle(Circle { coords })),
+            4 => Ok(AreaTagShape::Rect(Rect { coords })),
+            len if len >= 6 && len.is_multiple_of(2) => Ok(AreaTagShape::Poly(Poly { coords })),
+            _ => Err(ValueError::new(s, "Invalid number of coordinates.")),
+        }
+    }
+}
+
+impl_attr_value!(AreaTagShape);
+
+impl From<Rect> for AreaTagShape {
+    fn from(rect: Rect) -> Self {
+        AreaTagShape::Rect(rect)
+    }
+}
+
+impl From<Circle> for AreaTagShape {
+    fn from(circle: Circle) -> Self {
+        AreaTagShape::Circle(circle)
+    }
+}
+
+impl From<Poly> for AreaTagShape {
+    fn from(poly: Poly) -> Self {
+        AreaTagShape::Poly(poly)
+    }
+}
+
 /// Represents a rectangle shape for an html area tag.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Rect {
-    rect: String,
+    coords: CommaList<Number>,
 }
 
 impl Rect {
-    pub fn new(top_right: Coord, bottom_left: Coord) -> Self {
-        let top_right = top_right.as_str();
-        let bottom_left = bottom_left.as_str();
-        let mut rect = String::with_capacity(top_right.len() + bottom_left.len() + 1);
-        rect.push_str(top_right);
-        rect.push(',');
-        rect.push_str(bottom_left);
-        Self { rect }
+    pub fn new(top_left: Coord, bottom_right: Coord) -> Self {
+        Self {
+            coords: coord_list([top_left.x, top_left.y, bottom_right.x, bottom_right.y]),
+        }
     }
 
-    pub fn as_str(&self) -> &str {
-        self.rect.as_str()
+    pub fn top_left(&self) -> Coord {
+        Coord {
+            x: coord_at(&self.coords, 0),
+            y: coord_at(&self.coords, 1),
+        }
+    }
+
+    pub fn bottom_right(&self) -> Coord {
+        Coord {
+            x: coord_at(&self.coords, 2),
+            y: coord_at(&self.coords, 3),
+        }
+    }
+
+    /// Checks whether the point at `x`, `y` is inside the rectangle, edges included.
+    /// Like browsers, corners given in the wrong order are swapped.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        let bounds = self.bounding_box();
+        let (top_left, bottom_right) = (bounds.top_left(), bounds.bottom_right());
+        (top_left.x..=bottom_right.x).contains(&x) && (top_left.y..=bottom_right.y).contains(&y)
+    }
+
+    /// Returns the rectangle with its corners in top left, bottom right order.
+    pub fn bounding_box(&self) -> Rect {
+        let (a, b) = (self.top_left(), self.bottom_right());
+        Rect::new(
+            Coord {
+                x: a.x.min(b.x),
+                y: a.y.min(b.y),
+            },
+            Coord {
+                x: a.x.max(b.x),
+                y: a.y.max(b.y),
+            },
+        )
+    }
+
+    /// Scales the rectangle from an image's natural size to the size it is rendered at.
+    pub fn scale_to(&self, natural: (f64, f64), rendered: (f64, f64)) -> Self {
+        let scale = Scale::new(natural, rendered);
+        Rect::new(
+            scale.coord(self.top_left()),
+            scale.coord(self.bottom_right()),
+        )
+    }
+
+    /// Returns the coordinates in the order they appear in the `coords` attribute.
+    pub fn coords(&self) -> &[Number] {
+        self.coords.as_slice()
+    }
+}
+
+impl Display for Rect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.coords.fmt(f)
     }
 }
 
 /// Represents a circle for an html area tag.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Circle {
-    circle: String,
+    coords: CommaList<Number>,
 }
 
 impl Circle {
-    pub fn new(center: Coord, radius: u16) -> Self {
-        let center = center.as_str();
-        let radius = radius.to_string();
-        let mut circle = String::with_capacity(center.len() + radius.len() + 1);
-        circle.push_str(center);
-        circle.push(',');
-        circle.push_str(&radius);
-        Self { circle }
+    /// Fails if the radius is negative or not finite.
+    pub fn new(center: Coord, radius: f64) -> Result<Self, ValueError> {
+        if !(radius.is_finite() && radius >= 0.0) {
+            return Err(ValueError::new(
+                &format_number(radius),
+                "The radius must be a non-negative number.",
+            ));
+        }
+
+        Ok(Self {
+            coords: coord_list([center.x, center.y, radius]),
+        })
     }
 
-    pub fn as_str(&self) -> &str {
-        self.circle.as_str()
+    pub fn center(&self) -> Coord {
+        Coord {
+            x: coord_at(&self.coords, 0),
+            y: coord_at(&self.coords, 1),
+        }
+    }
+
+    pub fn radius(&self) -> f64 {
+        coord_at(&self.coords, 2)
+    }
+
+    /// Checks whether the point at `x`, `y` is inside the circle, edge included.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        let center = self.center();
+        let dx = x - center.x;
+        let dy = y - center.y;
+        let radius = self.radius();
+        dx * dx + dy * dy <= radius * radius
+    }
+
+    /// Returns the smallest rectangle containing the circle.
+    pub fn bounding_box(&self) -> Rect {
+        let center = self.center();
+        let radius = self.radius();
+        Rect::new(
+            Coord {
+                x: center.x - radius,
+                y: center.y - radius,
+            },
+            Coord {
+                x: center.x + radius,
+                y: center.y + radius,
+            },
+        )
+    }
+
+    /// Scales the circle from an image's natural size to the size it is rendered at.
+    /// Since an area can't be an ellipse, the radius is scaled by the smaller of the
+    /// horizontal and vertical factors so the circle stays within the scaled image.
+    pub fn scale_to(&self, natural: (f64, f64), rendered: (f64, f64)) -> Self {
+        let scale = Scale::new(natural, rendered);
+        let center = scale.coord(self.center());
+        Self {
+            coords: coord_list([center.x, center.y, self.radius() * scale.x.min(scale.y)]),
+        }
+    }
+
+    /// Returns the coordinates in the order they appear in the `coords` attribute.
+    pub fn coords(&self) -> &[Number] {
+        self.coords.as_slice()
     }
 }
 
-/// Represents a polygon for an html area tag.
-#[derive(Debug, Default)]
+impl Display for Circle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.coords.fmt(f)
+    }
+}
+
+/// Represents a polygon for an html area tag. It needs at least three vertices, so
+/// [Poly::new] starts with them and [Poly::add_coord] adds the others.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Poly {
-    poly: String,
+    coords: CommaList<Number>,
 }
 
 impl Poly {
-    pub fn new() -> Self {
+    pub fn new(a: Coord, b: Coord, c: Coord) -> Self {
         Self {
-            poly: String::new(),
+            coords: coord_list([a.x, a.y, b.x, b.y, c.x, c.y]),
         }
     }
 
+    /// Adds a vertex to the polygon.
+    pub fn add_coord(&mut self, coord: Coord) {
+        push_coords(&mut self.coords, [coord.x, coord.y]);
+    }
+
+    #[deprecated(note = "Use `add_coord` instead.")]
     pub fn add_cord(&mut self, coord: Coord) {
-        self.poly.push_str(coord.as_str());
+        self.add_coord(coord);
+    }
+
+    pub fn vertices(&self) -> Vec<Coord> {
+        self.coords
+            .as_slice()
+            .chunks_exact(2)
+            .map(|pair| Coord {
+                x: pair[0].value(),
+                y: pair[1].value(),
+            })
+            .collect()
+    }
+
+    /// Checks whether the point at `x`, `y` is inside the polygon, using the even-odd
+    /// rule like browsers do.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        let vertices = self.vertices();
+        let mut inside = false;
+        let mut previous = match vertices.last() {
+            Some(last) => *last,
+            None => return false,
+        };
+
+        for vertex in vertices {
+            let (x1, y1) = (previous.x, previous.y);
+            let (x2, y2) = (vertex.x, vertex.y);
+            if (y1 > y) != (y2 > y) && x < (x2 - x1) * (y - y1) / (y2 - y1) + x1 {
+                inside = !inside;
+            }
+            previous = vertex;
+        }
+
+        inside
     }
 
-    pub fn as_str(&self) -> &str {
-        self.poly.as_str()
+    /// Returns the smallest rectangle containing every vertex of the polygon.
+    pub fn bounding_box(&self) -> Rect {
+        let vertices = self.vertices();
+        let min = |axis: fn(&Coord) -> f64| vertices.iter().map(axis).fold(f64::INFINITY, f64::min);
+        let max =
+            |axis: fn(&Coord) -> f64| vertices.iter().map(axis).fold(f64::NEG_INFINITY, f64::max);
+        Rect::new(
+            Coord {
+                x: min(Coord::x),
+                y: min(Coord::y),
+            },
+            Coord {
+                x: max(Coord::x),
+                y: max(Coord::y),
+            },
+        )
+    }
+
+    /// Scales the polygon from an image's natural size to the size it is rendered at.
+    pub fn scale_to(&self, natural: (f64, f64), rendered: (f64, f64)) -> Self {
+        let scale = Scale::new(natural, rendered);
+        let mut coords = CommaList::new();
+        for vertex in self.vertices() {
+            let vertex = scale.coord(vertex);
+            push_coords(&mut coords, [vertex.x, vertex.y]);
+        }
+        Self { coords }
+    }
+
+    /// Returns the coordinates in the order they appear in the `coords` attribute.
+    pub fn coords(&self) -> &[Number] {
+        self.coords.as_slice()
     }
 }
 
-impl From<Vec<Coord>> for Poly {
-    fn from(vec: Vec<Coord>) -> Self {
-        let poly = vec
-            .iter()
-            .map(|coord| coord.as_str())
-            .collect::<Vec<&str>>()
-            .join(",");
-        Self { poly }
+impl TryFrom<Vec<Coord>> for Poly {
+    type Error = ValueError;
+
+    /// Fails with fewer than three vertices.
+    fn try_from(vec: Vec<Coord>) -> Result<Self, Self::Error> {
+        match vec.as_slice() {
+            [a, b, c, rest @ ..] => {
+                let mut poly = Self::new(*a, *b, *c);
+                for coord in rest {
+                    poly.add_coord(*coord);
+                }
+                Ok(poly)
+            }
+            _ => Err(ValueError::new(
+                &vec.len().to_string(),
+                "A polygon needs at least three vertices.",
+            )),
+        }
+    }
+}
+
+impl Display for Poly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.coords.fmt(f)
     }
 }
 
@@ -143,51 +453,95 @@ impl From<Vec<Coord>> for Poly {
 /// * [Poly]
 /// * [Rect]
 /// * [Circle]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Coord {
-    coord: String,
+    x: f64,
+    y: f64,
 }
 
 impl Coord {
-    pub fn new(x: u16, y: u16) -> Self {
-        let x = x.to_string();
-        let y = y.to_string();
-        let mut coord = String::with_capacity(x.len() + y.len() + 1);
-        coord.push_str(&x);
-        coord.push(',');
-        coord.push_str(&y);
-        Self { coord }
+    /// Fails if either value is `NaN` or infinite.
+    pub fn new(x: f64, y: f64) -> Result<Self, ValueError> {
+        Number::new(x)?;
+        Number::new(y)?;
+        Ok(Self { x, y })
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
     }
 
-    pub fn as_str(&self) -> &str {
-        &self.coord
+    pub fn y(&self) -> f64 {
+        self.y
     }
 }
 
-/// An enum defining the options for the rel attribute of an area tag.
-///
-/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Link_types>
-#[derive(Debug, AsRefStr)]
-#[strum(serialize_all = "lowercase")]
-pub enum AreaTagRel {
-    Alternate,
-    Archives,
-    Author,
-    Bookmark,
-    External,
-    First,
-    Help,
-    Index,
-    Last,
-    License,
-    Next,
-    Nofollow,
-    Noopener,
-    Noreferrer,
-    Opener,
-    Prev,
-    Search,
-    Sidebar,
-    Tag,
-    Up,
+impl From<(i32, i32)> for Coord {
+    fn from((x, y): (i32, i32)) -> Self {
+        Self {
+            x: x.into(),
+            y: y.into(),
+        }
+    }
+}
+
+impl Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        coord_list([self.x, self.y]).fmt(f)
+    }
+}
+
+/// Builds the comma-separated list of numbers that `coords` takes.
+fn coord_list<const N: usize>(values: [f64; N]) -> CommaList<Number> {
+    let mut coords = CommaList::new();
+    push_coords(&mut coords, values);
+    coords
+}
+
+/// Adds coordinates to a list. Coordinates computed from finite ones, such as scaled
+/// ones, can only overflow, so infinities are clamped to the largest finite number.
+fn push_coords(coords: &mut CommaList<Number>, values: impl IntoIterator<Item = f64>) {
+    for value in values {
+        let number =
+            Number::new(value.clamp(f64::MIN, f64::MAX)).expect("coordinates to be finite");
+        coords
+            .push(number)
+            .expect("a number to be a valid list item");
+    }
+}
+
+fn coord_at(coords: &CommaList<Number>, index: usize) -> f64 {
+    coords.as_slice()[index].value()
+}
+
+/// Horizontal and vertical factors for scaling coordinates from an image's natural
+/// size to its rendered size.
+struct Scale {
+    x: f64,
+    y: f64,
+}
+
+impl Scale {
+    fn new(natural: (f64, f64), rendered: (f64, f64)) -> Self {
+        let factor = |natural: f64, rendered: f64| {
+            let factor = rendered / natural;
+            if factor.is_finite() {
+                factor
+            } else {
+                1.0
+            }
+        };
+
+        Self {
+            x: factor(natural.0, rendered.0),
+            y: factor(natural.1, rendered.1),
+        }
+    }
+
+    fn coord(&self, coord: Coord) -> Coord {
+        Coord {
+            x: coord.x * self.x,
+            y: coord.y * self.y,
+        }
+    }
 }
@@ -1,307 +1,256 @@
-use super::add_impls;
-use super::{
-    anchor::AnchorAttribute, area::AreaAttribute, audio::AudioAttribute, base::BaseAttribute,
-    blockquote::BlockQuoteAttribute, button::ButtonAttribute, canvas::CanvasAttribute,
-    col::ColAttribute, colgroup::ColGroupAttribute, data::DataAttribute, details::DetailsAttribute,
-    li::LiAttribute, svg::SvgAttribute, Attribute,
-};
-use strum::AsRefStr;
+// @generated by `cargo run -p codegen` from spec/attributes/aria.json. Edit the
+// dataset instead and run the generator again.
+
+use super::{Attribute, AttributeValue};
+use crate::values::{EnumeratedValue, Number, TokenList};
 
 pub trait AriaAttribute: Attribute {}
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-activedescendant>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", String)]
+#[attribute("kebab-case", String, elements(global))]
 pub struct AriaActivedescendant(String);
-add_impls!(AriaActivedescendant);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-atomic>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", bool)]
+#[attribute("kebab-case", bool, elements(global))]
 pub struct AriaAtomic(String);
-add_impls!(AriaAtomic);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-autocomplete>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", AriaAutocompleteOption)]
+#[attribute("kebab-case", AriaAutocompleteOption, elements(global))]
 pub struct AriaAutocomplete(AriaAutocompleteOption);
-add_impls!(AriaAutocomplete);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-busy>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", bool)]
+#[attribute("kebab-case", bool, elements(global))]
 pub struct AriaBusy(String);
-add_impls!(AriaBusy);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-checked>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", AriaCheckedOption)]
+#[attribute("kebab-case", AriaCheckedOption, elements(global))]
 pub struct AriaChecked(AriaCheckedOption);
-add_impls!(AriaChecked);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-colcount>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", u8)]
+#[attribute("kebab-case", i32, range(-1..=-1, 1..), elements(global))]
 pub struct AriaColcount(String);
-// TODO: impl TableAttribute, GridAttribute, TreeGridAttribute
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-colindex>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", u8)]
+#[attribute("kebab-case", i32, range(1..), elements(global))]
 pub struct AriaColindex(String);
-// TODO: impl TableAttribute, GridAttribute, TreeGridAttribute
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-colspan>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", u8)]
+#[attribute("kebab-case", i32, range(1..), elements(global))]
 pub struct AriaColspan(String);
-// TODO: impl TableAttribute, GridAttribute, TreeGridAttribute
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-controls>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", Vec<String>)]
+#[attribute("kebab-case", TokenList, elements(global))]
 pub struct AriaControls(String);
-add_impls!(AriaControls);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-current>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", AriaCurrentOption)]
+#[attribute("kebab-case", AriaCurrentOption, elements(global))]
 pub struct AriaCurrent(AriaCurrentOption);
-add_impls!(AriaCurrent);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-describedby>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", Vec<String>)]
+#[attribute("kebab-case", TokenList, elements(global))]
 pub struct AriaDescribedby(String);
-add_impls!(AriaDescribedby);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-details>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", Vec<String>)]
+#[attribute("kebab-case", TokenList, elements(global))]
 pub struct AriaDetails(String);
-add_impls!(AriaDetails);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-disabled>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", bool)]
+#[attribute("kebab-case", bool, elements(global))]
 pub struct AriaDisabled(String);
-add_impls!(AriaDisabled);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-dropeffect>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", AriaDropEffectOption)]
+#[attribute("kebab-case", AriaDropEffectOption, elements(global))]
 pub struct AriaDropeffect(AriaDropEffectOption);
-add_impls!(AriaDropeffect);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-errormessage>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", String)]
+#[attribute("kebab-case", String, elements(input, select, textarea))]
 pub struct AriaErrormessage(String);
-// TODO: impl this for input elements only
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-expanded>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", bool)]
+#[attribute("kebab-case", bool, elements(global))]
 pub struct AriaExpanded(String);
-add_impls!(AriaExpanded);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-flowto>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", Vec<String>)]
+#[attribute("kebab-case", TokenList, elements(global))]
 pub struct AriaFlowto(String);
-add_impls!(AriaFlowto);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-grabbed>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", bool)]
+#[attribute("kebab-case", bool, elements(global))]
 pub struct AriaGrabbed(String);
-add_impls!(AriaGrabbed);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-haspopup>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", AriaHasPopupOption)]
+#[attribute("kebab-case", AriaHasPopupOption, elements(global))]
 pub struct AriaHaspopup(AriaHasPopupOption);
-add_impls!(AriaHaspopup);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-hidden>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", bool)]
+#[attribute("kebab-case", bool, elements(global))]
 pub struct AriaHidden(String);
-add_impls!(AriaHidden);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-invalid>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", AriaHasPopupOption)]
-pub struct AriaInvalid(AriaHasPopupOption);
-// TODO: impl this for input elements
+#[attribute("kebab-case", AriaInvalidOption, elements(global))]
+pub struct AriaInvalid(AriaInvalidOption);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-keyshortcuts>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", String)]
+#[attribute("kebab-case", String, elements(global))]
 pub struct AriaKeyshortcuts(String);
-add_impls!(AriaKeyshortcuts);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-label>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", String)]
+#[attribute("kebab-case", String, elements(global))]
 pub struct AriaLabel(String);
-add_impls!(AriaLabel);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-labelledby>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", Vec<String>)]
+#[attribute(key = "aria-labelledby", TokenList, elements(global))]
 pub struct AriaLabelledBy(String);
-add_impls!(AriaLabelledBy);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-level>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", u8)]
+#[attribute("kebab-case", i32, range(1..), elements(global))]
 pub struct AriaLevel(String);
-add_impls!(AriaLevel);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-live>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", AriaLiveOption)]
+#[attribute("kebab-case", AriaLiveOption, elements(global))]
 pub struct AriaLive(AriaLiveOption);
-add_impls!(AriaLive);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-modal>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", bool)]
+#[attribute("kebab-case", bool, elements(global))]
 pub struct AriaModal(String);
-add_impls!(AriaModal);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-multiline>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", bool)]
+#[attribute("kebab-case", bool, elements(global))]
 pub struct AriaMultiline(String);
-add_impls!(AriaMultiline);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-multiselectable>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", bool)]
+#[attribute("kebab-case", bool, elements(global))]
 pub struct AriaMultiselectable(String);
-add_impls!(AriaMultiselectable);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-orientation>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", AriaOrientationOption)]
+#[attribute("kebab-case", AriaOrientationOption, elements(global))]
 pub struct AriaOrientation(AriaOrientationOption);
-add_impls!(AriaOrientation);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-owns>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", Vec<String>)]
+#[attribute("kebab-case", TokenList, elements(global))]
 pub struct AriaOwns(String);
-add_impls!(AriaOwns);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-placeholder>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", String)]
+#[attribute("kebab-case", String, elements(global))]
 pub struct AriaPlaceholder(String);
-add_impls!(AriaPlaceholder);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-posinset>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", u16)]
+#[attribute("kebab-case", i32, range(1..), elements(global))]
 pub struct AriaPosinset(String);
-add_impls!(AriaPosinset);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-pressed>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", AriaPressedOption)]
+#[attribute("kebab-case", AriaPressedOption, elements(button))]
 pub struct AriaPressed(AriaPressedOption);
-impl ButtonAttribute for AriaPressed {}
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-readonly>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", bool)]
+#[attribute("kebab-case", bool, elements(global))]
 pub struct AriaReadonly(String);
-add_impls!(AriaReadonly);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-relevant>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", AriaRelevantOption)]
+#[attribute("kebab-case", AriaRelevantOption, elements(global))]
 pub struct AriaRelevant(AriaRelevantOption);
-add_impls!(AriaRelevant);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-required>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", bool)]
+#[attribute("kebab-case", bool, elements(input, select, textarea))]
 pub struct AriaRequired(String);
-// TODO: just implement for input element
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-roledescription>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", String)]
+#[attribute("kebab-case", String, elements(global))]
 pub struct AriaRoledescription(String);
-add_impls!(AriaRoledescription);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-rowcount>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", u8)]
+#[attribute("kebab-case", i32, range(-1..=-1, 1..), elements(global))]
 pub struct AriaRowcount(String);
-// TODO: impl TableAttribute, GridAttribute, TreeGridAttribute
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-rowindex>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", u8)]
+#[attribute("kebab-case", i32, range(1..), elements(global))]
 pub struct AriaRowindex(String);
-// TODO: impl TableAttribute, GridAttribute, TreeGridAttribute
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-rowspan>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", u8)]
+#[attribute("kebab-case", i32, range(0..), elements(global))]
 pub struct AriaRowspan(String);
-// TODO: impl TableAttribute, GridAttribute, TreeGridAttribute
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-selected>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", bool)]
+#[attribute("kebab-case", bool, elements(global))]
 pub struct AriaSelected(String);
-add_impls!(AriaSelected);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-setsize>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", i16)]
+#[attribute("kebab-case", i32, range(-1..=-1, 1..), elements(global))]
 pub struct AriaSetsize(String);
-add_impls!(AriaSetsize);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-sort>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", AriaSortOption)]
+#[attribute("kebab-case", AriaSortOption, elements(global))]
 pub struct AriaSort(AriaSortOption);
-add_impls!(AriaSort);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-valuemax>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", i16)]
+#[attribute("kebab-case", Number, elements(global))]
 pub struct AriaValuemax(String);
-add_impls!(AriaValuemax);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-valuemin>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", i16)]
+#[attribute("kebab-case", Number, elements(global))]
 pub struct AriaValuemin(String);
-add_impls!(AriaValuemin);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-valuenow>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", i16)]
+#[attribute("kebab-case", Number, elements(global))]
 pub struct AriaValuenow(String);
-add_impls!(AriaValuenow);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-valuetext>
 #[derive(Debug, Attribute)]
-#[attribute("kebab-case", String)]
+#[attribute("kebab-case", String, elements(global))]
 pub struct AriaValuetext(String);
-add_impls!(AriaValuetext);
 
 /// Models the possible values of the `aria-autocomplete` attribute.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-autocomplete>
-#[derive(Debug, AsRefStr, Default)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue, Default)]
+#[attribute_value(case = "lowercase")]
 pub enum AriaAutocompleteOption {
     #[default]
     None,
@@ -313,8 +262,8 @@ pub enum AriaAutocompleteOption {
 /// Models the possible values of the `aria-checked` attribute.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-checked>
-#[derive(Debug, AsRefStr, Default)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue, Default)]
+#[attribute_value(case = "lowercase")]
 pub enum AriaCheckedOption {
     False,
     Mixed,
@@ -326,8 +275,8 @@ pub enum AriaCheckedOption {
 /// Models the possible values of the `aria-current` attribute.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-current>
-#[derive(Debug, AsRefStr, Default)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue, Default)]
+#[attribute_value(case = "lowercase")]
 pub enum AriaCurrentOption {
     #[default]
     False,
@@ -342,8 +291,8 @@ pub enum AriaCurrentOption {
 /// Models the possible values of the `aria-dropeffect` attribute.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-dropeffect>
-#[derive(Debug, AsRefStr, Default)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue, Default)]
+#[attribute_value(case = "lowercase")]
 pub enum AriaDropEffectOption {
     #[default]
     None,
@@ -357,8 +306,8 @@ pub enum AriaDropEffectOption {
 /// Models the possible values of the `aria-haspopup` attribute.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-haspopup>
-#[derive(Debug, AsRefStr, Default)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue, Default)]
+#[attribute_value(case = "lowercase")]
 pub enum AriaHasPopupOption {
     #[default]
     False,
@@ -373,8 +322,8 @@ pub enum AriaHasPopupOption {
 /// Models the possible values of the `aria-invalid` attribute.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-invalid>
-#[derive(Debug, AsRefStr, Default)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue, Default, Copy)]
+#[attribute_value(case = "lowercase")]
 pub enum AriaInvalidOption {
     #[default]
     False,
@@ -383,11 +332,24 @@ pub enum AriaInvalidOption {
     Spelling,
 }
 
+impl EnumeratedValue for AriaInvalidOption {
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("false", Self::False),
+        ("", Self::False),
+        ("true", Self::True),
+        ("grammar", Self::Grammar),
+        ("spelling", Self::Spelling),
+    ];
+    const MISSING_VALUE_DEFAULT: Option<Self> = Some(Self::False);
+    // Unrecognized values must be treated as if `true` had been provided.
+    const INVALID_VALUE_DEFAULT: Option<Self> = Some(Self::True);
+}
+
 /// Models the possible values of the `aria-live` attribute.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-live>
-#[derive(Debug, AsRefStr, Default)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue, Default)]
+#[attribute_value(case = "lowercase")]
 pub enum AriaLiveOption {
     #[default]
     Off,
@@ -398,8 +360,8 @@ pub enum AriaLiveOption {
 /// Models the possible values of the `aria-orientation` attribute.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-orientation>
-#[derive(Debug, AsRefStr)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue)]
+#[attribute_value(case = "lowercase")]
 pub enum AriaOrientationOption {
     Horizontal,
     Vertical,
@@ -408,38 +370,38 @@ pub enum AriaOrientationOption {
 /// Models the possible values of the `aria-pressed` attribute.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-pressed>
-type AriaPressedOption = AriaCheckedOption;
+pub type AriaPressedOption = AriaCheckedOption;
 
 /// Models the possible values of the `aria-relevant` attribute.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-relevant>
-#[derive(Debug, AsRefStr, Default)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue, Default)]
+#[attribute_value(case = "lowercase")]
 pub enum AriaRelevantOption {
     Additions,
-    #[strum(serialize = "additions removals")]
+    #[attribute_value(rename = "additions removals")]
     AdditionsRemovals,
     #[default]
-    #[strum(serialize = "additions text")]
+    #[attribute_value(rename = "additions text")]
     AdditionsText,
     All,
     Removals,
-    #[strum(serialize = "removals additions")]
+    #[attribute_value(rename = "removals additions")]
     RemovalsAdditions,
-    #[strum(serialize = "removals text")]
+    #[attribute_value(rename = "removals text")]
     RemovalsText,
     Text,
-    #[strum(serialize = "text additions")]
+    #[attribute_value(rename = "text additions")]
     TextAdditions,
-    #[strum(serialize = "text removals")]
+    #[attribute_value(rename = "text removals")]
     TextRemovals,
 }
 
 /// Models the possible values of the `aria-sort` attribute.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-sort>
-#[derive(Debug, AsRefStr, Default)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue, Default)]
+#[attribute_value(case = "lowercase")]
 pub enum AriaSortOption {
     #[default]
     None,
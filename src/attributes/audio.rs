@@ -1,6 +1,5 @@
-use super::Attribute;
-use strum::AsRefStr;
-use url::Url;
+use super::{Attribute, AttributeValue};
+use crate::values::Href;
 
 pub trait AudioAttribute: Attribute {}
 
@@ -48,18 +47,18 @@ impl AudioAttribute for Preload {}
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a#attr-src>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", Url)]
-pub struct Src(String);
+#[attribute("lowercase", Href)]
+pub struct Src(Href);
 
-#[derive(Debug, AsRefStr)]
-#[strum(serialize_all = "kebab-case")]
+#[derive(Debug, AttributeValue)]
+#[attribute_value(case = "kebab-case")]
 pub enum AudioCrossOriginOptions {
     Anonymous,
     UseCredentials,
 }
 
-#[derive(Debug, AsRefStr)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue)]
+#[attribute_value(case = "lowercase")]
 pub enum AudioPreloadOptions {
     None,
     Metadata,
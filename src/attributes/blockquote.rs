@@ -1,10 +1,10 @@
 use super::Attribute;
-use url::Url;
+use crate::values::Href;
 
 pub trait BlockQuoteAttribute: Attribute {}
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/blockquote#attr-cite>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", Url)]
-pub struct Cite(String);
+#[attribute("lowercase", Href)]
+pub struct Cite(Href);
 impl BlockQuoteAttribute for Cite {}
@@ -1,7 +1,6 @@
-use super::{Attribute, TargetOption};
+use super::{Attribute, AttributeValue, TargetOption};
+use crate::values::Href;
 use std::fmt::Debug;
-use strum::AsRefStr;
-use url::Url;
 
 pub trait ButtonAttribute: Debug + Attribute {}
 
@@ -25,8 +24,8 @@ impl ButtonAttribute for Form {}
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-formaction>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", Url)]
-pub struct FormAction(String);
+#[attribute("lowercase", Href)]
+pub struct FormAction(Href);
 impl ButtonAttribute for FormAction {}
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-formenctype>
@@ -74,8 +73,8 @@ impl ButtonAttribute for Value {}
 /// An enum representing the different options for the type attribute of a button element.
 ///
 ///  <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-type>
-#[derive(Debug, AsRefStr)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue)]
+#[attribute_value(case = "lowercase")]
 pub enum ButtonTypeOption {
     Submit,
     Reset,
@@ -85,21 +84,21 @@ pub enum ButtonTypeOption {
 /// An enum representing the different options for the formenctype attribute of a button element.
 ///
 ///  <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-type>
-#[derive(Debug, AsRefStr)]
+#[derive(Debug, AttributeValue)]
 pub enum FormEncTypeOption {
-    #[strum(serialize = "application/x-www-form-urlencoded")]
+    #[attribute_value(rename = "application/x-www-form-urlencoded")]
     Application,
-    #[strum(serialize = "multipart/form-data")]
+    #[attribute_value(rename = "multipart/form-data")]
     Multipart,
-    #[strum(serialize = "text/plain")]
+    #[attribute_value(rename = "text/plain")]
     Text,
 }
 
 /// An enum representing the different options for the formmethod attribute of a button element.
 ///
 ///  <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-type>
-#[derive(Debug, AsRefStr)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue)]
+#[attribute_value(case = "lowercase")]
 pub enum FormMethodOption {
     Post,
     Get,
@@ -4,6 +4,6 @@ pub trait ColAttribute: Attribute {}
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/col#attr-span>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", u8)]
+#[attribute("lowercase", u8, range(1..))]
 pub struct Span(String);
 impl ColAttribute for Span {}
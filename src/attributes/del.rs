@@ -0,0 +1,11 @@
+use super::Attribute;
+
+pub trait DelAttribute: Attribute {}
+
+pub use super::ins::Cite;
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/del#attr-cite>
+impl DelAttribute for Cite {}
+
+pub use super::ins::DateTime;
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/del#attr-datetime>
+impl DelAttribute for DateTime {}
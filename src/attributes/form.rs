@@ -0,0 +1,47 @@
+use super::{Attribute, AttributeValue};
+use crate::values::{Href as HrefValue, LinkElement, LinkType, TokenList, ValueError};
+
+pub trait FormAttribute: Attribute {}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form#attr-action>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", HrefValue)]
+pub struct Action(HrefValue);
+impl FormAttribute for Action {}
+
+/// The default for the `autocomplete` attribute of the form's controls. Unlike on the
+/// controls themselves, only `on` and `off` are allowed.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form#attr-autocomplete>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", FormAutocompleteOption)]
+pub struct Autocomplete(FormAutocompleteOption);
+impl FormAttribute for Autocomplete {}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form#attr-rel>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase")]
+pub struct Rel(String);
+impl FormAttribute for Rel {}
+
+impl Rel {
+    /// Fails if one of the link types isn't allowed on `form` elements, such as
+    /// `bookmark` or `stylesheet`.
+    pub fn new(val: TokenList<LinkType>) -> Result<Self, ValueError> {
+        LinkType::validate(&val, LinkElement::Form)?;
+        Ok(Self(val.to_string()))
+    }
+}
+
+pub use super::anchor::Target;
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form#attr-target>
+impl FormAttribute for Target {}
+
+/// An enum representing the different options for the autocomplete attribute of a form.
+#[derive(Debug, AttributeValue, Copy, Default)]
+#[attribute_value(case = "lowercase")]
+pub enum FormAutocompleteOption {
+    #[default]
+    On,
+    Off,
+}
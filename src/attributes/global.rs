@@ -1,32 +1,27 @@
-use super::add_impls;
-use super::{
-    anchor::AnchorAttribute, area::AreaAttribute, audio::AudioAttribute, base::BaseAttribute,
-    blockquote::BlockQuoteAttribute, button::ButtonAttribute, canvas::CanvasAttribute,
-    col::ColAttribute, colgroup::ColGroupAttribute, data::DataAttribute, details::DetailsAttribute,
-    li::LiAttribute, svg::SvgAttribute, Attribute,
-};
+use super::{impl_element_attribute, Attribute, AttributeValue};
+use crate::values::{EnumeratedValue, Href, TokenList};
 use std::fmt::Display;
-use strum::AsRefStr;
 use url::Url;
 
 pub trait GlobalAttribute: Attribute {}
 
+/// The keys that focus or activate the element, in order of preference. Each key is a
+/// single code point.
+///
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/accesskey>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", Vec<char>)]
+#[attribute("lowercase", TokenList<char>, elements(global))]
 pub struct AccessKey(String);
 impl GlobalAttribute for AccessKey {}
-add_impls!(AccessKey);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/autocapitalize>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", AutoCapitalizeOptions)]
+#[attribute("lowercase", AutoCapitalizeOptions, elements(global))]
 pub struct AutoCapitalize(AutoCapitalizeOptions);
 impl GlobalAttribute for AutoCapitalize {}
-add_impls!(AutoCapitalize);
 
-#[derive(Debug, AsRefStr)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue)]
+#[attribute_value(case = "lowercase")]
 pub enum AutoCapitalizeOptions {
     Off,
     None,
@@ -38,17 +33,15 @@ pub enum AutoCapitalizeOptions {
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/autofocus>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase")]
+#[attribute("lowercase", elements(global))]
 pub struct Autofocus;
 impl GlobalAttribute for Autofocus {}
-add_impls!(Autofocus);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/contenteditable>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", ContentEditableOptions)]
+#[attribute("lowercase", ContentEditableOptions, elements(global))]
 pub struct ContentEditable(ContentEditableOptions);
 impl GlobalAttribute for ContentEditable {}
-add_impls!(ContentEditable);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/data-*>
 #[derive(Debug)]
@@ -81,17 +74,16 @@ impl Attribute for Data {
 }
 
 impl GlobalAttribute for Data {}
-add_impls!(Data);
+impl_element_attribute!(global, Data);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/dir>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", DirOptions)]
+#[attribute("lowercase", DirOptions, elements(global))]
 pub struct Dir(DirOptions);
 impl GlobalAttribute for Dir {}
-add_impls!(Dir);
 
-#[derive(Debug, AsRefStr)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue)]
+#[attribute_value(case = "lowercase")]
 pub enum DirOptions {
     Ltr,
     Rtl,
@@ -100,20 +92,18 @@ pub enum DirOptions {
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/draggable>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", bool)]
-pub struct Draggable(String);
+#[attribute("lowercase", DraggableOption, elements(global))]
+pub struct Draggable(DraggableOption);
 impl GlobalAttribute for Draggable {}
-add_impls!(Draggable);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/enterkeyhint>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", EnterKeyHintOption)]
+#[attribute("lowercase", EnterKeyHintOption, elements(global))]
 pub struct EnterKeyHint(EnterKeyHintOption);
 impl GlobalAttribute for EnterKeyHint {}
-add_impls!(EnterKeyHint);
 
-#[derive(Debug, AsRefStr)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue)]
+#[attribute_value(case = "lowercase")]
 pub enum EnterKeyHintOption {
     Enter,
     Done,
@@ -126,27 +116,24 @@ pub enum EnterKeyHintOption {
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/hidden>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase")]
-pub struct Hidden;
+#[attribute("lowercase", HiddenOption, elements(global))]
+pub struct Hidden(HiddenOption);
 impl GlobalAttribute for Hidden {}
-add_impls!(Hidden);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/id>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", String)]
+#[attribute("lowercase", String, non_empty, no_whitespace, elements(global))]
 pub struct Id(String);
 impl GlobalAttribute for Id {}
-add_impls!(Id);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/inputmode>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", InputModeOption)]
+#[attribute("lowercase", InputModeOption, elements(global))]
 pub struct InputMode(InputModeOption);
 impl GlobalAttribute for InputMode {}
-add_impls!(InputMode);
 
-#[derive(Debug, AsRefStr, Default)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue, Default)]
+#[attribute_value(case = "lowercase")]
 pub enum InputModeOption {
     None,
     #[default]
@@ -161,116 +148,109 @@ pub enum InputModeOption {
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/is>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", String)]
+#[attribute("lowercase", String, elements(global))]
 pub struct Is(String);
 impl GlobalAttribute for Is {}
-add_impls!(Is);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/itemid>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", String)]
-pub struct ItemId(String);
+#[attribute("lowercase", Href, elements(global))]
+pub struct ItemId(Href);
 impl GlobalAttribute for ItemId {}
-add_impls!(ItemId);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/itemprop>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", String)]
+#[attribute("lowercase", String, elements(global))]
 pub struct ItemProp(String);
 impl GlobalAttribute for ItemProp {}
-add_impls!(ItemProp);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/itemref>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", Vec<String>)]
+#[attribute("lowercase", TokenList, elements(global))]
 pub struct ItemRef(String);
 impl GlobalAttribute for ItemRef {}
-add_impls!(ItemRef);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/itemscope>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase")]
+#[attribute("lowercase", elements(global))]
 pub struct ItemScope;
 impl GlobalAttribute for ItemScope {}
-add_impls!(ItemScope);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/itemtype>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", Url)]
+#[attribute("lowercase", Url, elements(global))]
 pub struct ItemType(String);
 impl GlobalAttribute for ItemType {}
-add_impls!(ItemType);
 
 // TODO: make a struct to help with making language tags
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/lang>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", String)]
+#[attribute("lowercase", String, chars(ascii_alphanumeric, '-'), elements(global))]
 pub struct Lang(String);
 impl GlobalAttribute for Lang {}
-add_impls!(Lang);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/nonce>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", String)]
+#[attribute(
+    "lowercase",
+    String,
+    non_empty,
+    chars(ascii_alphanumeric, '+', '/', '-', '_', '='),
+    elements(global)
+)]
 pub struct Nonce(String);
 impl GlobalAttribute for Nonce {}
-add_impls!(Nonce);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/part>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", Vec<String>)]
+#[attribute("lowercase", TokenList, elements(global))]
 pub struct Part(String);
 impl GlobalAttribute for Part {}
-add_impls!(Part);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/slot>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", String)]
+#[attribute("lowercase", String, elements(global))]
 pub struct Slot(String);
 impl GlobalAttribute for Slot {}
-add_impls!(Slot);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/spellcheck>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", bool)]
-pub struct Spellcheck(String);
+#[attribute("lowercase", SpellcheckOption, elements(global))]
+pub struct Spellcheck(SpellcheckOption);
 
 impl GlobalAttribute for Spellcheck {}
-add_impls!(Spellcheck);
 
+/// Every negative value behaves like `-1`, so `try_new` only accepts `-1` and up.
+///
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/tabindex>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", i16)]
+#[attribute("lowercase", i16, range(-1..), elements(global))]
 pub struct TabIndex(String);
 impl GlobalAttribute for TabIndex {}
-add_impls!(TabIndex);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/title>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", String)]
+#[attribute("lowercase", String, elements(global))]
 pub struct Title(String);
 impl GlobalAttribute for Title {}
-add_impls!(Title);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/translate>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", TranslateOption)]
+#[attribute("lowercase", TranslateOption, elements(global))]
 pub struct Translate(TranslateOption);
 impl GlobalAttribute for Translate {}
-add_impls!(Translate);
 
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/role>
 #[derive(Debug, Attribute)]
-#[attribute("lowercase", AriaRole)]
+#[attribute("lowercase", AriaRole, elements(global))]
 pub struct Role(AriaRole);
 impl GlobalAttribute for Role {}
-add_impls!(Role);
 
 /// An enum representing the different options for the `aria-role` attribute.
 ///
 ///  <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles>
-#[derive(Debug, AsRefStr)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue)]
+#[attribute_value(case = "lowercase")]
 pub enum AriaRole {
     Alert,
     AlertDialog,
@@ -331,35 +311,141 @@ pub enum AriaRole {
     Switch,
     Tab,
     Table,
-    TabLList,
+    TabList,
     TabPanel,
     Term,
-    TextNox,
+    Textbox,
     Timer,
     Toolbar,
     Tooltip,
     Tree,
     TreeGrid,
     TreeItem,
+    #[attribute_value(custom)]
     Custom(String),
 }
 
-/// An enum representing the different options for the `aria-role` attribute.
+/// An enum representing the states of the `contenteditable` attribute. The `inherit`
+/// state has no keyword; omit the attribute to get it.
 ///
-/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/contenteditable>
-#[derive(Debug, AsRefStr)]
-#[strum(serialize_all = "lowercase")]
+/// <https://html.spec.whatwg.org/multipage/interaction.html#attr-contenteditable>
+#[derive(Debug, AttributeValue, Copy)]
+#[attribute_value(case = "kebab-case")]
 pub enum ContentEditableOptions {
     True,
     False,
-    Inherit,
+    PlaintextOnly,
+}
+
+impl EnumeratedValue for ContentEditableOptions {
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("true", Self::True),
+        ("", Self::True),
+        ("false", Self::False),
+        ("plaintext-only", Self::PlaintextOnly),
+    ];
+    const MISSING_VALUE_DEFAULT: Option<Self> = None;
+    const INVALID_VALUE_DEFAULT: Option<Self> = None;
+}
+
+impl From<bool> for ContentEditableOptions {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
+
+/// An enum representing the states of the `draggable` attribute. The `auto` state has
+/// no keyword; omit the attribute to get it.
+///
+/// <https://html.spec.whatwg.org/multipage/dnd.html#the-draggable-attribute>
+#[derive(Debug, AttributeValue, Copy)]
+#[attribute_value(case = "lowercase")]
+pub enum DraggableOption {
+    True,
+    False,
+}
+
+impl EnumeratedValue for DraggableOption {
+    const KEYWORDS: &'static [(&'static str, Self)] =
+        &[("true", Self::True), ("false", Self::False)];
+    const MISSING_VALUE_DEFAULT: Option<Self> = None;
+    const INVALID_VALUE_DEFAULT: Option<Self> = None;
+}
+
+impl From<bool> for DraggableOption {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
+
+/// An enum representing the states of the `hidden` attribute. The `hidden` state is
+/// serialized as an empty value, the same way `element.hidden = true` does. The not
+/// hidden state has no keyword; omit the attribute to get it.
+///
+/// <https://html.spec.whatwg.org/multipage/interaction.html#the-hidden-attribute>
+#[derive(Debug, AttributeValue, Copy, Default)]
+#[attribute_value(case = "kebab-case")]
+pub enum HiddenOption {
+    #[default]
+    #[attribute_value(rename = "")]
+    Hidden,
+    UntilFound,
+}
+
+impl EnumeratedValue for HiddenOption {
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("", Self::Hidden),
+        ("hidden", Self::Hidden),
+        ("until-found", Self::UntilFound),
+    ];
+    const MISSING_VALUE_DEFAULT: Option<Self> = None;
+    const INVALID_VALUE_DEFAULT: Option<Self> = Some(Self::Hidden);
+}
+
+/// An enum representing the states of the `spellcheck` attribute. The default state
+/// has no keyword; omit the attribute to get it.
+///
+/// <https://html.spec.whatwg.org/multipage/interaction.html#attr-spellcheck>
+#[derive(Debug, AttributeValue, Copy)]
+#[attribute_value(case = "lowercase")]
+pub enum SpellcheckOption {
+    True,
+    False,
+}
+
+impl EnumeratedValue for SpellcheckOption {
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("true", Self::True),
+        ("", Self::True),
+        ("false", Self::False),
+    ];
+    const MISSING_VALUE_DEFAULT: Option<Self> = None;
+    const INVALID_VALUE_DEFAULT: Option<Self> = None;
+}
+
+impl From<bool> for SpellcheckOption {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
 }
 
 /// An enum representing the different options for the `translate` attribute.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/translate>
-#[derive(Debug, AsRefStr)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, AttributeValue)]
+#[attribute_value(case = "lowercase")]
 pub enum TranslateOption {
     Yes,
     No,
@@ -380,7 +466,6 @@ impl CustomAttribute {
         }
     }
 }
-/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/translate>
 
 impl Attribute for CustomAttribute {
     fn get_key(&self) -> &str {
@@ -392,4 +477,4 @@ impl Attribute for CustomAttribute {
     }
 }
 impl GlobalAttribute for CustomAttribute {}
-add_impls!(CustomAttribute);
+impl_element_attribute!(global, CustomAttribute);
@@ -0,0 +1,84 @@
+use super::{Attribute, AttributeValue};
+use crate::values::{AutocompleteValue, CommaList, InputBound, InputDateValue};
+
+pub trait InputAttribute: Attribute {}
+
+/// The file types accepted by a file input, as MIME types, wildcards such as `image/*`
+/// or extensions such as `.pdf`.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#attr-accept>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", CommaList)]
+pub struct Accept(String);
+impl InputAttribute for Accept {}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/autocomplete>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", AutocompleteValue)]
+pub struct Autocomplete(String);
+impl InputAttribute for Autocomplete {}
+
+/// The greatest number, or the latest date or time, accepted by the input.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#attr-max>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", InputBound)]
+pub struct Max(String);
+impl InputAttribute for Max {}
+
+/// The smallest number, or the earliest date or time, accepted by the input.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#attr-min>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", InputBound)]
+pub struct Min(String);
+impl InputAttribute for Min {}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#attr-type>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", InputTypeOption)]
+pub struct Type(InputTypeOption);
+impl InputAttribute for Type {}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#attr-value>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", String)]
+pub struct Value(String);
+impl InputAttribute for Value {}
+
+impl Value {
+    /// Creates the value of a date-typed input.
+    pub fn date<T: Into<InputDateValue>>(val: T) -> Self {
+        Self(val.into().to_string())
+    }
+}
+
+/// An enum representing the different options for the type attribute of an input element.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#input_types>
+#[derive(Debug, AttributeValue)]
+#[attribute_value(case = "kebab-case")]
+pub enum InputTypeOption {
+    Button,
+    Checkbox,
+    Color,
+    Date,
+    DatetimeLocal,
+    Email,
+    File,
+    Hidden,
+    Image,
+    Month,
+    Number,
+    Password,
+    Radio,
+    Range,
+    Reset,
+    Search,
+    Submit,
+    Tel,
+    Text,
+    Time,
+    Url,
+    Week,
+}
@@ -0,0 +1,14 @@
+use super::Attribute;
+use crate::values::DateWithOptionalTime;
+
+pub trait InsAttribute: Attribute {}
+
+pub use super::blockquote::Cite;
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/ins#attr-cite>
+impl InsAttribute for Cite {}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/ins#attr-datetime>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", DateWithOptionalTime)]
+pub struct DateTime(String);
+impl InsAttribute for DateTime {}
@@ -0,0 +1,43 @@
+use super::Attribute;
+use crate::values::{self, LinkElement, LinkType, TokenList, ValueError};
+
+pub trait LinkAttribute: Attribute {}
+
+pub use super::anchor::Href;
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/link#attr-href>
+impl LinkAttribute for Href {}
+
+pub use super::anchor::HrefLang;
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/link#attr-hreflang>
+impl LinkAttribute for HrefLang {}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/link#attr-integrity>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase")]
+pub struct Integrity(String);
+impl LinkAttribute for Integrity {}
+
+impl Integrity {
+    pub fn new(val: values::Integrity) -> Self {
+        Self(val.to_string())
+    }
+}
+
+pub use super::anchor::ReferrerPolicy;
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/link#attr-referrerpolicy>
+impl LinkAttribute for ReferrerPolicy {}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/link#attr-rel>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase")]
+pub struct Rel(String);
+impl LinkAttribute for Rel {}
+
+impl Rel {
+    /// Fails if one of the link types isn't allowed on `link` elements, such as
+    /// `noopener` or `bookmark`.
+    pub fn new(val: TokenList<LinkType>) -> Result<Self, ValueError> {
+        LinkType::validate(&val, LinkElement::Link)?;
+        Ok(Self(val.to_string()))
+    }
+}
@@ -10,8 +10,12 @@
 //!
 //! ## Limitations
 //!
-//! Currently `domatt` does not type check the formatting of dates or unit strings ("2px", "80%").
-//! These would be excellent features to add. Feel free to put in a PR.
+//! Dates, times and unit strings ("2px", "80%") are type checked through the types in
+//! [crate::values]. Dates and times can be converted from `chrono` and `time` types by
+//! enabling the `chrono` and `time-crate` features. Many SVG attributes whose values are
+//! keywords or lists still accept any string, through [NumberOrString] when the value
+//! may contain numbers. Typing them would be an excellent feature to add. Feel free to
+//! put in a PR.
 //!
 //! ## Issues
 //!
@@ -19,11 +23,15 @@
 //! Please file an [issue](https://github.com/toadslop/domatt/issues), or if you'd like
 //! to solve it yourself feel free to put in a PR.
 
-use attribute_derive::Attribute;
+use crate::values::{impl_attr_value, Number, ValueError};
+/// Derives for attributes, their keyword values and sets of attributes, which other
+/// crates can use with their own value types.
+pub use attribute_derive::{Attribute, AttributeValue, Attributes};
 use std::fmt::{self, Debug, Display};
-use strum::AsRefStr;
+use std::str::FromStr;
 use web_sys::Element;
 
+// @generated begin: modules
 #[cfg(feature = "anchor")]
 pub mod anchor;
 
@@ -57,18 +65,52 @@ pub mod colgroup;
 #[cfg(feature = "data")]
 pub mod data;
 
+#[cfg(feature = "del")]
+pub mod del;
+
 #[cfg(feature = "details")]
 pub mod details;
 
+#[cfg(feature = "form")]
+pub mod form;
+
 #[cfg(feature = "global")]
 pub mod global;
 
+#[cfg(feature = "input")]
+pub mod input;
+
+#[cfg(feature = "ins")]
+pub mod ins;
+
 #[cfg(feature = "li")]
 pub mod li;
 
+#[cfg(feature = "link")]
+pub mod link;
+
+#[cfg(feature = "script")]
+pub mod script;
+
+#[cfg(feature = "select")]
+pub mod select;
+
+#[cfg(feature = "slot")]
+pub mod slot;
+
 #[cfg(feature = "svg")]
 pub mod svg;
 
+#[cfg(feature = "template")]
+pub mod template;
+
+#[cfg(feature = "textarea")]
+pub mod textarea;
+
+#[cfg(feature = "time")]
+pub mod time;
+// @generated end: modules
+
 /// Marks a type as a DOM attribute.
 pub trait Attribute: Debug {
     /// Returns a string representing the key of a DOM attribute.
@@ -80,10 +122,33 @@ pub trait Attribute: Debug {
     fn get_val(&self) -> Option<&str>;
 }
 
+/// A set of attributes, such as the props of a component, which can be set on an
+/// element or rendered at once. Derive it for a struct whose fields are attributes
+/// with `#[derive(Attributes)]`.
+pub trait Attributes {
+    /// Returns the attributes of the set.
+    fn attributes(&self) -> Box<dyn Iterator<Item = &dyn Attribute> + '_>;
+}
+
+impl Attributes for [Box<dyn Attribute>] {
+    fn attributes(&self) -> Box<dyn Iterator<Item = &dyn Attribute> + '_> {
+        Box::new(self.iter().map(|attribute| attribute.as_ref()))
+    }
+}
+
+impl Attributes for Vec<Box<dyn Attribute>> {
+    fn attributes(&self) -> Box<dyn Iterator<Item = &dyn Attribute> + '_> {
+        self.as_slice().attributes()
+    }
+}
+
 pub trait BaseAttribute {}
 
 /// Convenience method for setting an attribute on an element.
-pub fn set_attribute<T: Attribute>(element: &Element, attribute: &T) -> Result<(), AttributeError> {
+pub fn set_attribute<T: Attribute + ?Sized>(
+    element: &Element,
+    attribute: &T,
+) -> Result<(), AttributeError> {
     let key = attribute.get_key().to_owned();
     let value = attribute.get_val().unwrap_or_default().to_owned();
     element
@@ -97,6 +162,41 @@ pub fn set_attribute<T: Attribute>(element: &Element, attribute: &T) -> Result<(
     Ok(())
 }
 
+/// Convenience method for setting every attribute of a set on an element.
+pub fn set_attributes<T: Attributes + ?Sized>(
+    element: &Element,
+    attributes: &T,
+) -> Result<(), AttributeError> {
+    for attribute in attributes.attributes() {
+        set_attribute(element, attribute)?;
+    }
+
+    Ok(())
+}
+
+/// Serializes an attribute for an HTML start tag, such as `name="value"`, for
+/// rendering on the server. Boolean attributes are written as their key alone, and
+/// `&` and `"` in values are escaped.
+pub fn render_attribute<T: Attribute + ?Sized>(attribute: &T) -> String {
+    match attribute.get_val() {
+        Some(value) => format!(
+            "{}=\"{}\"",
+            attribute.get_key(),
+            value.replace('&', "&amp;").replace('"', "&quot;")
+        ),
+        None => attribute.get_key().to_owned(),
+    }
+}
+
+/// Serializes a set of attributes for an HTML start tag, separated by spaces.
+pub fn render_attributes<T: Attributes + ?Sized>(attributes: &T) -> String {
+    attributes
+        .attributes()
+        .map(render_attribute)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Error indicating an error with performing operations with attributes on an element.
 #[derive(Debug, Clone)]
 pub struct AttributeError {
@@ -118,12 +218,26 @@ impl fmt::Display for AttributeError {
 
 /// An enum representing a value that could be either a number or string. It's typically
 /// used to represent a number value that could have an optional unit attached to it.
+/// Numbers are serialized the same way JavaScript would, see [format_number](crate::values::format_number).
 #[derive(Debug)]
 pub enum NumberOrString {
-    Number(f64),
+    Number(Number),
     String(String),
 }
 
+impl NumberOrString {
+    /// Creates a number value, rejecting `NaN` and infinities.
+    pub fn number(value: f64) -> Result<Self, ValueError> {
+        Number::new(value).map(Self::Number)
+    }
+}
+
+impl From<Number> for NumberOrString {
+    fn from(value: Number) -> Self {
+        Self::Number(value)
+    }
+}
+
 impl Display for NumberOrString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -133,8 +247,20 @@ impl Display for NumberOrString {
     }
 }
 
-#[derive(Debug, AsRefStr, Clone, PartialEq, Eq)]
-#[strum(serialize_all = "kebab-case")]
+impl FromStr for NumberOrString {
+    type Err = ValueError;
+
+    /// Parses a number if `s` is one, and keeps any other value as a string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.parse::<Number>()
+            .map_or_else(|_| Self::String(s.to_owned()), Self::Number))
+    }
+}
+
+impl_attr_value!(NumberOrString);
+
+#[derive(Debug, AttributeValue)]
+#[attribute_value(case = "kebab-case")]
 pub enum ReferrerPolicyOption {
     NoReferrer,
     NoReferrerWhenDowngrade,
@@ -144,39 +270,154 @@ pub enum ReferrerPolicyOption {
     StrictOrigin,
     StrictOriginWhenCrossOrigin,
     UnsafeUrl,
-    #[strum(serialize = "")]
+    #[attribute_value(rename = "")]
     Blank,
 }
 
-#[derive(Debug, AsRefStr, Clone, PartialEq, Eq)]
+#[derive(Debug, AttributeValue)]
 pub enum TargetOption {
-    #[strum(serialize = "_self")]
+    #[attribute_value(rename = "_self")]
     Self_,
-    #[strum(serialize = "_blank")]
+    #[attribute_value(rename = "_blank")]
     Blank,
-    #[strum(serialize = "_parent")]
+    #[attribute_value(rename = "_parent")]
     Parent,
-    #[strum(serialize = "_top")]
+    #[attribute_value(rename = "_top")]
     Top,
+    #[attribute_value(custom)]
     Custom(String),
 }
 
-macro_rules! add_impls {
-    ($attr_struct:ty ) => {
-        impl AnchorAttribute for $attr_struct {}
-        impl AreaAttribute for $attr_struct {}
-        impl AudioAttribute for $attr_struct {}
-        impl BaseAttribute for $attr_struct {}
-        impl BlockQuoteAttribute for $attr_struct {}
-        impl ButtonAttribute for $attr_struct {}
-        impl CanvasAttribute for $attr_struct {}
-        impl ColAttribute for $attr_struct {}
-        impl ColGroupAttribute for $attr_struct {}
-        impl DataAttribute for $attr_struct {}
-        impl DetailsAttribute for $attr_struct {}
-        impl LiAttribute for $attr_struct {}
-        impl SvgAttribute for $attr_struct {}
+// @generated begin: elements
+/// Implements the attribute trait of an element module for an attribute type. This is
+/// what `#[attribute(..., elements(...))]` expands to; `global` stands for every
+/// element. The arms are generated from spec/modules.json.
+#[allow(unused_macros)]
+macro_rules! impl_element_attribute {
+    (global, $attr_struct:ty) => {
+        $crate::attributes::impl_element_attribute!(anchor, $attr_struct);
+        $crate::attributes::impl_element_attribute!(area, $attr_struct);
+        $crate::attributes::impl_element_attribute!(audio, $attr_struct);
+        $crate::attributes::impl_element_attribute!(base, $attr_struct);
+        $crate::attributes::impl_element_attribute!(blockquote, $attr_struct);
+        $crate::attributes::impl_element_attribute!(button, $attr_struct);
+        $crate::attributes::impl_element_attribute!(canvas, $attr_struct);
+        $crate::attributes::impl_element_attribute!(col, $attr_struct);
+        $crate::attributes::impl_element_attribute!(colgroup, $attr_struct);
+        $crate::attributes::impl_element_attribute!(data, $attr_struct);
+        $crate::attributes::impl_element_attribute!(del, $attr_struct);
+        $crate::attributes::impl_element_attribute!(details, $attr_struct);
+        $crate::attributes::impl_element_attribute!(form, $attr_struct);
+        $crate::attributes::impl_element_attribute!(input, $attr_struct);
+        $crate::attributes::impl_element_attribute!(ins, $attr_struct);
+        $crate::attributes::impl_element_attribute!(li, $attr_struct);
+        $crate::attributes::impl_element_attribute!(link, $attr_struct);
+        $crate::attributes::impl_element_attribute!(script, $attr_struct);
+        $crate::attributes::impl_element_attribute!(select, $attr_struct);
+        $crate::attributes::impl_element_attribute!(slot, $attr_struct);
+        $crate::attributes::impl_element_attribute!(svg, $attr_struct);
+        $crate::attributes::impl_element_attribute!(template, $attr_struct);
+        $crate::attributes::impl_element_attribute!(textarea, $attr_struct);
+        $crate::attributes::impl_element_attribute!(time, $attr_struct);
+    };
+    (anchor, $attr_struct:ty) => {
+        #[cfg(feature = "anchor")]
+        impl $crate::attributes::anchor::AnchorAttribute for $attr_struct {}
+    };
+    (area, $attr_struct:ty) => {
+        #[cfg(feature = "area")]
+        impl $crate::attributes::area::AreaAttribute for $attr_struct {}
+    };
+    (audio, $attr_struct:ty) => {
+        #[cfg(feature = "audio")]
+        impl $crate::attributes::audio::AudioAttribute for $attr_struct {}
+    };
+    (base, $attr_struct:ty) => {
+        #[cfg(feature = "base")]
+        impl $crate::attributes::base::BaseAttribute for $attr_struct {}
+    };
+    (blockquote, $attr_struct:ty) => {
+        #[cfg(feature = "blockquote")]
+        impl $crate::attributes::blockquote::BlockQuoteAttribute for $attr_struct {}
+    };
+    (button, $attr_struct:ty) => {
+        #[cfg(feature = "button")]
+        impl $crate::attributes::button::ButtonAttribute for $attr_struct {}
+    };
+    (canvas, $attr_struct:ty) => {
+        #[cfg(feature = "canvas")]
+        impl $crate::attributes::canvas::CanvasAttribute for $attr_struct {}
+    };
+    (col, $attr_struct:ty) => {
+        #[cfg(feature = "col")]
+        impl $crate::attributes::col::ColAttribute for $attr_struct {}
+    };
+    (colgroup, $attr_struct:ty) => {
+        #[cfg(feature = "colgroup")]
+        impl $crate::attributes::colgroup::ColGroupAttribute for $attr_struct {}
+    };
+    (data, $attr_struct:ty) => {
+        #[cfg(feature = "data")]
+        impl $crate::attributes::data::DataAttribute for $attr_struct {}
+    };
+    (del, $attr_struct:ty) => {
+        #[cfg(feature = "del")]
+        impl $crate::attributes::del::DelAttribute for $attr_struct {}
+    };
+    (details, $attr_struct:ty) => {
+        #[cfg(feature = "details")]
+        impl $crate::attributes::details::DetailsAttribute for $attr_struct {}
+    };
+    (form, $attr_struct:ty) => {
+        #[cfg(feature = "form")]
+        impl $crate::attributes::form::FormAttribute for $attr_struct {}
+    };
+    (input, $attr_struct:ty) => {
+        #[cfg(feature = "input")]
+        impl $crate::attributes::input::InputAttribute for $attr_struct {}
+    };
+    (ins, $attr_struct:ty) => {
+        #[cfg(feature = "ins")]
+        impl $crate::attributes::ins::InsAttribute for $attr_struct {}
+    };
+    (li, $attr_struct:ty) => {
+        #[cfg(feature = "li")]
+        impl $crate::attributes::li::LiAttribute for $attr_struct {}
+    };
+    (link, $attr_struct:ty) => {
+        #[cfg(feature = "link")]
+        impl $crate::attributes::link::LinkAttribute for $attr_struct {}
+    };
+    (script, $attr_struct:ty) => {
+        #[cfg(feature = "script")]
+        impl $crate::attributes::script::ScriptAttribute for $attr_struct {}
+    };
+    (select, $attr_struct:ty) => {
+        #[cfg(feature = "select")]
+        impl $crate::attributes::select::SelectAttribute for $attr_struct {}
+    };
+    (slot, $attr_struct:ty) => {
+        #[cfg(feature = "slot")]
+        impl $crate::attributes::slot::SlotAttribute for $attr_struct {}
+    };
+    (svg, $attr_struct:ty) => {
+        #[cfg(feature = "svg")]
+        impl $crate::attributes::svg::SvgAttribute for $attr_struct {}
+    };
+    (template, $attr_struct:ty) => {
+        #[cfg(feature = "template")]
+        impl $crate::attributes::template::TemplateAttribute for $attr_struct {}
+    };
+    (textarea, $attr_struct:ty) => {
+        #[cfg(feature = "textarea")]
+        impl $crate::attributes::textarea::TextAreaAttribute for $attr_struct {}
+    };
+    (time, $attr_struct:ty) => {
+        #[cfg(feature = "time")]
+        impl $crate::attributes::time::TimeAttribute for $attr_struct {}
     };
 }
 
-pub(crate) use add_impls;
+#[allow(unused_imports)]
+pub(crate) use impl_element_attribute;
+// @generated end: elements
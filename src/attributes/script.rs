@@ -0,0 +1,7 @@
+use super::Attribute;
+
+pub trait ScriptAttribute: Attribute {}
+
+pub use super::link::Integrity;
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/script#attr-integrity>
+impl ScriptAttribute for Integrity {}
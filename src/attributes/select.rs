@@ -0,0 +1,7 @@
+use super::Attribute;
+
+pub trait SelectAttribute: Attribute {}
+
+pub use super::input::Autocomplete;
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/select#attr-autocomplete>
+impl SelectAttribute for Autocomplete {}
@@ -0,0 +1,18 @@
+//! Attributes of the `slot` element, which marks where the children of a shadow host
+//! are shown in its shadow tree. A slot is usually declared inside a `template` with
+//! `shadowrootmode`, whose attributes are in the `template` module.
+//!
+//! <https://html.spec.whatwg.org/multipage/scripting.html#the-slot-element>
+
+use super::Attribute;
+
+pub trait SlotAttribute: Attribute {}
+
+/// The name that elements use in their `slot` attribute to be assigned to this slot.
+/// A slot without a name receives every child that isn't assigned to a named slot.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/slot#name>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", String)]
+pub struct Name(String);
+impl SlotAttribute for Name {}
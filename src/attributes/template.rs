@@ -0,0 +1,52 @@
+//! Attributes for declarative shadow DOM. A `template` with `shadowrootmode` is turned
+//! into a shadow root by the HTML parser, so these attributes only take effect in
+//! server-rendered markup; see [render_attributes](super::render_attributes). Setting
+//! them on an existing element has no effect.
+//!
+//! <https://html.spec.whatwg.org/multipage/scripting.html#the-template-element>
+
+use super::{Attribute, AttributeValue};
+use crate::values::EnumeratedValue;
+
+pub trait TemplateAttribute: Attribute {}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/template#shadowrootmode>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", ShadowRootModeOption)]
+pub struct ShadowRootMode(ShadowRootModeOption);
+impl TemplateAttribute for ShadowRootMode {}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/template#shadowrootdelegatesfocus>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase")]
+pub struct ShadowRootDelegatesFocus;
+impl TemplateAttribute for ShadowRootDelegatesFocus {}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/template#shadowrootclonable>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase")]
+pub struct ShadowRootClonable;
+impl TemplateAttribute for ShadowRootClonable {}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/template#shadowrootserializable>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase")]
+pub struct ShadowRootSerializable;
+impl TemplateAttribute for ShadowRootSerializable {}
+
+/// Whether the shadow root created from a template can be reached through the host's
+/// `shadowRoot` property.
+#[derive(Debug, AttributeValue, Copy)]
+#[attribute_value(case = "lowercase")]
+pub enum ShadowRootModeOption {
+    Open,
+    Closed,
+}
+
+/// A missing or invalid value leaves the template as a plain template.
+impl EnumeratedValue for ShadowRootModeOption {
+    const KEYWORDS: &'static [(&'static str, Self)] =
+        &[("open", Self::Open), ("closed", Self::Closed)];
+    const MISSING_VALUE_DEFAULT: Option<Self> = None;
+    const INVALID_VALUE_DEFAULT: Option<Self> = None;
+}
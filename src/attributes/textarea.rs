@@ -0,0 +1,7 @@
+use super::Attribute;
+
+pub trait TextAreaAttribute: Attribute {}
+
+pub use super::input::Autocomplete;
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/textarea#attr-autocomplete>
+impl TextAreaAttribute for Autocomplete {}
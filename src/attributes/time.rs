@@ -0,0 +1,10 @@
+use super::Attribute;
+use crate::values::DateTimeValue;
+
+pub trait TimeAttribute: Attribute {}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/time#attr-datetime>
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", DateTimeValue)]
+pub struct DateTime(String);
+impl TimeAttribute for DateTime {}
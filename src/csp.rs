@@ -0,0 +1,185 @@
+//! Helpers for allowing inline scripts and styles with a Content Security Policy nonce.
+//!
+//! A page that uses a nonce must send the same random value in its policy and in the
+//! `nonce` attribute of every `script`, `style` and `link` element, and must pick a new
+//! value for every response. A [CspContext] holds the nonce of one response, so that
+//! the policy and the attributes are always built from the same value.
+//!
+//! <https://www.w3.org/TR/CSP3/#framework-directive-source-list>
+
+use crate::attributes::global::Nonce;
+use crate::attributes::{set_attribute, Attribute, AttributeError};
+use crate::values::ValueError;
+use attribute_derive::AttributeValue;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use web_sys::Element;
+
+/// The number of random bytes in a generated nonce. The spec asks for at least 128 bits.
+const NONCE_LEN: usize = 16;
+
+/// The tags whose elements are allowed by a nonce in `script-src` or `style-src`. A
+/// `link` is checked against them when it loads a stylesheet, a preload or a module.
+const NONCE_TAGS: &[&str] = &["link", "script", "style"];
+
+/// A CSP nonce, such as `EDNnf03nceIOfn39fn3e9h3sdfa`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CspNonce(String);
+
+impl CspNonce {
+    /// Creates a nonce from 128 bits of randomness from the operating system, or from
+    /// `crypto.getRandomValues` in the browser.
+    pub fn generate() -> Self {
+        let mut bytes = [0; NONCE_LEN];
+        getrandom::getrandom(&mut bytes).expect("a source of randomness to be available");
+        Self(STANDARD.encode(bytes))
+    }
+
+    /// Wraps a nonce that was generated elsewhere. Fails if `value` isn't base64 or
+    /// base64url.
+    pub fn new(value: &str) -> Result<Self, ValueError> {
+        let unpadded = value.trim_end_matches('=');
+        let is_base64 = !unpadded.is_empty()
+            && value.len() - unpadded.len() <= 2
+            && unpadded
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'-' | b'_'));
+        if !is_base64 {
+            return Err(ValueError::new(value, "Nonces must be base64 encoded."));
+        }
+
+        Ok(Self(value.to_owned()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the source expression that allows this nonce in a policy, such as
+    /// `'nonce-EDNnf03nceIOfn39fn3e9h3sdfa'`.
+    pub fn source_expression(&self) -> String {
+        format!("'nonce-{}'", self.0)
+    }
+
+    /// Returns the `nonce` attribute for an element.
+    pub fn attribute(&self) -> Nonce {
+        Nonce::new(self.0.clone())
+    }
+}
+
+impl FromStr for CspNonce {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl AsRef<str> for CspNonce {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for CspNonce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A policy directive that accepts nonce sources.
+#[derive(Debug, Copy, AttributeValue)]
+#[attribute_value(case = "kebab-case")]
+pub enum NonceDirective {
+    ScriptSrc,
+    ScriptSrcElem,
+    StyleSrc,
+    StyleSrcElem,
+}
+
+/// The nonce of a single response.
+///
+/// Create one context per request, build the policy with [CspContext::policy] and
+/// pass every element's attributes through [CspContext::attributes] or
+/// [CspContext::set_attributes]. A `nonce` set by hand on a `script`, `style` or `link`
+/// element is replaced, so a stale or mistyped nonce can't reach the page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CspContext {
+    nonce: CspNonce,
+}
+
+impl CspContext {
+    /// Creates a context with a freshly generated nonce.
+    pub fn new() -> Self {
+        Self::with_nonce(CspNonce::generate())
+    }
+
+    pub fn with_nonce(nonce: CspNonce) -> Self {
+        Self { nonce }
+    }
+
+    pub fn nonce(&self) -> &CspNonce {
+        &self.nonce
+    }
+
+    /// Returns a single directive allowing the nonce, such as
+    /// `script-src 'nonce-EDNnf03nceIOfn39fn3e9h3sdfa'`.
+    pub fn directive(&self, directive: NonceDirective) -> String {
+        format!("{directive} {}", self.nonce.source_expression())
+    }
+
+    /// Returns the `script-src` and `style-src` directives allowing the nonce, for the
+    /// `Content-Security-Policy` header or the `content` of a
+    /// `<meta http-equiv="Content-Security-Policy">` element.
+    pub fn policy(&self) -> String {
+        [NonceDirective::ScriptSrc, NonceDirective::StyleSrc]
+            .into_iter()
+            .map(|directive| self.directive(directive))
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    /// Returns the attributes of an element with `tag`. `script`, `style` and `link`
+    /// elements get the context's nonce in place of any `nonce` they had. The
+    /// attributes of other elements are returned unchanged.
+    pub fn attributes(
+        &self,
+        tag: &str,
+        attributes: Vec<Box<dyn Attribute>>,
+    ) -> Vec<Box<dyn Attribute>> {
+        let is_nonce_tag = NONCE_TAGS
+            .iter()
+            .any(|nonce_tag| tag.eq_ignore_ascii_case(nonce_tag));
+        if !is_nonce_tag {
+            return attributes;
+        }
+
+        let nonce = self.nonce.attribute();
+        let mut attributes: Vec<Box<dyn Attribute>> = attributes
+            .into_iter()
+            .filter(|attribute| attribute.get_key() != nonce.get_key())
+            .collect();
+        attributes.push(Box::new(nonce));
+        attributes
+    }
+
+    /// Sets the attributes returned by [CspContext::attributes] on `element`.
+    pub fn set_attributes(
+        &self,
+        element: &Element,
+        attributes: Vec<Box<dyn Attribute>>,
+    ) -> Result<(), AttributeError> {
+        for attribute in self.attributes(&element.tag_name(), attributes) {
+            set_attribute(element, attribute.as_ref())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for CspContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -110,7 +110,7 @@ gen_event_structs!(
     ContextMenu,
     DblClick,
     MouseDown,
-    MousEenter,
+    MouseEnter,
     MouseLeave,
     MouseMove,
     MouseOut,
@@ -1,2 +1,10 @@
+// The derives refer to this crate as `::domatt`, so that they work in other crates too.
+extern crate self as domatt;
+
 pub mod attributes;
+#[cfg(feature = "csp")]
+pub mod csp;
 pub mod events;
+#[cfg(feature = "microdata")]
+pub mod microdata;
+pub mod values;
@@ -0,0 +1,298 @@
+use crate::attributes::Attribute;
+use std::collections::HashMap;
+
+/// An element in an in-memory tree, built from the same attribute types that are set
+/// on real elements. Used with [extract] to check the microdata of rendered markup
+/// without a browser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    tag: String,
+    attributes: Vec<(String, Option<String>)>,
+    children: Vec<Child>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Child {
+    Element(Node),
+    Text(String),
+}
+
+impl Node {
+    pub fn new(tag: &str) -> Self {
+        Self {
+            tag: tag.to_ascii_lowercase(),
+            attributes: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets an attribute, replacing any previous value for the same key.
+    pub fn attribute<T: Attribute + ?Sized>(mut self, attribute: &T) -> Self {
+        let key = attribute.get_key().to_owned();
+        let value = attribute.get_val().map(str::to_owned);
+        match self.attributes.iter_mut().find(|(k, _)| *k == key) {
+            Some(existing) => existing.1 = value,
+            None => self.attributes.push((key, value)),
+        }
+        self
+    }
+
+    pub fn attributes(self, attributes: &[Box<dyn Attribute>]) -> Self {
+        attributes
+            .iter()
+            .fold(self, |node, attribute| node.attribute(attribute.as_ref()))
+    }
+
+    pub fn child(mut self, child: Node) -> Self {
+        self.children.push(Child::Element(child));
+        self
+    }
+
+    pub fn text(mut self, text: &str) -> Self {
+        self.children.push(Child::Text(text.to_owned()));
+        self
+    }
+
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Returns the value of an attribute. Boolean attributes have an empty value.
+    pub fn get_attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_deref().unwrap_or_default())
+    }
+
+    pub fn has_attribute(&self, key: &str) -> bool {
+        self.get_attribute(key).is_some()
+    }
+
+    /// Returns the text of every descendant, in tree order.
+    pub fn text_content(&self) -> String {
+        let mut text = String::new();
+        self.collect_text(&mut text);
+        text
+    }
+
+    fn collect_text(&self, text: &mut String) {
+        for child in &self.children {
+            match child {
+                Child::Element(node) => node.collect_text(text),
+                Child::Text(value) => text.push_str(value),
+            }
+        }
+    }
+
+    fn elements(&self) -> impl Iterator<Item = &Node> {
+        self.children.iter().filter_map(|child| match child {
+            Child::Element(node) => Some(node),
+            Child::Text(_) => None,
+        })
+    }
+
+    fn tokens(&self, key: &str) -> Vec<&str> {
+        self.get_attribute(key)
+            .map(|value| value.split_ascii_whitespace().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// A microdata item read from a tree of [Node]s.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ExtractedItem {
+    types: Vec<String>,
+    id: Option<String>,
+    properties: Vec<(String, PropertyValue)>,
+}
+
+impl ExtractedItem {
+    pub fn item_types(&self) -> &[String] {
+        &self.types
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Returns every property in tree order. A name appears once for each value.
+    pub fn properties(&self) -> &[(String, PropertyValue)] {
+        &self.properties
+    }
+
+    /// Returns the first value of the property with the given name.
+    pub fn property<'a>(&'a self, name: &'a str) -> Option<&'a PropertyValue> {
+        self.values(name).next()
+    }
+
+    /// Returns every value of the property with the given name.
+    pub fn values<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a PropertyValue> {
+        self.properties
+            .iter()
+            .filter(move |(property, _)| property == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// The value of a microdata property.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PropertyValue {
+    /// A string, such as the text of an element or the `href` of a link. URLs are not
+    /// resolved, since the tree has no base URL.
+    Text(String),
+    Item(ExtractedItem),
+    /// The item refers back to itself through `itemref`, which is a microdata error.
+    Error,
+}
+
+impl PropertyValue {
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            PropertyValue::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_item(&self) -> Option<&ExtractedItem> {
+        match self {
+            PropertyValue::Item(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the top-level items of a tree, that is every element with `itemscope` that
+/// isn't itself the value of a property, in tree order.
+///
+/// <https://html.spec.whatwg.org/multipage/microdata.html#associating-names-with-items>
+pub fn extract(root: &Node) -> Vec<ExtractedItem> {
+    let document = Document::new(root);
+    document
+        .order
+        .iter()
+        .filter(|node| node.has_attribute("itemscope") && !node.has_attribute("itemprop"))
+        .map(|node| document.item(node, &mut Vec::new()))
+        .collect()
+}
+
+/// Every element of the tree in tree order, with the lookups needed to crawl items.
+struct Document<'a> {
+    order: Vec<&'a Node>,
+    positions: HashMap<*const Node, usize>,
+    ids: HashMap<&'a str, &'a Node>,
+}
+
+impl<'a> Document<'a> {
+    fn new(root: &'a Node) -> Self {
+        let mut document = Self {
+            order: Vec::new(),
+            positions: HashMap::new(),
+            ids: HashMap::new(),
+        };
+        document.visit(root);
+        document
+    }
+
+    fn visit(&mut self, node: &'a Node) {
+        self.positions.insert(node, self.order.len());
+        self.order.push(node);
+        if let Some(id) = node.get_attribute("id") {
+            // The first element with an id wins, like getElementById.
+            self.ids.entry(id).or_insert(node);
+        }
+        for child in node.elements() {
+            self.visit(child);
+        }
+    }
+
+    fn item(&self, node: &'a Node, ancestors: &mut Vec<*const Node>) -> ExtractedItem {
+        ancestors.push(node);
+
+        let types: Vec<String> = node
+            .tokens("itemtype")
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let id = if types.is_empty() {
+            None
+        } else {
+            node.get_attribute("itemid").map(str::to_owned)
+        };
+
+        let mut properties = Vec::new();
+        for property in self.crawl(node) {
+            let value = if property.has_attribute("itemscope") {
+                if ancestors.contains(&(property as *const Node)) {
+                    PropertyValue::Error
+                } else {
+                    PropertyValue::Item(self.item(property, ancestors))
+                }
+            } else {
+                PropertyValue::Text(property_value(property))
+            };
+
+            for name in property.tokens("itemprop") {
+                properties.push((name.to_owned(), value.clone()));
+            }
+        }
+
+        ancestors.pop();
+        ExtractedItem {
+            types,
+            id,
+            properties,
+        }
+    }
+
+    /// Returns the elements holding the properties of the item on `root`, in tree order.
+    ///
+    /// <https://html.spec.whatwg.org/multipage/microdata.html#the-properties-of-an-item>
+    fn crawl(&self, root: &'a Node) -> Vec<&'a Node> {
+        let mut memory: Vec<*const Node> = vec![root];
+        let mut pending: Vec<&Node> = root.elements().collect();
+        pending.extend(
+            root.tokens("itemref")
+                .into_iter()
+                .filter_map(|id| self.ids.get(id).copied()),
+        );
+
+        let mut results = Vec::new();
+        while let Some(current) = pending.pop() {
+            if memory.contains(&(current as *const Node)) {
+                continue;
+            }
+            memory.push(current);
+
+            if !current.has_attribute("itemscope") {
+                pending.extend(current.elements());
+            }
+            if !current.tokens("itemprop").is_empty() {
+                results.push(current);
+            }
+        }
+
+        results.sort_by_key(|node| self.positions[&(*node as *const Node)]);
+        results
+    }
+}
+
+/// Returns the value of a property that isn't an item, which depends on the element.
+///
+/// <https://html.spec.whatwg.org/multipage/microdata.html#values>
+fn property_value(node: &Node) -> String {
+    let attribute = match node.tag() {
+        "meta" => Some("content"),
+        "audio" | "embed" | "iframe" | "img" | "source" | "track" | "video" => Some("src"),
+        "a" | "area" | "link" => Some("href"),
+        "object" => Some("data"),
+        "data" | "meter" => Some("value"),
+        "time" if node.has_attribute("datetime") => Some("datetime"),
+        _ => None,
+    };
+
+    match attribute {
+        Some(attribute) => node.get_attribute(attribute).unwrap_or_default().to_owned(),
+        None => node.text_content(),
+    }
+}
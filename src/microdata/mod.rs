@@ -0,0 +1,188 @@
+//! Helpers for annotating elements with microdata.
+//!
+//! An [Item] produces the `itemscope`, `itemtype`, `itemid` and `itemref` attributes of
+//! the element that holds an item, and [PropertyName] validates the names given to
+//! `itemprop`. The [schema_org] module has typed constants for common schema.org types
+//! and properties, and [extract] reads the items back out of a tree of elements, which
+//! is handy for testing the markup a component renders.
+//!
+//! <https://html.spec.whatwg.org/multipage/microdata.html>
+
+use crate::attributes::global::{ItemId, ItemProp, ItemRef, ItemScope, ItemType};
+use crate::attributes::Attribute;
+use crate::values::{impl_attr_value, Href, TokenList, ValueError};
+use std::borrow::Cow;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use url::Url;
+
+mod extract;
+pub mod schema_org;
+
+pub use extract::{extract, ExtractedItem, Node, PropertyValue};
+
+/// A microdata item, declared on the element that holds its properties.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Item {
+    item_type: Option<Url>,
+    id: Option<Href>,
+    refs: TokenList,
+}
+
+impl Item {
+    /// Creates an item without a type. Its property names are only meaningful to the
+    /// page that defines them.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an item of the given type, such as `https://schema.org/Person`. The type
+    /// defines which properties the item may have.
+    pub fn typed(item_type: &str) -> Result<Self, ValueError> {
+        let item_type = Url::parse(item_type)
+            .map_err(|_| ValueError::new(item_type, "Item types must be absolute URLs."))?;
+
+        Ok(Self {
+            item_type: Some(item_type),
+            ..Self::default()
+        })
+    }
+
+    /// Sets the global identifier of the item. Only typed items can have one.
+    pub fn id(mut self, id: Href) -> Result<Self, ValueError> {
+        if self.item_type.is_none() {
+            return Err(ValueError::new(
+                id.as_str(),
+                "Only items with a type can have an itemid.",
+            ));
+        }
+
+        self.id = Some(id);
+        Ok(self)
+    }
+
+    /// Adds the properties of the element with the given id to the item, for
+    /// properties that aren't descendants of the item's element.
+    pub fn item_ref(mut self, id: &str) -> Result<Self, ValueError> {
+        self.refs.add(id.to_owned())?;
+        Ok(self)
+    }
+
+    pub fn item_scope(&self) -> ItemScope {
+        ItemScope
+    }
+
+    pub fn item_type(&self) -> Option<ItemType> {
+        self.item_type.clone().map(ItemType::new)
+    }
+
+    pub fn item_id(&self) -> Option<ItemId> {
+        self.id.clone().map(ItemId::new)
+    }
+
+    pub fn item_refs(&self) -> Option<ItemRef> {
+        (!self.refs.is_empty()).then(|| ItemRef::new(self.refs.clone()))
+    }
+
+    /// Returns every attribute needed to declare the item.
+    pub fn attributes(&self) -> Vec<Box<dyn Attribute>> {
+        let mut attributes: Vec<Box<dyn Attribute>> = vec![Box::new(self.item_scope())];
+        if let Some(item_type) = self.item_type() {
+            attributes.push(Box::new(item_type));
+        }
+        if let Some(id) = self.item_id() {
+            attributes.push(Box::new(id));
+        }
+        if let Some(refs) = self.item_refs() {
+            attributes.push(Box::new(refs));
+        }
+
+        attributes
+    }
+}
+
+impl From<schema_org::Type> for Item {
+    fn from(item_type: schema_org::Type) -> Self {
+        Self {
+            item_type: Some(item_type.url()),
+            ..Self::default()
+        }
+    }
+}
+
+/// The name of a microdata property, used in `itemprop`. A name is either an absolute
+/// URL or a word without `.` or `:`, such as `name`.
+///
+/// <https://html.spec.whatwg.org/multipage/microdata.html#names:-the-itemprop-attribute>
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PropertyName(Cow<'static, str>);
+
+impl PropertyName {
+    pub fn new(name: &str) -> Result<Self, ValueError> {
+        if name.is_empty() || name.contains(|c: char| c.is_ascii_whitespace()) {
+            return Err(ValueError::new(
+                name,
+                "Property names may not be empty or contain whitespace.",
+            ));
+        }
+
+        let is_valid = if name.contains(':') {
+            Url::parse(name).is_ok()
+        } else {
+            !name.contains('.')
+        };
+        if !is_valid {
+            return Err(ValueError::new(
+                name,
+                "Property names must be absolute URLs or may not contain '.' or ':'.",
+            ));
+        }
+
+        Ok(Self(Cow::Owned(name.to_owned())))
+    }
+
+    /// Creates a name known to be valid, for constants.
+    pub(crate) const fn from_static(name: &'static str) -> Self {
+        Self(Cow::Borrowed(name))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for PropertyName {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl AsRef<str> for PropertyName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for PropertyName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl_attr_value!(PropertyName);
+
+impl From<PropertyName> for ItemProp {
+    fn from(name: PropertyName) -> Self {
+        ItemProp::new(name.to_string())
+    }
+}
+
+/// An element can hold several properties with the same value, such as both the
+/// `name` and `headline` of an article.
+impl From<TokenList<PropertyName>> for ItemProp {
+    fn from(names: TokenList<PropertyName>) -> Self {
+        ItemProp::new(names.to_string())
+    }
+}
@@ -0,0 +1,91 @@
+//! Common types and properties of the schema.org vocabulary.
+//!
+//! Only a small part of the vocabulary is covered. Other types can be used through
+//! [Item::typed](super::Item::typed), and other properties through
+//! [PropertyName::new].
+//!
+//! <https://schema.org/docs/full.html>
+
+use super::PropertyName;
+use attribute_derive::AttributeValue;
+use url::Url;
+
+const BASE: &str = "https://schema.org/";
+
+/// A schema.org type, serialized as its name, such as `Person`.
+#[derive(Debug, Copy, AttributeValue)]
+#[attribute_value(case_sensitive)]
+pub enum Type {
+    AggregateRating,
+    Article,
+    BlogPosting,
+    Book,
+    BreadcrumbList,
+    Event,
+    ImageObject,
+    ListItem,
+    LocalBusiness,
+    Offer,
+    Organization,
+    Person,
+    Place,
+    PostalAddress,
+    Product,
+    Rating,
+    Recipe,
+    Review,
+    Thing,
+    VideoObject,
+    WebPage,
+    WebSite,
+}
+
+impl Type {
+    /// Returns the URL used in `itemtype`, such as `https://schema.org/Person`.
+    pub fn url(&self) -> Url {
+        Url::parse(&format!("{BASE}{self}")).expect("schema.org type URLs to be valid")
+    }
+}
+
+macro_rules! properties {
+    ($($const_name:ident => $name:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("The `", $name, "` property.")]
+            pub const $const_name: PropertyName = PropertyName::from_static($name);
+        )*
+    };
+}
+
+properties! {
+    ADDRESS => "address",
+    ADDRESS_COUNTRY => "addressCountry",
+    ADDRESS_LOCALITY => "addressLocality",
+    AGGREGATE_RATING => "aggregateRating",
+    AUTHOR => "author",
+    BIRTH_DATE => "birthDate",
+    BRAND => "brand",
+    DATE_PUBLISHED => "datePublished",
+    DESCRIPTION => "description",
+    EMAIL => "email",
+    END_DATE => "endDate",
+    HEADLINE => "headline",
+    IMAGE => "image",
+    ITEM => "item",
+    ITEM_LIST_ELEMENT => "itemListElement",
+    JOB_TITLE => "jobTitle",
+    LOCATION => "location",
+    NAME => "name",
+    OFFERS => "offers",
+    POSITION => "position",
+    POSTAL_CODE => "postalCode",
+    PRICE => "price",
+    PRICE_CURRENCY => "priceCurrency",
+    RATING_VALUE => "ratingValue",
+    REVIEW_COUNT => "reviewCount",
+    SKU => "sku",
+    START_DATE => "startDate",
+    STREET_ADDRESS => "streetAddress",
+    TELEPHONE => "telephone",
+    URL => "url",
+    WORKS_FOR => "worksFor",
+}
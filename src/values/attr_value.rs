@@ -0,0 +1,258 @@
+use super::{
+    AutocompleteValue, Calc, CommaList, Date, DateTimeValue, DateWithOptionalTime, Duration,
+    FieldName, GlobalDateTime, Href, InputBound, InputDateValue, Integrity, IntegrityMetadata,
+    KeywordOr, Length, LengthPercentage, LengthPercentageList, LocalDateTime, Month, Number,
+    NumberList, NumberOptionalNumber, NumberOrPercentage, Percentage, Time, TimeZoneOffset,
+    TokenList, ValueError, ViewBoxRect, Week, YearlessDate,
+};
+use std::fmt::Display;
+use std::str::FromStr;
+use url::Url;
+
+/// A type that can be written as the value of an attribute.
+///
+/// `#[derive(Attribute)]` uses it to serialize the value taken by the generated `new`,
+/// so a type defined outside of this crate can be the value of an attribute as long as
+/// it implements this trait and [FromAttrValue].
+pub trait ToAttrValue {
+    /// Returns the value as it appears in the attribute.
+    fn to_attr_value(&self) -> String;
+}
+
+/// A type that can be read back from the value of an attribute. The derived `FromStr`
+/// of an attribute parses its value with it.
+pub trait FromAttrValue: Sized {
+    fn from_attr_value(value: &str) -> Result<Self, ValueError>;
+}
+
+/// Implements [ToAttrValue] with `Display` and [FromAttrValue] with `FromStr`, for types
+/// whose `FromStr` already fails with a [ValueError].
+macro_rules! impl_attr_value {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl $crate::values::ToAttrValue for $ty {
+                fn to_attr_value(&self) -> String {
+                    self.to_string()
+                }
+            }
+
+            impl $crate::values::FromAttrValue for $ty {
+                fn from_attr_value(
+                    value: &str,
+                ) -> Result<Self, $crate::values::ValueError> {
+                    value.parse()
+                }
+            }
+        )+
+    };
+}
+pub(crate) use impl_attr_value;
+
+impl_attr_value!(
+    AutocompleteValue,
+    Calc,
+    Date,
+    DateTimeValue,
+    DateWithOptionalTime,
+    Duration,
+    FieldName,
+    GlobalDateTime,
+    Href,
+    InputBound,
+    InputDateValue,
+    Integrity,
+    IntegrityMetadata,
+    Length,
+    LengthPercentage,
+    LengthPercentageList,
+    LocalDateTime,
+    Month,
+    Number,
+    NumberList,
+    NumberOptionalNumber,
+    NumberOrPercentage,
+    Percentage,
+    Time,
+    TimeZoneOffset,
+    ViewBoxRect,
+    Week,
+    YearlessDate,
+);
+
+impl ToAttrValue for String {
+    fn to_attr_value(&self) -> String {
+        self.clone()
+    }
+}
+
+impl FromAttrValue for String {
+    fn from_attr_value(value: &str) -> Result<Self, ValueError> {
+        Ok(value.to_owned())
+    }
+}
+
+impl ToAttrValue for char {
+    fn to_attr_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl FromAttrValue for char {
+    fn from_attr_value(value: &str) -> Result<Self, ValueError> {
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(ValueError::new(value, "Expected a single character.")),
+        }
+    }
+}
+
+/// Booleans are written as the keywords `true` and `false`, as ARIA and SVG attributes
+/// expect. HTML boolean attributes are unit structs instead.
+impl ToAttrValue for bool {
+    fn to_attr_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl FromAttrValue for bool {
+    fn from_attr_value(value: &str) -> Result<Self, ValueError> {
+        match value {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            _ => Err(ValueError::new(value, "Expected `true` or `false`.")),
+        }
+    }
+}
+
+macro_rules! impl_attr_value_for_integers {
+    ($($ty:ty),+) => {
+        $(
+            impl ToAttrValue for $ty {
+                fn to_attr_value(&self) -> String {
+                    self.to_string()
+                }
+            }
+
+            impl FromAttrValue for $ty {
+                fn from_attr_value(value: &str) -> Result<Self, ValueError> {
+                    value
+                        .parse()
+                        .map_err(|_| ValueError::new(value, "Invalid integer."))
+                }
+            }
+        )+
+    };
+}
+
+impl_attr_value_for_integers!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Floats are written the way JavaScript would write them. `NaN` and infinities can't
+/// be parsed back, since no attribute accepts them.
+macro_rules! impl_attr_value_for_floats {
+    ($($ty:ty),+) => {
+        $(
+            impl ToAttrValue for $ty {
+                fn to_attr_value(&self) -> String {
+                    super::format_number(f64::from(*self))
+                }
+            }
+
+            impl FromAttrValue for $ty {
+                fn from_attr_value(value: &str) -> Result<Self, ValueError> {
+                    let number: Number = value.parse()?;
+                    Ok(number.value() as $ty)
+                }
+            }
+        )+
+    };
+}
+
+impl_attr_value_for_floats!(f32, f64);
+
+impl ToAttrValue for Url {
+    fn to_attr_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl FromAttrValue for Url {
+    fn from_attr_value(value: &str) -> Result<Self, ValueError> {
+        Url::parse(value).map_err(|err| ValueError::new(value, &format!("Invalid URL: {err}.")))
+    }
+}
+
+/// `None` is written as an empty value.
+impl<T: ToAttrValue> ToAttrValue for Option<T> {
+    fn to_attr_value(&self) -> String {
+        match self {
+            Some(value) => value.to_attr_value(),
+            None => String::new(),
+        }
+    }
+}
+
+impl<T: FromAttrValue> FromAttrValue for Option<T> {
+    fn from_attr_value(value: &str) -> Result<Self, ValueError> {
+        if value.is_empty() {
+            Ok(None)
+        } else {
+            T::from_attr_value(value).map(Some)
+        }
+    }
+}
+
+/// Items are separated by spaces. Unlike [TokenList], duplicates are kept.
+impl<T: ToAttrValue> ToAttrValue for Vec<T> {
+    fn to_attr_value(&self) -> String {
+        self.iter()
+            .map(ToAttrValue::to_attr_value)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl<T: FromAttrValue> FromAttrValue for Vec<T> {
+    fn from_attr_value(value: &str) -> Result<Self, ValueError> {
+        value
+            .split_ascii_whitespace()
+            .map(T::from_attr_value)
+            .collect()
+    }
+}
+
+impl<T: Display + FromStr> ToAttrValue for CommaList<T> {
+    fn to_attr_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<T: Display + FromStr> FromAttrValue for CommaList<T> {
+    fn from_attr_value(value: &str) -> Result<Self, ValueError> {
+        value.parse()
+    }
+}
+
+impl<K: Display, V: Display> ToAttrValue for KeywordOr<K, V> {
+    fn to_attr_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<K: FromStr, V: FromStr<Err = ValueError>> FromAttrValue for KeywordOr<K, V> {
+    fn from_attr_value(value: &str) -> Result<Self, ValueError> {
+        value.parse()
+    }
+}
+
+impl<T: ToAttrValue + FromStr> ToAttrValue for TokenList<T> {
+    fn to_attr_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<T: ToAttrValue + FromStr> FromAttrValue for TokenList<T> {
+    fn from_attr_value(value: &str) -> Result<Self, ValueError> {
+        value.parse()
+    }
+}
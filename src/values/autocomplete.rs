@@ -0,0 +1,467 @@
+//! Types for the `autocomplete` attribute of `input`, `select` and `textarea`
+//! elements.
+//!
+//! Besides `on` and `off`, the attribute takes a list of autofill detail tokens in
+//! a fixed order: an optional `section-*` name, an optional `shipping` or `billing`
+//! hint, an optional contact type, the field name and an optional `webauthn` token.
+//! [Autofill] builds such a list and always serializes the tokens in that order.
+//! Contact types such as `work` only apply to contact fields like `email`, which
+//! is enforced by only offering [Autofill::contact_type] for [ContactField]s.
+//!
+//! <https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#autofill>
+
+use super::ValueError;
+use attribute_derive::AttributeValue;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+const SECTION_PREFIX: &str = "section-";
+
+/// The value of an `autocomplete` attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AutocompleteValue {
+    /// The browser may fill in the field, but no hint is given about what it holds.
+    On,
+    /// The field holds sensitive or one-off data that shouldn't be filled in.
+    Off,
+    Autofill(Autofill<FieldName>),
+}
+
+impl<F: Into<FieldName>> From<Autofill<F>> for AutocompleteValue {
+    fn from(autofill: Autofill<F>) -> Self {
+        AutocompleteValue::Autofill(autofill.into_field_name())
+    }
+}
+
+impl From<AutofillField> for AutocompleteValue {
+    fn from(field: AutofillField) -> Self {
+        Autofill::new(field).into()
+    }
+}
+
+impl From<ContactField> for AutocompleteValue {
+    fn from(field: ContactField) -> Self {
+        Autofill::new(field).into()
+    }
+}
+
+impl Display for AutocompleteValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AutocompleteValue::On => f.write_str("on"),
+            AutocompleteValue::Off => f.write_str("off"),
+            AutocompleteValue::Autofill(autofill) => autofill.fmt(f),
+        }
+    }
+}
+
+impl FromStr for AutocompleteValue {
+    type Err = ValueError;
+
+    /// Parses a value following the autofill grammar. Tokens are matched ASCII
+    /// case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |message: &str| ValueError::new(s, message);
+        let lowercase = s.to_ascii_lowercase();
+        let mut tokens: Vec<&str> = lowercase.split_ascii_whitespace().collect();
+
+        match tokens.as_slice() {
+            ["on"] => return Ok(AutocompleteValue::On),
+            ["off"] => return Ok(AutocompleteValue::Off),
+            [] => return Err(invalid("Autocomplete values may not be empty.")),
+            _ => (),
+        }
+
+        let webauthn = tokens.last() == Some(&"webauthn");
+        if webauthn {
+            tokens.pop();
+        }
+
+        let field: FieldName = tokens
+            .pop()
+            .ok_or_else(|| invalid("Missing autofill field name."))?
+            .parse()
+            .map_err(|_| invalid("Unknown autofill field name."))?;
+
+        let mut contact_type = None;
+        if let (FieldName::Contact(_), Some(token)) = (field, tokens.last()) {
+            if let Ok(parsed) = token.parse() {
+                contact_type = Some(parsed);
+                tokens.pop();
+            }
+        }
+
+        let mut address_type = None;
+        if let Some(token) = tokens.last() {
+            if let Ok(parsed) = token.parse() {
+                address_type = Some(parsed);
+                tokens.pop();
+            }
+        }
+
+        let mut section = None;
+        if let Some(token) = tokens.last() {
+            if let Some(name) = token
+                .strip_prefix(SECTION_PREFIX)
+                .filter(|name| !name.is_empty())
+            {
+                section = Some(name.to_owned());
+                tokens.pop();
+            }
+        }
+
+        if !tokens.is_empty() {
+            return Err(invalid("Autofill tokens are invalid or out of order."));
+        }
+
+        Ok(AutocompleteValue::Autofill(Autofill {
+            section,
+            address_type,
+            contact_type,
+            field,
+            webauthn,
+        }))
+    }
+}
+
+/// Builder for a list of autofill detail tokens, such as `section-blue shipping
+/// street-address` or `work email webauthn`. `F` is either an [AutofillField] or a
+/// [ContactField]; only the latter accepts a [ContactType].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Autofill<F> {
+    section: Option<String>,
+    address_type: Option<AddressType>,
+    contact_type: Option<ContactType>,
+    field: F,
+    webauthn: bool,
+}
+
+impl<F: Into<FieldName>> Autofill<F> {
+    pub fn new(field: F) -> Self {
+        Self {
+            section: None,
+            address_type: None,
+            contact_type: None,
+            field,
+            webauthn: false,
+        }
+    }
+
+    /// Groups the field with the other fields of the same section, so that a form
+    /// with several addresses can be filled in correctly. The name is serialized with
+    /// a `section-` prefix and may not be empty or contain whitespace.
+    pub fn section(mut self, name: &str) -> Result<Self, ValueError> {
+        if name.is_empty() || name.contains(|c: char| c.is_ascii_whitespace()) {
+            return Err(ValueError::new(
+                name,
+                "Section names may not be empty or contain whitespace.",
+            ));
+        }
+
+        self.section = Some(name.to_owned());
+        Ok(self)
+    }
+
+    /// Marks the field as part of a shipping address.
+    pub fn shipping(mut self) -> Self {
+        self.address_type = Some(AddressType::Shipping);
+        self
+    }
+
+    /// Marks the field as part of a billing address.
+    pub fn billing(mut self) -> Self {
+        self.address_type = Some(AddressType::Billing);
+        self
+    }
+
+    /// Lets the browser offer passkeys when the field is focused.
+    pub fn webauthn(mut self) -> Self {
+        self.webauthn = true;
+        self
+    }
+
+    fn into_field_name(self) -> Autofill<FieldName> {
+        Autofill {
+            section: self.section,
+            address_type: self.address_type,
+            contact_type: self.contact_type,
+            field: self.field.into(),
+            webauthn: self.webauthn,
+        }
+    }
+}
+
+impl Autofill<ContactField> {
+    /// Sets what kind of contact information the field holds, such as a work email.
+    pub fn contact_type(mut self, contact_type: ContactType) -> Self {
+        self.contact_type = Some(contact_type);
+        self
+    }
+}
+
+impl<F: Into<FieldName> + Copy> Display for Autofill<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(section) = &self.section {
+            write!(f, "{SECTION_PREFIX}{section} ")?;
+        }
+        if let Some(address_type) = self.address_type {
+            write!(f, "{address_type} ")?;
+        }
+        if let Some(contact_type) = self.contact_type {
+            write!(f, "{contact_type} ")?;
+        }
+        write!(f, "{}", self.field.into())?;
+        if self.webauthn {
+            f.write_str(" webauthn")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether an address field is part of a shipping or billing address.
+#[derive(Debug, Copy, AttributeValue)]
+#[attribute_value(case = "lowercase")]
+pub enum AddressType {
+    Shipping,
+    Billing,
+}
+
+/// The kind of contact information held by a [ContactField].
+#[derive(Debug, Copy, AttributeValue)]
+#[attribute_value(case = "lowercase")]
+pub enum ContactType {
+    Home,
+    Work,
+    Mobile,
+    Fax,
+    Pager,
+}
+
+/// An autofill field name that can't be given a [ContactType].
+///
+/// <https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#autofill-field>
+#[derive(Debug, Copy, AttributeValue)]
+#[attribute_value(case = "kebab-case")]
+pub enum AutofillField {
+    Name,
+    HonorificPrefix,
+    GivenName,
+    AdditionalName,
+    FamilyName,
+    HonorificSuffix,
+    Nickname,
+    Username,
+    NewPassword,
+    CurrentPassword,
+    OneTimeCode,
+    OrganizationTitle,
+    Organization,
+    StreetAddress,
+    #[attribute_value(rename = "address-line1")]
+    AddressLine1,
+    #[attribute_value(rename = "address-line2")]
+    AddressLine2,
+    #[attribute_value(rename = "address-line3")]
+    AddressLine3,
+    #[attribute_value(rename = "address-level4")]
+    AddressLevel4,
+    #[attribute_value(rename = "address-level3")]
+    AddressLevel3,
+    #[attribute_value(rename = "address-level2")]
+    AddressLevel2,
+    #[attribute_value(rename = "address-level1")]
+    AddressLevel1,
+    Country,
+    CountryName,
+    PostalCode,
+    CcName,
+    CcGivenName,
+    CcAdditionalName,
+    CcFamilyName,
+    CcNumber,
+    CcExp,
+    CcExpMonth,
+    CcExpYear,
+    CcCsc,
+    CcType,
+    TransactionCurrency,
+    TransactionAmount,
+    Language,
+    Bday,
+    BdayDay,
+    BdayMonth,
+    BdayYear,
+    Sex,
+    Url,
+    Photo,
+}
+
+/// An autofill field name for contact information, which can be given a
+/// [ContactType].
+///
+/// <https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#autofill-field>
+#[derive(Debug, Copy, AttributeValue)]
+#[attribute_value(case = "kebab-case")]
+pub enum ContactField {
+    Tel,
+    TelCountryCode,
+    TelNational,
+    TelAreaCode,
+    TelLocal,
+    TelLocalPrefix,
+    TelLocalSuffix,
+    TelExtension,
+    Email,
+    Impp,
+}
+
+/// Either kind of autofill field name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldName {
+    Field(AutofillField),
+    Contact(ContactField),
+}
+
+impl From<AutofillField> for FieldName {
+    fn from(field: AutofillField) -> Self {
+        FieldName::Field(field)
+    }
+}
+
+impl From<ContactField> for FieldName {
+    fn from(field: ContactField) -> Self {
+        FieldName::Contact(field)
+    }
+}
+
+impl AsRef<str> for FieldName {
+    fn as_ref(&self) -> &str {
+        match self {
+            FieldName::Field(field) => field.as_ref(),
+            FieldName::Contact(field) => field.as_ref(),
+        }
+    }
+}
+
+impl Display for FieldName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl FromStr for FieldName {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse()
+            .map(FieldName::Field)
+            .or_else(|_| s.parse().map(FieldName::Contact))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Result<AutocompleteValue, ValueError> {
+        input.parse()
+    }
+
+    #[test]
+    fn tokens_follow_the_grammar_order() {
+        let value = parse("section-blue shipping work email webauthn").unwrap();
+        assert_eq!(
+            value,
+            Autofill::new(ContactField::Email)
+                .section("blue")
+                .unwrap()
+                .shipping()
+                .contact_type(ContactType::Work)
+                .webauthn()
+                .into()
+        );
+        assert!(parse("billing street-address").is_ok());
+        assert!(parse("section-a cc-number").is_ok());
+        for input in [
+            "shipping section-blue street-address",
+            "work shipping email",
+            "email work",
+            "webauthn username",
+            "street-address shipping",
+            "section- name",
+            "shipping billing name",
+            "username current-password",
+        ] {
+            assert!(parse(input).is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn contact_types_only_apply_to_contact_fields() {
+        for input in ["home tel", "mobile tel-national", "fax email", "pager impp"] {
+            assert!(parse(input).is_ok(), "{input:?}");
+        }
+        for input in ["work name", "home street-address", "mobile cc-number"] {
+            assert!(parse(input).is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn on_and_off_stand_alone() {
+        assert_eq!(parse("on"), Ok(AutocompleteValue::On));
+        assert_eq!(parse(" off "), Ok(AutocompleteValue::Off));
+        for input in [
+            "",
+            "  ",
+            "on email",
+            "off webauthn",
+            "section-a off",
+            "on off",
+        ] {
+            assert!(parse(input).is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn tokens_are_case_insensitive() {
+        assert_eq!(parse("OFF"), Ok(AutocompleteValue::Off));
+        let value = parse("Section-Blue SHIPPING Work Email WebAuthn").unwrap();
+        assert_eq!(
+            value.to_string(),
+            "section-blue shipping work email webauthn"
+        );
+        assert_eq!(parse("Given-Name"), Ok(AutofillField::GivenName.into()));
+    }
+
+    #[test]
+    fn builder_output_round_trips() {
+        let values: Vec<AutocompleteValue> = vec![
+            AutocompleteValue::On,
+            AutocompleteValue::Off,
+            AutofillField::OneTimeCode.into(),
+            Autofill::new(AutofillField::AddressLine1)
+                .section("work-address")
+                .unwrap()
+                .billing()
+                .into(),
+            Autofill::new(ContactField::TelExtension)
+                .contact_type(ContactType::Home)
+                .into(),
+            Autofill::new(AutofillField::Username).webauthn().into(),
+        ];
+        for value in values {
+            assert_eq!(parse(&value.to_string()), Ok(value.clone()), "{value}");
+        }
+        assert_eq!(
+            Autofill::new(ContactField::Tel)
+                .webauthn()
+                .contact_type(ContactType::Mobile)
+                .shipping()
+                .to_string(),
+            "shipping mobile tel webauthn"
+        );
+        assert!(Autofill::new(AutofillField::Name).section("a b").is_err());
+        assert!(Autofill::new(AutofillField::Name).section("").is_err());
+    }
+}
@@ -0,0 +1,156 @@
+use super::ValueError;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A list of comma-separated tokens, such as the value of `coords` or `accept`. Unlike
+/// a [TokenList](super::TokenList), the list keeps duplicates, since a repeated value
+/// such as a coordinate is meaningful.
+///
+/// Items are serialized with their [Display] implementation and may not be empty or
+/// contain a comma. Whitespace around each item is ignored when parsing.
+///
+/// <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#comma-separated-tokens>
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommaList<T = String> {
+    items: Vec<T>,
+}
+
+impl<T: Display> CommaList<T> {
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Adds `item` to the end of the list.
+    pub fn push(&mut self, item: T) -> Result<(), ValueError> {
+        let serialized = item.to_string();
+        if serialized.is_empty() {
+            return Err(ValueError::new(&serialized, "List items may not be empty."));
+        }
+        if serialized.contains(',') {
+            return Err(ValueError::new(
+                &serialized,
+                "List items may not contain a comma.",
+            ));
+        }
+
+        self.items.push(item);
+        Ok(())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T: Display> Default for CommaList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Display> TryFrom<Vec<T>> for CommaList<T> {
+    type Error = ValueError;
+
+    fn try_from(items: Vec<T>) -> Result<Self, Self::Error> {
+        let mut list = Self::new();
+        for item in items {
+            list.push(item)?;
+        }
+
+        Ok(list)
+    }
+}
+
+impl<T: Display + FromStr> FromStr for CommaList<T> {
+    type Err = ValueError;
+
+    /// Splits `s` on commas and parses each item with surrounding ASCII whitespace
+    /// removed. An empty or all-whitespace string is an empty list.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut list = Self::new();
+        if s.trim_matches(|c: char| c.is_ascii_whitespace()).is_empty() {
+            return Ok(list);
+        }
+
+        for item in s.split(',') {
+            let item = item.trim_matches(|c: char| c.is_ascii_whitespace());
+            let parsed = item
+                .parse()
+                .map_err(|_| ValueError::new(item, "Invalid list item."))?;
+            list.push(parsed)?;
+        }
+
+        Ok(list)
+    }
+}
+
+impl<T: Display> Display for CommaList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, item) in self.items.iter().enumerate() {
+            if index > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{item}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> IntoIterator for CommaList<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::values::Number;
+
+    #[test]
+    fn whitespace_around_items_is_ignored() {
+        let list: CommaList = " image/png ,\timage/jpeg,.pdf\n".parse().unwrap();
+        let items: Vec<&str> = list.iter().map(String::as_str).collect();
+        assert_eq!(items, ["image/png", "image/jpeg", ".pdf"]);
+        assert_eq!(list.to_string(), "image/png,image/jpeg,.pdf");
+    }
+
+    #[test]
+    fn empty_items_are_rejected() {
+        assert!("".parse::<CommaList>().unwrap().is_empty());
+        assert!("  ".parse::<CommaList>().unwrap().is_empty());
+        for input in ["a,,b", "a,", ",a", " , "] {
+            assert!(input.parse::<CommaList>().is_err(), "{input:?}");
+        }
+
+        let mut list = CommaList::new();
+        assert!(list.push(String::new()).is_err());
+        assert!(list.push(String::from("a,b")).is_err());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn round_trip() {
+        let list: CommaList<Number> = "1, 1, 2.50,-0".parse().unwrap();
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.to_string(), "1,1,2.5,0");
+        assert_eq!(list.to_string().parse::<CommaList<Number>>(), Ok(list));
+        assert!("1,two".parse::<CommaList<Number>>().is_err());
+    }
+}
@@ -0,0 +1,131 @@
+//! Conversions between the date and time values and the types of the `chrono` crate.
+
+use super::{Date, Duration, GlobalDateTime, LocalDateTime, Time, TimeZoneOffset};
+use crate::values::ValueError;
+use ::chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeDelta,
+    TimeZone, Timelike,
+};
+
+impl TryFrom<NaiveDate> for Date {
+    type Error = ValueError;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        let year = u32::try_from(date.year())
+            .map_err(|_| ValueError::new(&date.to_string(), "Years before 1 are not allowed."))?;
+        Date::new(year, date.month() as u8, date.day() as u8)
+    }
+}
+
+impl TryFrom<Date> for NaiveDate {
+    type Error = ValueError;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        i32::try_from(date.year)
+            .ok()
+            .and_then(|year| NaiveDate::from_ymd_opt(year, date.month.into(), date.day.into()))
+            .ok_or_else(|| ValueError::new(&date.to_string(), "Date out of range for chrono."))
+    }
+}
+
+impl From<NaiveTime> for Time {
+    /// Sub-millisecond precision is truncated and leap seconds are clamped to the
+    /// last millisecond of the minute.
+    fn from(time: NaiveTime) -> Self {
+        let millisecond = (time.nanosecond() / 1_000_000).min(999) as u16;
+        Self {
+            hour: time.hour() as u8,
+            minute: time.minute() as u8,
+            second: time.second() as u8,
+            millisecond,
+        }
+    }
+}
+
+impl From<Time> for NaiveTime {
+    fn from(time: Time) -> Self {
+        NaiveTime::from_hms_milli_opt(
+            time.hour.into(),
+            time.minute.into(),
+            time.second.into(),
+            time.millisecond.into(),
+        )
+        .expect("a validated time to be valid")
+    }
+}
+
+impl TryFrom<NaiveDateTime> for LocalDateTime {
+    type Error = ValueError;
+
+    fn try_from(datetime: NaiveDateTime) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            datetime.date().try_into()?,
+            datetime.time().into(),
+        ))
+    }
+}
+
+impl TryFrom<LocalDateTime> for NaiveDateTime {
+    type Error = ValueError;
+
+    fn try_from(datetime: LocalDateTime) -> Result<Self, Self::Error> {
+        Ok(NaiveDateTime::new(
+            datetime.date.try_into()?,
+            datetime.time.into(),
+        ))
+    }
+}
+
+impl TryFrom<FixedOffset> for TimeZoneOffset {
+    type Error = ValueError;
+
+    fn try_from(offset: FixedOffset) -> Result<Self, Self::Error> {
+        let seconds = offset.local_minus_utc();
+        if seconds % 60 != 0 {
+            return Err(ValueError::new(
+                &offset.to_string(),
+                "Time-zone offsets must be a whole number of minutes.",
+            ));
+        }
+
+        Self::from_minutes((seconds / 60) as i16)
+    }
+}
+
+impl From<TimeZoneOffset> for FixedOffset {
+    fn from(offset: TimeZoneOffset) -> Self {
+        FixedOffset::east_opt(i32::from(offset.minutes) * 60)
+            .expect("a validated offset to be in range")
+    }
+}
+
+impl<Tz: TimeZone> TryFrom<DateTime<Tz>> for GlobalDateTime {
+    type Error = ValueError;
+
+    fn try_from(datetime: DateTime<Tz>) -> Result<Self, Self::Error> {
+        let offset = datetime.offset().fix().try_into()?;
+        Ok(Self::new(datetime.naive_local().try_into()?, offset))
+    }
+}
+
+impl TryFrom<GlobalDateTime> for DateTime<FixedOffset> {
+    type Error = ValueError;
+
+    fn try_from(datetime: GlobalDateTime) -> Result<Self, Self::Error> {
+        let local = NaiveDateTime::try_from(datetime.local)?;
+        FixedOffset::from(datetime.offset)
+            .from_local_datetime(&local)
+            .single()
+            .ok_or_else(|| ValueError::new(&datetime.to_string(), "Date out of range for chrono."))
+    }
+}
+
+impl TryFrom<TimeDelta> for Duration {
+    type Error = ValueError;
+
+    fn try_from(delta: TimeDelta) -> Result<Self, Self::Error> {
+        u64::try_from(delta.num_milliseconds())
+            .map(Self::from_millis)
+            .map_err(|_| ValueError::new(&delta.to_string(), "Durations cannot be negative."))
+    }
+}
@@ -0,0 +1,986 @@
+//! Types for the HTML date and time microsyntaxes.
+//!
+//! <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#dates-and-times>
+
+use super::{Number, ValueError};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[cfg(feature = "chrono")]
+mod chrono;
+
+#[cfg(feature = "time-crate")]
+mod time;
+
+/// A calendar date, such as `2011-11-18`.
+///
+/// <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#dates>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: u32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    pub fn new(year: u32, month: u8, day: u8) -> Result<Self, ValueError> {
+        Month::new(year, month)?;
+        if day == 0 || day > days_in_month(year, month) {
+            return Err(ValueError::new(
+                &format!("{year:04}-{month:02}-{day:02}"),
+                "Day out of range for the month.",
+            ));
+        }
+
+        Ok(Self { year, month, day })
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid date string.");
+        let (month, day) = s.rsplit_once('-').ok_or_else(invalid)?;
+        let month = month.parse::<Month>().map_err(|_| invalid())?;
+        let day = fixed_digits(day, 2).ok_or_else(invalid)?;
+
+        Self::new(month.year, month.month, day as u8).map_err(|_| invalid())
+    }
+}
+
+/// A year and month, such as `2011-11`.
+///
+/// <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#months>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Month {
+    year: u32,
+    month: u8,
+}
+
+impl Month {
+    pub fn new(year: u32, month: u8) -> Result<Self, ValueError> {
+        if year == 0 {
+            return Err(ValueError::new("0000", "Years start at 1."));
+        }
+        if !(1..=12).contains(&month) {
+            return Err(ValueError::new(&month.to_string(), "Month out of range."));
+        }
+
+        Ok(Self { year, month })
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+}
+
+impl Display for Month {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year, self.month)
+    }
+}
+
+impl FromStr for Month {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid month string.");
+        let (year, month) = s.split_once('-').ok_or_else(invalid)?;
+        let year = year_digits(year).ok_or_else(invalid)?;
+        let month = fixed_digits(month, 2).ok_or_else(invalid)?;
+
+        Self::new(year, month as u8).map_err(|_| invalid())
+    }
+}
+
+/// A month and day without a year, such as `12-25`.
+///
+/// <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#yearless-dates>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearlessDate {
+    month: u8,
+    day: u8,
+}
+
+impl YearlessDate {
+    /// Creates a yearless date. February 29th is allowed since the year is unknown.
+    pub fn new(month: u8, day: u8) -> Result<Self, ValueError> {
+        // 4 is a leap year, so February has its maximum number of days.
+        Date::new(4, month, day).map_err(|_| {
+            ValueError::new(&format!("{month:02}-{day:02}"), "Invalid yearless date.")
+        })?;
+
+        Ok(Self { month, day })
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl Display for YearlessDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}-{:02}", self.month, self.day)
+    }
+}
+
+impl FromStr for YearlessDate {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid yearless date string.");
+        let (month, day) = s
+            .strip_prefix("--")
+            .unwrap_or(s)
+            .split_once('-')
+            .ok_or_else(invalid)?;
+        let month = fixed_digits(month, 2).ok_or_else(invalid)?;
+        let day = fixed_digits(day, 2).ok_or_else(invalid)?;
+
+        Self::new(month as u8, day as u8).map_err(|_| invalid())
+    }
+}
+
+/// An ISO 8601 week of a week-year, such as `2011-W47`.
+///
+/// <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#weeks>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Week {
+    year: u32,
+    week: u8,
+}
+
+impl Week {
+    pub fn new(year: u32, week: u8) -> Result<Self, ValueError> {
+        if year == 0 || week == 0 || week > weeks_in_year(year) {
+            return Err(ValueError::new(
+                &format!("{year:04}-W{week:02}"),
+                "Week out of range for the year.",
+            ));
+        }
+
+        Ok(Self { year, week })
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn week(&self) -> u8 {
+        self.week
+    }
+}
+
+impl Display for Week {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-W{:02}", self.year, self.week)
+    }
+}
+
+impl FromStr for Week {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid week string.");
+        let (year, week) = s.split_once("-W").ok_or_else(invalid)?;
+        let year = year_digits(year).ok_or_else(invalid)?;
+        let week = fixed_digits(week, 2).ok_or_else(invalid)?;
+
+        Self::new(year, week as u8).map_err(|_| invalid())
+    }
+}
+
+/// A time of day with millisecond precision, such as `14:54:39.929`.
+///
+/// <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#times>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+    second: u8,
+    millisecond: u16,
+}
+
+impl Time {
+    pub fn new(hour: u8, minute: u8, second: u8, millisecond: u16) -> Result<Self, ValueError> {
+        if hour > 23 || minute > 59 || second > 59 || millisecond > 999 {
+            return Err(ValueError::new(
+                &format!("{hour:02}:{minute:02}:{second:02}.{millisecond:03}"),
+                "Time out of range.",
+            ));
+        }
+
+        Ok(Self {
+            hour,
+            minute,
+            second,
+            millisecond,
+        })
+    }
+
+    /// Creates a time with only hours and minutes, such as `14:54`.
+    pub fn hm(hour: u8, minute: u8) -> Result<Self, ValueError> {
+        Self::new(hour, minute, 0, 0)
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    pub fn millisecond(&self) -> u16 {
+        self.millisecond
+    }
+}
+
+/// Serializes to the shortest valid time string, omitting zero seconds and
+/// trailing zeros of the fraction.
+impl Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)?;
+        if self.second != 0 || self.millisecond != 0 {
+            write!(f, ":{:02}", self.second)?;
+        }
+        if self.millisecond != 0 {
+            let fraction = format!("{:03}", self.millisecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Time {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid time string.");
+        let mut parts = s.split(':');
+        let hour = parts.next().and_then(|hour| fixed_digits(hour, 2));
+        let minute = parts.next().and_then(|minute| fixed_digits(minute, 2));
+        let (hour, minute) = hour.zip(minute).ok_or_else(invalid)?;
+
+        let (second, millisecond) = match parts.next() {
+            None => (0, 0),
+            Some(seconds) => {
+                let (second, fraction) = match seconds.split_once('.') {
+                    Some((second, fraction)) => (second, Some(fraction)),
+                    None => (seconds, None),
+                };
+                let second = fixed_digits(second, 2).ok_or_else(invalid)?;
+                let millisecond = match fraction {
+                    None => 0,
+                    Some(fraction) if (1..=3).contains(&fraction.len()) => {
+                        let padded = format!("{fraction:0<3}");
+                        fixed_digits(&padded, 3).ok_or_else(invalid)?
+                    }
+                    Some(_) => return Err(invalid()),
+                };
+                (second, millisecond)
+            }
+        };
+
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+
+        Self::new(hour as u8, minute as u8, second as u8, millisecond as u16).map_err(|_| invalid())
+    }
+}
+
+/// A date and time without a time-zone offset, such as `2011-11-18T14:54:39`.
+///
+/// <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#local-dates-and-times>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LocalDateTime {
+    date: Date,
+    time: Time,
+}
+
+impl LocalDateTime {
+    pub fn new(date: Date, time: Time) -> Self {
+        Self { date, time }
+    }
+
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    pub fn time(&self) -> Time {
+        self.time
+    }
+}
+
+/// Serializes to the normalized form, which separates the date and time with `T`.
+impl Display for LocalDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
+
+impl FromStr for LocalDateTime {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid local date and time string.");
+        let (date, time) = s.split_once(['T', ' ']).ok_or_else(invalid)?;
+        let date = date.parse().map_err(|_| invalid())?;
+        let time = time.parse().map_err(|_| invalid())?;
+
+        Ok(Self::new(date, time))
+    }
+}
+
+/// An offset from UTC, such as `+05:30`. A zero offset serializes as `Z`.
+///
+/// <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#time-zones>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TimeZoneOffset {
+    minutes: i16,
+}
+
+impl TimeZoneOffset {
+    pub const UTC: Self = Self { minutes: 0 };
+
+    /// Creates an offset from a signed number of minutes east of UTC.
+    pub fn from_minutes(minutes: i16) -> Result<Self, ValueError> {
+        if minutes.unsigned_abs() > 23 * 60 + 59 {
+            return Err(ValueError::new(
+                &minutes.to_string(),
+                "Time-zone offset out of range.",
+            ));
+        }
+
+        Ok(Self { minutes })
+    }
+
+    pub fn minutes(&self) -> i16 {
+        self.minutes
+    }
+}
+
+impl Display for TimeZoneOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.minutes == 0 {
+            return f.write_str("Z");
+        }
+
+        let sign = if self.minutes < 0 { '-' } else { '+' };
+        let minutes = self.minutes.unsigned_abs();
+        write!(f, "{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+    }
+}
+
+impl FromStr for TimeZoneOffset {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid time-zone offset string.");
+        if s == "Z" {
+            return Ok(Self::UTC);
+        }
+
+        let (sign, rest) = match s.split_at_checked(1) {
+            Some(("+", rest)) => (1, rest),
+            Some(("-", rest)) => (-1, rest),
+            _ => return Err(invalid()),
+        };
+        let (hours, minutes) = match rest.split_once(':') {
+            Some(split) => split,
+            None => rest.get(..2).zip(rest.get(2..)).ok_or_else(invalid)?,
+        };
+        let hours = fixed_digits(hours, 2).filter(|hours| *hours <= 23);
+        let minutes = fixed_digits(minutes, 2).filter(|minutes| *minutes <= 59);
+        let (hours, minutes) = hours.zip(minutes).ok_or_else(invalid)?;
+
+        Self::from_minutes(sign * (hours * 60 + minutes) as i16)
+    }
+}
+
+/// A date and time with a time-zone offset, such as `2011-11-18T14:54:39Z`.
+///
+/// <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#global-dates-and-times>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GlobalDateTime {
+    local: LocalDateTime,
+    offset: TimeZoneOffset,
+}
+
+impl GlobalDateTime {
+    pub fn new(local: LocalDateTime, offset: TimeZoneOffset) -> Self {
+        Self { local, offset }
+    }
+
+    pub fn local(&self) -> LocalDateTime {
+        self.local
+    }
+
+    pub fn offset(&self) -> TimeZoneOffset {
+        self.offset
+    }
+}
+
+impl Display for GlobalDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.local, self.offset)
+    }
+}
+
+impl FromStr for GlobalDateTime {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid global date and time string.");
+        let (date, time) = s.split_once(['T', ' ']).ok_or_else(invalid)?;
+        let offset_start = time.find(['Z', '+', '-']).ok_or_else(invalid)?;
+        let local_len = date.len() + 1 + offset_start;
+        let local = s[..local_len].parse().map_err(|_| invalid())?;
+        let offset = s[local_len..].parse().map_err(|_| invalid())?;
+
+        Ok(Self::new(local, offset))
+    }
+}
+
+/// A duration with millisecond precision, serialized in its ISO 8601 form, such as
+/// `PT4H18M3S`. Durations of months or years are not allowed in HTML since their
+/// length varies.
+///
+/// <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#durations>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Duration {
+    milliseconds: u64,
+}
+
+impl Duration {
+    pub fn from_millis(milliseconds: u64) -> Self {
+        Self { milliseconds }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the duration doesn't fit in a `u64` of milliseconds.
+    pub fn from_secs(seconds: u64) -> Self {
+        let milliseconds = seconds
+            .checked_mul(1000)
+            .expect("overflow when converting seconds to milliseconds");
+        Self::from_millis(milliseconds)
+    }
+
+    pub fn as_millis(&self) -> u64 {
+        self.milliseconds
+    }
+}
+
+impl From<std::time::Duration> for Duration {
+    /// Sub-millisecond precision is truncated.
+    fn from(duration: std::time::Duration) -> Self {
+        Self::from_millis(duration.as_millis() as u64)
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let milliseconds = self.milliseconds % 1000;
+        let seconds = self.milliseconds / 1000 % 60;
+        let minutes = self.milliseconds / 60_000 % 60;
+        let hours = self.milliseconds / 3_600_000 % 24;
+        let days = self.milliseconds / 86_400_000;
+
+        f.write_str("P")?;
+        if days != 0 {
+            write!(f, "{days}D")?;
+        }
+        if hours == 0 && minutes == 0 && seconds == 0 && milliseconds == 0 && days != 0 {
+            return Ok(());
+        }
+
+        f.write_str("T")?;
+        if hours != 0 {
+            write!(f, "{hours}H")?;
+        }
+        if minutes != 0 {
+            write!(f, "{minutes}M")?;
+        }
+        if milliseconds != 0 {
+            let fraction = format!("{milliseconds:03}");
+            write!(f, "{}.{}S", seconds, fraction.trim_end_matches('0'))?;
+        } else if seconds != 0 || (hours == 0 && minutes == 0) {
+            write!(f, "{seconds}S")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Duration {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid duration string.");
+        let rest = s.strip_prefix('P').ok_or_else(invalid)?;
+        let (days, time) = match rest.split_once('T') {
+            Some((days, time)) if !time.is_empty() => (days, Some(time)),
+            Some(_) => return Err(invalid()),
+            None => (rest, None),
+        };
+
+        // Adds `value` units of `factor` milliseconds, failing on overflow.
+        let mut milliseconds: u64 = 0;
+        let mut add = |value: u64, factor: u64| {
+            milliseconds = value
+                .checked_mul(factor)
+                .and_then(|value| milliseconds.checked_add(value))
+                .ok_or_else(invalid)?;
+            Ok::<_, ValueError>(())
+        };
+
+        if !days.is_empty() {
+            let days = days
+                .strip_suffix('D')
+                .and_then(digits)
+                .ok_or_else(invalid)?;
+            add(days, 86_400_000)?;
+        } else if time.is_none() {
+            return Err(invalid());
+        }
+
+        let mut time = time.unwrap_or_default();
+        for (unit, factor) in [('H', 3_600_000), ('M', 60_000)] {
+            if let Some((value, rest)) = time.split_once(unit) {
+                add(digits(value).ok_or_else(invalid)?, factor)?;
+                time = rest;
+            }
+        }
+        if let Some(seconds) = time.strip_suffix('S') {
+            let (whole, fraction) = seconds.split_once('.').unwrap_or((seconds, ""));
+            add(digits(whole).ok_or_else(invalid)?, 1000)?;
+            if !fraction.is_empty() {
+                if fraction.len() > 3 {
+                    return Err(invalid());
+                }
+                let fraction = fixed_digits(&format!("{fraction:0<3}"), 3).ok_or_else(invalid)?;
+                add(fraction as u64, 1)?;
+            }
+        } else if !time.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self::from_millis(milliseconds))
+    }
+}
+
+/// Any value accepted by the `datetime` attribute of the `time` element.
+///
+/// <https://html.spec.whatwg.org/multipage/text-level-semantics.html#attr-time-datetime>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateTimeValue {
+    Date(Date),
+    Month(Month),
+    YearlessDate(YearlessDate),
+    Week(Week),
+    Time(Time),
+    LocalDateTime(LocalDateTime),
+    GlobalDateTime(GlobalDateTime),
+    TimeZoneOffset(TimeZoneOffset),
+    Duration(Duration),
+}
+
+/// A value accepted by the `datetime` attribute of the `ins` and `del` elements: a
+/// date, optionally with a time and time-zone offset.
+///
+/// <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#valid-date-string-with-optional-time>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateWithOptionalTime {
+    Date(Date),
+    GlobalDateTime(GlobalDateTime),
+}
+
+/// A value accepted by the `min`, `max` and `value` attributes of date-typed `input`
+/// elements.
+///
+/// <https://html.spec.whatwg.org/multipage/input.html#date-state-(type=date)>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputDateValue {
+    Date(Date),
+    Month(Month),
+    Week(Week),
+    Time(Time),
+    LocalDateTime(LocalDateTime),
+}
+
+/// A value accepted by the `min` and `max` attributes of `input` elements: a number for
+/// number and range inputs, or a date or time for date-typed inputs.
+///
+/// <https://html.spec.whatwg.org/multipage/input.html#the-min-and-max-attributes>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputBound {
+    Number(Number),
+    Date(InputDateValue),
+}
+
+impl Display for InputBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputBound::Number(value) => value.fmt(f),
+            InputBound::Date(value) => value.fmt(f),
+        }
+    }
+}
+
+impl FromStr for InputBound {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(value) = s.parse() {
+            return Ok(InputBound::Date(value));
+        }
+        s.parse()
+            .map(InputBound::Number)
+            .map_err(|_| ValueError::new(s, "Invalid input bound."))
+    }
+}
+
+impl From<Number> for InputBound {
+    fn from(value: Number) -> Self {
+        InputBound::Number(value)
+    }
+}
+
+impl From<InputDateValue> for InputBound {
+    fn from(value: InputDateValue) -> Self {
+        InputBound::Date(value)
+    }
+}
+
+macro_rules! input_bound_from_date {
+    ($($variant:ident),+) => {
+        $(
+            impl From<$variant> for InputBound {
+                fn from(value: $variant) -> Self {
+                    InputBound::Date(value.into())
+                }
+            }
+        )+
+    };
+}
+
+input_bound_from_date!(Date, Month, Week, Time, LocalDateTime);
+
+/// Implements `Display`, `FromStr` and `From` for enums of date and time values. Parsing
+/// tries each variant in order, so variants must be listed from most to least specific.
+macro_rules! date_time_enum {
+    ($name:ident, $message:literal, $($variant:ident),+) => {
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $($name::$variant(value) => value.fmt(f),)+
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ValueError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    if let Ok(value) = s.parse::<$variant>() {
+                        return Ok($name::$variant(value));
+                    }
+                )+
+                Err(ValueError::new(s, $message))
+            }
+        }
+
+        $(
+            impl From<$variant> for $name {
+                fn from(value: $variant) -> Self {
+                    $name::$variant(value)
+                }
+            }
+        )+
+    };
+}
+
+date_time_enum!(
+    DateTimeValue,
+    "Invalid datetime value.",
+    GlobalDateTime,
+    LocalDateTime,
+    Date,
+    Month,
+    Week,
+    YearlessDate,
+    Time,
+    TimeZoneOffset,
+    Duration
+);
+
+date_time_enum!(
+    DateWithOptionalTime,
+    "Invalid date string with optional time.",
+    GlobalDateTime,
+    Date
+);
+
+date_time_enum!(
+    InputDateValue,
+    "Invalid date input value.",
+    LocalDateTime,
+    Date,
+    Month,
+    Week,
+    Time
+);
+
+fn is_leap_year(year: u32) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+fn days_in_month(year: u32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Day of the week of January 1st of `year`, where 0 is Sunday. The sum is computed in
+/// `u64` since it overflows a `u32` for the largest years.
+fn january_first_weekday(year: u32) -> u32 {
+    let y = u64::from(year) - 1;
+    ((1 + y + y / 4 - y / 100 + y / 400) % 7) as u32
+}
+
+/// A year has 53 weeks if it starts on a Thursday, or on a Wednesday in a leap year.
+fn weeks_in_year(year: u32) -> u8 {
+    match january_first_weekday(year) {
+        4 => 53,
+        3 if is_leap_year(year) => 53,
+        _ => 52,
+    }
+}
+
+/// Parses a non-empty string of ASCII digits.
+fn digits(s: &str) -> Option<u64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse().ok()
+}
+
+/// Parses exactly `len` ASCII digits.
+fn fixed_digits(s: &str, len: usize) -> Option<u32> {
+    if s.len() != len {
+        return None;
+    }
+
+    digits(s).map(|value| value as u32)
+}
+
+/// Parses a year, which must have at least four digits.
+fn year_digits(s: &str) -> Option<u32> {
+    if s.len() < 4 {
+        return None;
+    }
+
+    digits(s).and_then(|year| u32::try_from(year).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses `input` and checks that it serializes back to `output`.
+    fn round_trip<T: FromStr + Display>(input: &str, output: &str) {
+        match input.parse::<T>() {
+            Ok(value) => assert_eq!(value.to_string(), output, "{input:?}"),
+            Err(_) => panic!("{input:?} should parse"),
+        }
+    }
+
+    fn rejects<T: FromStr>(input: &str) {
+        assert!(input.parse::<T>().is_err(), "{input:?} should be rejected");
+    }
+
+    #[test]
+    fn dates() {
+        round_trip::<Date>("2011-11-18", "2011-11-18");
+        round_trip::<Date>("2012-02-29", "2012-02-29");
+        round_trip::<Date>("12345-01-01", "12345-01-01");
+        for input in [
+            "2011-02-29",
+            "2011-11-31",
+            "0000-01-01",
+            "11-11-18",
+            "2011-1-18",
+        ] {
+            rejects::<Date>(input);
+        }
+    }
+
+    #[test]
+    fn months_and_yearless_dates() {
+        round_trip::<Month>("2011-11", "2011-11");
+        round_trip::<YearlessDate>("11-18", "11-18");
+        round_trip::<YearlessDate>("--02-29", "02-29");
+        for input in ["2011-13", "2011-00", "2011-1é"] {
+            rejects::<Month>(input);
+        }
+        for input in ["02-30", "1-18", "11-18-"] {
+            rejects::<YearlessDate>(input);
+        }
+    }
+
+    #[test]
+    fn weeks() {
+        round_trip::<Week>("2011-W47", "2011-W47");
+        round_trip::<Week>("2015-W53", "2015-W53");
+        for input in ["2011-W53", "2011-W00", "2011-W7"] {
+            rejects::<Week>(input);
+        }
+        round_trip::<Week>("4294967295-W01", "4294967295-W01");
+        assert!(Week::new(4_000_000_000, 1).is_ok());
+        assert!(Week::new(u32::MAX, 54).is_err());
+        rejects::<Week>("4294967296-W01");
+    }
+
+    #[test]
+    fn times() {
+        round_trip::<Time>("14:54", "14:54");
+        round_trip::<Time>("14:54:39", "14:54:39");
+        round_trip::<Time>("14:54:39.929", "14:54:39.929");
+        round_trip::<Time>("14:54:00.500", "14:54:00.5");
+        for input in [
+            "24:00",
+            "14:60",
+            "14:54:39.9291",
+            "14:54:39:00",
+            "14:54:39.",
+        ] {
+            rejects::<Time>(input);
+        }
+    }
+
+    #[test]
+    fn dates_and_times() {
+        round_trip::<LocalDateTime>("2011-11-18T14:54", "2011-11-18T14:54");
+        round_trip::<LocalDateTime>("2011-11-18 14:54:39", "2011-11-18T14:54:39");
+        round_trip::<GlobalDateTime>("2011-11-18T14:54:39.929Z", "2011-11-18T14:54:39.929Z");
+        round_trip::<GlobalDateTime>("2011-11-18 14:54-04:00", "2011-11-18T14:54-04:00");
+        rejects::<LocalDateTime>("2011-11-18");
+        rejects::<GlobalDateTime>("2011-11-18T14:54");
+        rejects::<GlobalDateTime>("2011-11-18T14:54+25:00");
+    }
+
+    #[test]
+    fn time_zone_offsets() {
+        round_trip::<TimeZoneOffset>("Z", "Z");
+        round_trip::<TimeZoneOffset>("+00:00", "Z");
+        round_trip::<TimeZoneOffset>("+05:30", "+05:30");
+        round_trip::<TimeZoneOffset>("-0800", "-08:00");
+        for input in [
+            "+1é",
+            "+é1",
+            "+1",
+            "+24:00",
+            "+05:60",
+            "05:30",
+            "+5:30",
+            "+05:30:00",
+        ] {
+            rejects::<TimeZoneOffset>(input);
+        }
+        assert_eq!(
+            TimeZoneOffset::from_minutes(-1439).unwrap().to_string(),
+            "-23:59"
+        );
+        for minutes in [i16::MIN, i16::MAX, 1440, -1440] {
+            assert!(TimeZoneOffset::from_minutes(minutes).is_err(), "{minutes}");
+        }
+    }
+
+    #[test]
+    fn durations() {
+        round_trip::<Duration>("PT4H18M3S", "PT4H18M3S");
+        round_trip::<Duration>("P1D", "P1D");
+        round_trip::<Duration>("P2DT1M", "P2DT1M");
+        round_trip::<Duration>("PT0.5S", "PT0.5S");
+        round_trip::<Duration>("PT90M", "PT1H30M");
+        round_trip::<Duration>("PT0S", "PT0S");
+        for input in ["P", "PT", "P1DT", "PT1.2345S", "PT1X", "4H", "P1W"] {
+            rejects::<Duration>(input);
+        }
+    }
+
+    #[test]
+    fn durations_reject_overflow() {
+        for input in [
+            "P999999999999999D",
+            "PT99999999999999999H",
+            "PT999999999999999999M",
+            "PT99999999999999999S",
+            "P213503982334DT23H",
+            "PT18446744073709551616S",
+        ] {
+            rejects::<Duration>(input);
+        }
+        assert!(std::panic::catch_unwind(|| Duration::from_secs(u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn enums_try_the_most_specific_variant_first() {
+        let value: DateTimeValue = "2011-11-18T14:54Z".parse().unwrap();
+        assert!(matches!(value, DateTimeValue::GlobalDateTime(_)));
+        let value: DateTimeValue = "PT4H".parse().unwrap();
+        assert!(matches!(value, DateTimeValue::Duration(_)));
+        let value: InputDateValue = "2011-W47".parse().unwrap();
+        assert!(matches!(value, InputDateValue::Week(_)));
+        rejects::<DateWithOptionalTime>("14:54");
+    }
+
+    #[test]
+    fn input_bounds() {
+        round_trip::<InputBound>("10", "10");
+        round_trip::<InputBound>("-0.5", "-0.5");
+        round_trip::<InputBound>("2011-11-18", "2011-11-18");
+        round_trip::<InputBound>("14:54", "14:54");
+        assert!(matches!("2011".parse(), Ok(InputBound::Number(_))));
+        rejects::<InputBound>("ten");
+    }
+}
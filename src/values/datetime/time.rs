@@ -0,0 +1,117 @@
+//! Conversions between the date and time values and the types of the `time` crate.
+
+use super::{Date, Duration, GlobalDateTime, LocalDateTime, Time, TimeZoneOffset};
+use crate::values::ValueError;
+
+impl TryFrom<::time::Date> for Date {
+    type Error = ValueError;
+
+    fn try_from(date: ::time::Date) -> Result<Self, Self::Error> {
+        let year = u32::try_from(date.year())
+            .map_err(|_| ValueError::new(&date.to_string(), "Years before 1 are not allowed."))?;
+        Date::new(year, date.month().into(), date.day())
+    }
+}
+
+impl TryFrom<Date> for ::time::Date {
+    type Error = ValueError;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        let out_of_range = || ValueError::new(&date.to_string(), "Date out of range for time.");
+        let year = i32::try_from(date.year).map_err(|_| out_of_range())?;
+        let month = ::time::Month::try_from(date.month).map_err(|_| out_of_range())?;
+        ::time::Date::from_calendar_date(year, month, date.day).map_err(|_| out_of_range())
+    }
+}
+
+impl From<::time::Time> for Time {
+    /// Sub-millisecond precision is truncated.
+    fn from(time: ::time::Time) -> Self {
+        Self {
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+            millisecond: time.millisecond(),
+        }
+    }
+}
+
+impl From<Time> for ::time::Time {
+    fn from(time: Time) -> Self {
+        ::time::Time::from_hms_milli(time.hour, time.minute, time.second, time.millisecond)
+            .expect("a validated time to be valid")
+    }
+}
+
+impl TryFrom<::time::PrimitiveDateTime> for LocalDateTime {
+    type Error = ValueError;
+
+    fn try_from(datetime: ::time::PrimitiveDateTime) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            datetime.date().try_into()?,
+            datetime.time().into(),
+        ))
+    }
+}
+
+impl TryFrom<LocalDateTime> for ::time::PrimitiveDateTime {
+    type Error = ValueError;
+
+    fn try_from(datetime: LocalDateTime) -> Result<Self, Self::Error> {
+        Ok(::time::PrimitiveDateTime::new(
+            datetime.date.try_into()?,
+            datetime.time.into(),
+        ))
+    }
+}
+
+impl TryFrom<::time::UtcOffset> for TimeZoneOffset {
+    type Error = ValueError;
+
+    fn try_from(offset: ::time::UtcOffset) -> Result<Self, Self::Error> {
+        let seconds = offset.whole_seconds();
+        if seconds % 60 != 0 {
+            return Err(ValueError::new(
+                &offset.to_string(),
+                "Time-zone offsets must be a whole number of minutes.",
+            ));
+        }
+
+        Self::from_minutes((seconds / 60) as i16)
+    }
+}
+
+impl From<TimeZoneOffset> for ::time::UtcOffset {
+    fn from(offset: TimeZoneOffset) -> Self {
+        ::time::UtcOffset::from_whole_seconds(i32::from(offset.minutes) * 60)
+            .expect("a validated offset to be in range")
+    }
+}
+
+impl TryFrom<::time::OffsetDateTime> for GlobalDateTime {
+    type Error = ValueError;
+
+    fn try_from(datetime: ::time::OffsetDateTime) -> Result<Self, Self::Error> {
+        let local = ::time::PrimitiveDateTime::new(datetime.date(), datetime.time());
+        Ok(Self::new(local.try_into()?, datetime.offset().try_into()?))
+    }
+}
+
+impl TryFrom<GlobalDateTime> for ::time::OffsetDateTime {
+    type Error = ValueError;
+
+    fn try_from(datetime: GlobalDateTime) -> Result<Self, Self::Error> {
+        let local = ::time::PrimitiveDateTime::try_from(datetime.local)?;
+        Ok(local.assume_offset(datetime.offset.into()))
+    }
+}
+
+impl TryFrom<::time::Duration> for Duration {
+    type Error = ValueError;
+
+    fn try_from(duration: ::time::Duration) -> Result<Self, Self::Error> {
+        u64::try_from(duration.whole_milliseconds())
+            .map(Self::from_millis)
+            .map_err(|_| ValueError::new(&duration.to_string(), "Durations cannot be negative."))
+    }
+}
@@ -0,0 +1,33 @@
+/// The value of an enumerated attribute such as `contenteditable`, `draggable` or
+/// `aria-invalid`, whose value is one of a fixed set of keywords.
+///
+/// Each keyword maps to a state. Some states have no keyword at all (the `inherit`
+/// state of `contenteditable`, the `auto` state of `draggable`); those are reached by
+/// omitting the attribute or by giving it an invalid value, and are represented by
+/// `None`.
+///
+/// <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#keywords-and-enumerated-attributes>
+pub trait EnumeratedValue: AsRef<str> + Copy + Sized + 'static {
+    /// Every keyword of the attribute and the state it maps to. More than one keyword
+    /// may map to the same state, such as `""` and `"true"` for `contenteditable`.
+    const KEYWORDS: &'static [(&'static str, Self)];
+
+    /// The state of the attribute when it is absent.
+    const MISSING_VALUE_DEFAULT: Option<Self>;
+
+    /// The state of the attribute when its value is not one of the keywords.
+    const INVALID_VALUE_DEFAULT: Option<Self>;
+
+    /// Returns the state of the attribute given its value, or `None` if the attribute
+    /// is absent. Keywords are matched ASCII case-insensitively.
+    fn from_attribute_value(value: Option<&str>) -> Option<Self> {
+        match value {
+            Some(value) => Self::KEYWORDS
+                .iter()
+                .find(|(keyword, _)| keyword.eq_ignore_ascii_case(value))
+                .map(|(_, state)| *state)
+                .or(Self::INVALID_VALUE_DEFAULT),
+            None => Self::MISSING_VALUE_DEFAULT,
+        }
+    }
+}
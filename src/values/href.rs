@@ -0,0 +1,386 @@
+use super::ValueError;
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use url::{ParseError, Url};
+
+/// Characters escaped in the fragment of a fragment-only reference.
+///
+/// <https://url.spec.whatwg.org/#fragment-percent-encode-set>
+const FRAGMENT: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>').add(b'`');
+
+/// Characters escaped in the addresses and header values of a `mailto:` URL.
+///
+/// <https://www.rfc-editor.org/rfc/rfc6068#section-2>
+const MAILTO: &AsciiSet = &FRAGMENT
+    .add(b'#')
+    .add(b'%')
+    .add(b'&')
+    .add(b',')
+    .add(b'=')
+    .add(b'?')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+/// Base used to check that a relative reference resolves to a valid URL. It is never
+/// part of the serialized value.
+const VALIDATION_BASE: &str = "https://domatt.invalid/";
+
+/// The kind of reference held by an [Href].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HrefKind {
+    /// An absolute URL, such as `https://example.com/about`.
+    Absolute,
+    /// A URL resolved against the document base, such as `/about` or `../x`.
+    Relative,
+    /// A reference to a fragment of the current document, such as `#top`.
+    Fragment,
+    /// A `mailto:` URL.
+    Mailto,
+    /// A `tel:` URL.
+    Tel,
+    /// An `sms:` URL.
+    Sms,
+    /// A `javascript:` URL. Only produced by [Href::unsafe_javascript].
+    Javascript,
+}
+
+/// A value for URL-valued attributes such as `href`, `src`, `cite` and `formaction`.
+///
+/// Unlike [url::Url], an `Href` accepts relative references and fragment-only links,
+/// which is what most links in a document are. `javascript:` URLs are rejected unless
+/// they are explicitly created with [Href::unsafe_javascript].
+///
+/// <https://html.spec.whatwg.org/multipage/urls-and-fetching.html#valid-url-potentially-surrounded-by-spaces>
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Href {
+    value: String,
+    kind: HrefKind,
+}
+
+impl Href {
+    /// Parses an absolute URL or a relative reference. Surrounding whitespace is
+    /// removed, and `javascript:` URLs are rejected.
+    pub fn parse(input: &str) -> Result<Self, ValueError> {
+        let value = input.trim_matches(|c: char| c.is_ascii_whitespace() || c.is_ascii_control());
+
+        match Url::parse(value) {
+            Ok(url) => Self::try_from(url),
+            Err(ParseError::RelativeUrlWithoutBase) => {
+                let base = Url::parse(VALIDATION_BASE).expect("the validation base to be valid");
+                base.join(value).map_err(|err| {
+                    ValueError::new(input, &format!("Invalid relative URL: {err}."))
+                })?;
+
+                let kind = if value.starts_with('#') {
+                    HrefKind::Fragment
+                } else {
+                    HrefKind::Relative
+                };
+
+                Ok(Self {
+                    value: value.to_owned(),
+                    kind,
+                })
+            }
+            Err(err) => Err(ValueError::new(input, &format!("Invalid URL: {err}."))),
+        }
+    }
+
+    /// Creates a reference to the element with the given id in the current document.
+    pub fn fragment(id: &str) -> Self {
+        Self {
+            value: format!("#{}", utf8_percent_encode(id, FRAGMENT)),
+            kind: HrefKind::Fragment,
+        }
+    }
+
+    /// Creates a `mailto:` URL from a [MailTo] builder.
+    pub fn mailto(mailto: MailTo) -> Self {
+        mailto.into()
+    }
+
+    /// Creates a `tel:` URL. The number may contain digits, visual separators
+    /// (`-`, `.`, `(`, `)`, spaces) and a leading `+`. Spaces are removed.
+    ///
+    /// <https://www.rfc-editor.org/rfc/rfc3966>
+    pub fn tel(number: &str) -> Result<Self, ValueError> {
+        Ok(Self {
+            value: format!("tel:{}", phone_number(number)?),
+            kind: HrefKind::Tel,
+        })
+    }
+
+    /// Creates an `sms:` URL with an optional prefilled message body.
+    ///
+    /// <https://www.rfc-editor.org/rfc/rfc5724>
+    pub fn sms(number: &str, body: Option<&str>) -> Result<Self, ValueError> {
+        let mut value = format!("sms:{}", phone_number(number)?);
+        if let Some(body) = body {
+            value.push_str("?body=");
+            value.extend(utf8_percent_encode(body, MAILTO));
+        }
+
+        Ok(Self {
+            value,
+            kind: HrefKind::Sms,
+        })
+    }
+
+    /// Creates a `javascript:` URL. Script URLs run in the context of the page and
+    /// are a common source of XSS, so only use this with code you control.
+    pub fn unsafe_javascript(code: &str) -> Self {
+        Self {
+            value: format!("javascript:{code}"),
+            kind: HrefKind::Javascript,
+        }
+    }
+
+    /// Returns the kind of reference this value holds.
+    pub fn kind(&self) -> HrefKind {
+        self.kind
+    }
+
+    /// Returns the serialized URL.
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+}
+
+impl TryFrom<Url> for Href {
+    type Error = ValueError;
+
+    fn try_from(url: Url) -> Result<Self, Self::Error> {
+        let kind = match url.scheme() {
+            "javascript" => {
+                return Err(ValueError::new(
+                    url.as_str(),
+                    "javascript: URLs are not allowed. Use Href::unsafe_javascript instead.",
+                ))
+            }
+            "mailto" => HrefKind::Mailto,
+            "tel" => HrefKind::Tel,
+            "sms" => HrefKind::Sms,
+            _ => HrefKind::Absolute,
+        };
+
+        Ok(Self {
+            value: url.into(),
+            kind,
+        })
+    }
+}
+
+impl TryFrom<&str> for Href {
+    type Error = ValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl FromStr for Href {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl AsRef<str> for Href {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for Href {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+/// Builder for `mailto:` URLs.
+///
+/// <https://www.rfc-editor.org/rfc/rfc6068>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MailTo {
+    to: Vec<String>,
+    cc: Vec<String>,
+    bcc: Vec<String>,
+    subject: Option<String>,
+    body: Option<String>,
+}
+
+impl MailTo {
+    pub fn new(address: &str) -> Self {
+        Self {
+            to: vec![address.to_owned()],
+            ..Default::default()
+        }
+    }
+
+    /// Adds another recipient.
+    pub fn to(mut self, address: &str) -> Self {
+        self.to.push(address.to_owned());
+        self
+    }
+
+    pub fn cc(mut self, address: &str) -> Self {
+        self.cc.push(address.to_owned());
+        self
+    }
+
+    pub fn bcc(mut self, address: &str) -> Self {
+        self.bcc.push(address.to_owned());
+        self
+    }
+
+    pub fn subject(mut self, subject: &str) -> Self {
+        self.subject = Some(subject.to_owned());
+        self
+    }
+
+    pub fn body(mut self, body: &str) -> Self {
+        self.body = Some(body.to_owned());
+        self
+    }
+}
+
+impl From<MailTo> for Href {
+    fn from(mailto: MailTo) -> Self {
+        let encode_addresses = |addresses: &[String]| {
+            addresses
+                .iter()
+                .map(|address| utf8_percent_encode(address, MAILTO).to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        };
+
+        let mut headers = Vec::new();
+        if !mailto.cc.is_empty() {
+            headers.push(format!("cc={}", encode_addresses(&mailto.cc)));
+        }
+        if !mailto.bcc.is_empty() {
+            headers.push(format!("bcc={}", encode_addresses(&mailto.bcc)));
+        }
+        if let Some(subject) = &mailto.subject {
+            headers.push(format!("subject={}", utf8_percent_encode(subject, MAILTO)));
+        }
+        if let Some(body) = &mailto.body {
+            headers.push(format!("body={}", utf8_percent_encode(body, MAILTO)));
+        }
+
+        let mut value = format!("mailto:{}", encode_addresses(&mailto.to));
+        if !headers.is_empty() {
+            value.push('?');
+            value.push_str(&headers.join("&"));
+        }
+
+        Self {
+            value,
+            kind: HrefKind::Mailto,
+        }
+    }
+}
+
+/// Validates a phone number for `tel:` and `sms:` URLs and strips its whitespace.
+fn phone_number(number: &str) -> Result<String, ValueError> {
+    let stripped: String = number
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace())
+        .collect();
+
+    let digits = stripped.strip_prefix('+').unwrap_or(&stripped);
+    let is_valid = digits.chars().any(|c| c.is_ascii_digit())
+        && digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '-' | '.' | '(' | ')'));
+
+    if is_valid {
+        Ok(stripped)
+    } else {
+        Err(ValueError::new(number, "Invalid phone number."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_references() {
+        for (input, kind) in [
+            ("/about", HrefKind::Relative),
+            ("../x?y=1", HrefKind::Relative),
+            ("//example.com/x", HrefKind::Relative),
+            ("#top", HrefKind::Fragment),
+            (" https://example.com/about\n", HrefKind::Absolute),
+        ] {
+            let href = Href::parse(input).unwrap();
+            assert_eq!(href.kind(), kind, "{input:?}");
+            assert_eq!(href.as_str(), input.trim(), "{input:?}");
+        }
+        assert_eq!(Href::fragment("a b").as_str(), "#a%20b");
+        assert!(Href::parse("http://exa mple.com").is_err());
+    }
+
+    #[test]
+    fn mailto() {
+        let href = Href::mailto(
+            MailTo::new("a@example.com")
+                .to("b@example.com")
+                .cc("c@example.com")
+                .subject("Hi & bye?")
+                .body("50% off"),
+        );
+        assert_eq!(href.kind(), HrefKind::Mailto);
+        assert_eq!(
+            href.as_str(),
+            "mailto:a@example.com,b@example.com?cc=c@example.com&subject=Hi%20%26%20bye%3F&body=50%25%20off"
+        );
+        assert_eq!(
+            Href::mailto(MailTo::new("a@example.com")).as_str(),
+            "mailto:a@example.com"
+        );
+    }
+
+    #[test]
+    fn tel_and_sms() {
+        let tel = Href::tel("+1 (555) 010-0000").unwrap();
+        assert_eq!(tel.kind(), HrefKind::Tel);
+        assert_eq!(tel.as_str(), "tel:+1(555)010-0000");
+        let sms = Href::sms("555 0100", Some("See you at 5?")).unwrap();
+        assert_eq!(sms.kind(), HrefKind::Sms);
+        assert_eq!(sms.as_str(), "sms:5550100?body=See%20you%20at%205%3F");
+        for number in ["", "+", "call me", "555#1"] {
+            assert!(Href::tel(number).is_err(), "{number:?}");
+        }
+    }
+
+    #[test]
+    fn javascript_urls_are_rejected() {
+        for input in [
+            "javascript:alert(1)",
+            "JavaScript:alert(1)",
+            "  JAVASCRIPT:alert(1)",
+            "\tjavascript:alert(1)",
+            "java\nscript:alert(1)",
+        ] {
+            assert!(Href::parse(input).is_err(), "{input:?}");
+        }
+        let url = Url::parse("javascript:alert(1)").unwrap();
+        assert!(Href::try_from(url).is_err());
+    }
+
+    #[test]
+    fn unsafe_javascript() {
+        let href = Href::unsafe_javascript("void(0)");
+        assert_eq!(href.kind(), HrefKind::Javascript);
+        assert_eq!(href.to_string(), "javascript:void(0)");
+    }
+}
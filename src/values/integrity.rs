@@ -0,0 +1,375 @@
+//! Types for the `integrity` attribute of `script` and `link` elements.
+//!
+//! An [Integrity] value lists hashes of the resource, such as
+//! `sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC`. The
+//! browser refuses to use the resource if it doesn't match one of the hashes for the
+//! strongest algorithm in the list. With the `sri` feature, the hashes can be computed
+//! from the bytes of the resource, for example when building or serving assets.
+//!
+//! <https://www.w3.org/TR/SRI/#the-integrity-attribute>
+
+use super::ValueError;
+use attribute_derive::AttributeValue;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[cfg(feature = "sri")]
+use base64::{engine::general_purpose::STANDARD, Engine};
+#[cfg(feature = "sri")]
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+/// A hash function allowed in an integrity value. The variants are ordered from the
+/// weakest to the strongest.
+#[derive(Debug, Copy, PartialOrd, Ord, AttributeValue)]
+#[attribute_value(case = "lowercase")]
+pub enum HashAlgorithm {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    /// Returns the length of the digest in bytes.
+    pub fn digest_len(&self) -> usize {
+        match self {
+            HashAlgorithm::Sha256 => 32,
+            HashAlgorithm::Sha384 => 48,
+            HashAlgorithm::Sha512 => 64,
+        }
+    }
+
+    /// Returns the base64 encoded digest of `bytes`.
+    #[cfg(feature = "sri")]
+    pub fn digest(&self, bytes: &[u8]) -> String {
+        match self {
+            HashAlgorithm::Sha256 => STANDARD.encode(Sha256::digest(bytes)),
+            HashAlgorithm::Sha384 => STANDARD.encode(Sha384::digest(bytes)),
+            HashAlgorithm::Sha512 => STANDARD.encode(Sha512::digest(bytes)),
+        }
+    }
+}
+
+/// A single hash in an integrity value, such as `sha256-<digest>`, with optional
+/// `?`-separated options. No options are defined yet, but they are kept so that values
+/// written for newer browsers round-trip.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntegrityMetadata {
+    algorithm: HashAlgorithm,
+    digest: String,
+    options: Vec<String>,
+}
+
+impl IntegrityMetadata {
+    /// Fails if `digest` isn't base64 or base64url, or doesn't have the length of a
+    /// digest of `algorithm`.
+    pub fn new(algorithm: HashAlgorithm, digest: &str) -> Result<Self, ValueError> {
+        validate_digest(algorithm, digest)?;
+        Ok(Self {
+            algorithm,
+            digest: digest.to_owned(),
+            options: Vec::new(),
+        })
+    }
+
+    /// Hashes `bytes` with `algorithm`.
+    #[cfg(feature = "sri")]
+    pub fn compute(algorithm: HashAlgorithm, bytes: &[u8]) -> Self {
+        Self {
+            algorithm,
+            digest: algorithm.digest(bytes),
+            options: Vec::new(),
+        }
+    }
+
+    /// Adds an option. Options are made of visible ASCII characters other than `?`.
+    pub fn option(mut self, option: &str) -> Result<Self, ValueError> {
+        if option.is_empty() || !option.bytes().all(|b| b.is_ascii_graphic() && b != b'?') {
+            return Err(ValueError::new(
+                option,
+                "Integrity options must be visible ASCII characters other than '?'.",
+            ));
+        }
+
+        self.options.push(option.to_owned());
+        Ok(self)
+    }
+
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns the digest as it was given, in base64 or base64url.
+    pub fn digest(&self) -> &str {
+        &self.digest
+    }
+
+    pub fn options(&self) -> &[String] {
+        &self.options
+    }
+
+    /// Checks whether `bytes` hash to this digest.
+    #[cfg(feature = "sri")]
+    pub fn matches(&self, bytes: &[u8]) -> bool {
+        let normalize = |digest: &str| {
+            digest
+                .trim_end_matches('=')
+                .replace('-', "+")
+                .replace('_', "/")
+        };
+
+        normalize(&self.algorithm.digest(bytes)) == normalize(&self.digest)
+    }
+}
+
+impl Display for IntegrityMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.algorithm, self.digest)?;
+        for option in &self.options {
+            write!(f, "?{option}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for IntegrityMetadata {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('?');
+        let hash = parts.next().unwrap_or_default();
+        let (algorithm, digest) = hash.split_once('-').ok_or_else(|| {
+            ValueError::new(s, "Integrity hashes must be '<algorithm>-<digest>'.")
+        })?;
+        let algorithm = algorithm
+            .parse()
+            .map_err(|_| ValueError::new(s, "Unsupported hash algorithm."))?;
+
+        parts.try_fold(Self::new(algorithm, digest)?, |metadata, option| {
+            metadata.option(option)
+        })
+    }
+}
+
+/// The value of an `integrity` attribute: one or more hashes of the resource.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Integrity(Vec<IntegrityMetadata>);
+
+impl Integrity {
+    /// Hashes `bytes` with `algorithm`. Use [Integrity::add] to list hashes made with
+    /// other algorithms, for browsers that don't support the strongest one.
+    #[cfg(feature = "sri")]
+    pub fn compute(algorithm: HashAlgorithm, bytes: &[u8]) -> Self {
+        IntegrityMetadata::compute(algorithm, bytes).into()
+    }
+
+    /// Adds a hash, unless it is already in the list.
+    pub fn add(&mut self, metadata: IntegrityMetadata) {
+        if !self.0.contains(&metadata) {
+            self.0.push(metadata);
+        }
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, IntegrityMetadata> {
+        self.0.iter()
+    }
+
+    /// Returns the hashes made with the strongest algorithm in the list. Browsers only
+    /// check these.
+    pub fn strongest(&self) -> impl Iterator<Item = &IntegrityMetadata> {
+        let strongest = self.0.iter().map(IntegrityMetadata::algorithm).max();
+        self.0
+            .iter()
+            .filter(move |metadata| Some(metadata.algorithm()) == strongest)
+    }
+
+    /// Checks `bytes` the way a browser does: they must match one of the hashes made
+    /// with the strongest algorithm.
+    #[cfg(feature = "sri")]
+    pub fn matches(&self, bytes: &[u8]) -> bool {
+        self.strongest().any(|metadata| metadata.matches(bytes))
+    }
+}
+
+impl From<IntegrityMetadata> for Integrity {
+    fn from(metadata: IntegrityMetadata) -> Self {
+        Self(vec![metadata])
+    }
+}
+
+impl Display for Integrity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hashes: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+        f.write_str(&hashes.join(" "))
+    }
+}
+
+impl FromStr for Integrity {
+    type Err = ValueError;
+
+    /// Parses a list of hashes. Like browsers, hashes made with an algorithm that isn't
+    /// recognised, such as `sha1`, are ignored, so the value only fails if none are left.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut integrity = Self(Vec::new());
+        for token in s.split_ascii_whitespace() {
+            if has_known_algorithm(token) {
+                integrity.add(token.parse()?);
+            }
+        }
+
+        if integrity.0.is_empty() {
+            return Err(ValueError::new(
+                s,
+                "Integrity values need at least one hash with a supported algorithm.",
+            ));
+        }
+
+        Ok(integrity)
+    }
+}
+
+impl<'a> IntoIterator for &'a Integrity {
+    type Item = &'a IntegrityMetadata;
+    type IntoIter = std::slice::Iter<'a, IntegrityMetadata>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Whether `token` starts with the name of a [HashAlgorithm] followed by `-`.
+fn has_known_algorithm(token: &str) -> bool {
+    token
+        .split_once('-')
+        .is_some_and(|(algorithm, _)| algorithm.parse::<HashAlgorithm>().is_ok())
+}
+
+/// Checks that `digest` is base64 or base64url, with or without padding, and decodes
+/// to a digest of `algorithm`.
+fn validate_digest(algorithm: HashAlgorithm, digest: &str) -> Result<(), ValueError> {
+    let unpadded = digest.trim_end_matches('=');
+    let padding = digest.len() - unpadded.len();
+    let is_base64 = unpadded
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'-' | b'_'))
+        && padding <= 2
+        && (padding == 0 || digest.len().is_multiple_of(4));
+    if !is_base64 || unpadded.len() % 4 == 1 {
+        return Err(ValueError::new(digest, "Digests must be base64 encoded."));
+    }
+
+    if unpadded.len() * 3 / 4 != algorithm.digest_len() {
+        return Err(ValueError::new(
+            digest,
+            &format!(
+                "{algorithm} digests must be {} bytes long.",
+                algorithm.digest_len()
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Hashes of `alert('Hello, world.');`.
+    const SHA256: &str = "qznLcsROx4GACP2dm0UCKCzCG+HiZ1guq6ZZDob/Tng=";
+    const SHA256_URL_SAFE: &str = "qznLcsROx4GACP2dm0UCKCzCG-HiZ1guq6ZZDob_Tng";
+    const SHA384: &str = "H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO";
+    const SHA512: &str =
+        "Q2bFTOhEALkN8hOms2FKTDLy7eugP2zFZ1T8LCvX42Fp3WoNr3bjZSAHeOsHrbV1Fu9/A0EzCinRE7Af1ofPrw==";
+
+    #[test]
+    fn parses_metadata() {
+        let metadata: IntegrityMetadata = format!("sha384-{SHA384}?opt").parse().unwrap();
+        assert_eq!(metadata.algorithm(), HashAlgorithm::Sha384);
+        assert_eq!(metadata.digest(), SHA384);
+        assert_eq!(metadata.options(), ["opt"]);
+        assert_eq!(metadata.to_string(), format!("sha384-{SHA384}?opt"));
+
+        for input in [
+            format!("sha1-{SHA256}"),
+            format!("sha256{SHA256}"),
+            format!("sha256-{SHA256}?"),
+            format!("sha256-{SHA384}"),
+        ] {
+            assert!(input.parse::<IntegrityMetadata>().is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn ignores_unknown_algorithms() {
+        let value = format!("sha1-abc sha256-{SHA256}  sha3-512-xyz md5 sha512-{SHA512}");
+        let integrity: Integrity = value.parse().unwrap();
+        assert_eq!(
+            integrity.to_string(),
+            format!("sha256-{SHA256} sha512-{SHA512}")
+        );
+
+        for input in ["", "  ", "sha1-abc", "sha3-256-abc md5-xyz"] {
+            assert!(input.parse::<Integrity>().is_err(), "{input:?}");
+        }
+        // A hash made with a known algorithm must still be valid.
+        assert!(format!("sha256-{SHA384} sha512-{SHA512}")
+            .parse::<Integrity>()
+            .is_err());
+    }
+
+    #[test]
+    fn validates_digests() {
+        assert!(validate_digest(HashAlgorithm::Sha256, SHA256).is_ok());
+        assert!(validate_digest(HashAlgorithm::Sha256, SHA256_URL_SAFE).is_ok());
+        assert!(validate_digest(HashAlgorithm::Sha512, SHA512).is_ok());
+        for digest in [
+            "",
+            &SHA256[1..],
+            "qznLcsROx4GACP2dm0UCKCzCG+HiZ1guq6ZZDob/Tng===",
+            "qznLcsROx4GACP2dm0UCKCzCG+HiZ1guq6ZZDob/Tn!=",
+            SHA384,
+        ] {
+            assert!(
+                validate_digest(HashAlgorithm::Sha256, digest).is_err(),
+                "{digest:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn strongest_algorithm() {
+        let weak = IntegrityMetadata::new(HashAlgorithm::Sha256, SHA256).unwrap();
+        let strong = IntegrityMetadata::new(HashAlgorithm::Sha384, SHA384).unwrap();
+        let mut integrity = Integrity::from(weak.clone());
+        integrity.add(strong.clone());
+        integrity.add(weak);
+        assert_eq!(integrity.iter().count(), 2);
+        assert_eq!(integrity.strongest().collect::<Vec<_>>(), [&strong]);
+    }
+
+    #[cfg(feature = "sri")]
+    #[test]
+    fn computes_and_matches() {
+        const SCRIPT: &[u8] = b"alert('Hello, world.');";
+        for (algorithm, digest) in [
+            (HashAlgorithm::Sha256, SHA256),
+            (HashAlgorithm::Sha384, SHA384),
+            (HashAlgorithm::Sha512, SHA512),
+        ] {
+            let metadata = IntegrityMetadata::compute(algorithm, SCRIPT);
+            assert_eq!(metadata.digest(), digest);
+            assert!(metadata.matches(SCRIPT));
+            assert!(!metadata.matches(b"alert(1);"));
+        }
+
+        let metadata = IntegrityMetadata::new(HashAlgorithm::Sha256, SHA256_URL_SAFE).unwrap();
+        assert!(metadata.matches(SCRIPT));
+
+        // Only the strongest hashes are checked, so a matching weaker one isn't enough.
+        let mut integrity = Integrity::compute(HashAlgorithm::Sha256, SCRIPT);
+        assert!(integrity.matches(SCRIPT));
+        integrity.add(IntegrityMetadata::compute(HashAlgorithm::Sha512, b"other"));
+        assert!(!integrity.matches(SCRIPT));
+    }
+}
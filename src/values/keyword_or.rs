@@ -0,0 +1,34 @@
+use super::ValueError;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A value that is either one of the keywords of `K` or a value of `V`, such as the
+/// value of `font-size`, which takes `larger` as well as `1.2em`.
+///
+/// Parsing tries the keywords first, so `V` is only parsed when the value isn't one of
+/// them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeywordOr<K, V> {
+    Keyword(K),
+    Value(V),
+}
+
+impl<K: Display, V: Display> Display for KeywordOr<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeywordOr::Keyword(keyword) => keyword.fmt(f),
+            KeywordOr::Value(value) => value.fmt(f),
+        }
+    }
+}
+
+impl<K: FromStr, V: FromStr<Err = ValueError>> FromStr for KeywordOr<K, V> {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse() {
+            Ok(keyword) => Ok(KeywordOr::Keyword(keyword)),
+            Err(_) => s.parse().map(KeywordOr::Value),
+        }
+    }
+}
@@ -0,0 +1,773 @@
+//! CSS length, percentage and `calc()` values used by SVG geometry and text attributes.
+//!
+//! <https://www.w3.org/TR/css-values-4/#lengths>
+
+use super::number::is_number_token;
+use super::{Number, ValueError};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use strum::{AsRefStr, EnumString};
+
+/// An enum defining the CSS length units.
+///
+/// <https://www.w3.org/TR/css-values-4/#lengths>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, AsRefStr, EnumString)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum LengthUnit {
+    // Absolute lengths
+    Px,
+    Cm,
+    Mm,
+    #[strum(serialize = "Q")]
+    Q,
+    In,
+    Pt,
+    Pc,
+    // Font-relative lengths
+    Em,
+    Rem,
+    Ex,
+    Rex,
+    Cap,
+    Rcap,
+    Ch,
+    Rch,
+    Ic,
+    Ric,
+    Lh,
+    Rlh,
+    // Viewport-percentage lengths
+    Vw,
+    Vh,
+    Vi,
+    Vb,
+    Vmin,
+    Vmax,
+    Svw,
+    Svh,
+    Svi,
+    Svb,
+    Svmin,
+    Svmax,
+    Lvw,
+    Lvh,
+    Lvi,
+    Lvb,
+    Lvmin,
+    Lvmax,
+    Dvw,
+    Dvh,
+    Dvi,
+    Dvb,
+    Dvmin,
+    Dvmax,
+    // Container query lengths
+    Cqw,
+    Cqh,
+    Cqi,
+    Cqb,
+    Cqmin,
+    Cqmax,
+}
+
+/// A distance, such as `2px` or `1.5rem`. A length without a unit is in SVG user units.
+///
+/// <https://www.w3.org/TR/SVG2/types.html#InterfaceSVGLength>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Length {
+    value: Number,
+    unit: Option<LengthUnit>,
+}
+
+impl Length {
+    pub fn new(value: f64, unit: LengthUnit) -> Result<Self, ValueError> {
+        Ok(Self {
+            value: Number::new(value)?,
+            unit: Some(unit),
+        })
+    }
+
+    /// Creates a length in SVG user units, which serializes as a plain number.
+    pub fn user_units(value: f64) -> Result<Self, ValueError> {
+        Ok(Self {
+            value: Number::new(value)?,
+            unit: None,
+        })
+    }
+
+    pub fn px(value: f64) -> Result<Self, ValueError> {
+        Self::new(value, LengthUnit::Px)
+    }
+
+    pub fn em(value: f64) -> Result<Self, ValueError> {
+        Self::new(value, LengthUnit::Em)
+    }
+
+    pub fn rem(value: f64) -> Result<Self, ValueError> {
+        Self::new(value, LengthUnit::Rem)
+    }
+
+    pub fn ch(value: f64) -> Result<Self, ValueError> {
+        Self::new(value, LengthUnit::Ch)
+    }
+
+    pub fn vw(value: f64) -> Result<Self, ValueError> {
+        Self::new(value, LengthUnit::Vw)
+    }
+
+    pub fn vh(value: f64) -> Result<Self, ValueError> {
+        Self::new(value, LengthUnit::Vh)
+    }
+
+    pub fn value(&self) -> Number {
+        self.value
+    }
+
+    pub fn unit(&self) -> Option<LengthUnit> {
+        self.unit
+    }
+}
+
+impl From<Number> for Length {
+    fn from(value: Number) -> Self {
+        Self { value, unit: None }
+    }
+}
+
+impl Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)?;
+        if let Some(unit) = self.unit {
+            f.write_str(unit.as_ref())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Length {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid length.");
+        let unit_start = s
+            .rfind(|c: char| c.is_ascii_digit() || c == '.')
+            .map(|index| index + 1)
+            .ok_or_else(invalid)?;
+        let (value, unit) = s.split_at(unit_start);
+        let value = value.parse::<Number>().map_err(|_| invalid())?;
+        let unit = match unit {
+            "" => None,
+            unit => Some(unit.parse::<LengthUnit>().map_err(|_| invalid())?),
+        };
+
+        Ok(Self { value, unit })
+    }
+}
+
+/// A percentage, such as `50%`.
+///
+/// <https://www.w3.org/TR/css-values-4/#percentages>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Percentage(Number);
+
+impl Percentage {
+    pub fn new(value: f64) -> Result<Self, ValueError> {
+        Ok(Self(Number::new(value)?))
+    }
+
+    pub fn value(&self) -> Number {
+        self.0
+    }
+}
+
+impl From<Number> for Percentage {
+    fn from(value: Number) -> Self {
+        Self(value)
+    }
+}
+
+impl Display for Percentage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+impl FromStr for Percentage {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.strip_suffix('%')
+            .and_then(|value| value.parse().ok())
+            .map(Self)
+            .ok_or_else(|| ValueError::new(s, "Invalid percentage."))
+    }
+}
+
+/// A value that is either a number or a percentage, such as the value of `opacity`.
+///
+/// <https://www.w3.org/TR/css-color-4/#typedef-alpha-value>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberOrPercentage {
+    Number(Number),
+    Percentage(Percentage),
+}
+
+impl From<Number> for NumberOrPercentage {
+    fn from(value: Number) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<Percentage> for NumberOrPercentage {
+    fn from(value: Percentage) -> Self {
+        Self::Percentage(value)
+    }
+}
+
+impl Display for NumberOrPercentage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(value) => value.fmt(f),
+            Self::Percentage(value) => value.fmt(f),
+        }
+    }
+}
+
+impl FromStr for NumberOrPercentage {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.ends_with('%') {
+            s.parse().map(Self::Percentage)
+        } else {
+            s.parse().map(Self::Number)
+        }
+    }
+}
+
+/// A value that is either a length, a percentage or a `calc()` expression combining
+/// them, such as the value of `cx` or `width`.
+///
+/// <https://www.w3.org/TR/css-values-4/#typedef-length-percentage>
+#[derive(Debug, Clone, PartialEq)]
+pub enum LengthPercentage {
+    Length(Length),
+    Percentage(Percentage),
+    Calc(Calc),
+}
+
+impl From<Length> for LengthPercentage {
+    fn from(value: Length) -> Self {
+        Self::Length(value)
+    }
+}
+
+impl From<Percentage> for LengthPercentage {
+    fn from(value: Percentage) -> Self {
+        Self::Percentage(value)
+    }
+}
+
+impl From<Calc> for LengthPercentage {
+    fn from(value: Calc) -> Self {
+        Self::Calc(value)
+    }
+}
+
+impl Display for LengthPercentage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length(value) => value.fmt(f),
+            Self::Percentage(value) => value.fmt(f),
+            Self::Calc(value) => value.fmt(f),
+        }
+    }
+}
+
+impl FromStr for LengthPercentage {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if starts_with_calc(s) {
+            s.parse().map(Self::Calc)
+        } else if s.ends_with('%') {
+            s.parse().map(Self::Percentage)
+        } else {
+            s.parse().map(Self::Length)
+        }
+    }
+}
+
+/// A list of one or more lengths or percentages, such as the per-glyph positions in
+/// `x="10 20 30"` on `<text>`. Items are separated by whitespace, a comma or both, and
+/// serialized separated by a space.
+///
+/// <https://www.w3.org/TR/SVG2/text.html#TSpanAttributes>
+#[derive(Debug, Clone, PartialEq)]
+pub struct LengthPercentageList {
+    items: Vec<LengthPercentage>,
+}
+
+impl LengthPercentageList {
+    pub fn new(first: LengthPercentage) -> Self {
+        Self { items: vec![first] }
+    }
+
+    /// Adds `item` to the end of the list.
+    pub fn push(&mut self, item: LengthPercentage) {
+        self.items.push(item);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &LengthPercentage> {
+        self.items.iter()
+    }
+}
+
+impl<T: Into<LengthPercentage>> From<T> for LengthPercentageList {
+    fn from(value: T) -> Self {
+        Self::new(value.into())
+    }
+}
+
+impl TryFrom<Vec<LengthPercentage>> for LengthPercentageList {
+    type Error = ValueError;
+
+    fn try_from(items: Vec<LengthPercentage>) -> Result<Self, Self::Error> {
+        if items.is_empty() {
+            return Err(ValueError::new("", "The list needs at least one item."));
+        }
+
+        Ok(Self { items })
+    }
+}
+
+impl Display for LengthPercentageList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, item) in self.items.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            item.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for LengthPercentageList {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid list of lengths.");
+        let mut items = Vec::new();
+        let mut rest = s.trim_matches(|c: char| c.is_ascii_whitespace());
+        while !rest.is_empty() {
+            // A `calc()` item contains whitespace, so it ends at its closing parenthesis.
+            let len = if starts_with_calc(rest) {
+                let mut depth = 0;
+                rest.find(|c: char| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        _ => {}
+                    }
+                    c == ')' && depth == 0
+                })
+                .ok_or_else(invalid)?
+                    + 1
+            } else {
+                rest.find(|c: char| c.is_ascii_whitespace() || c == ',')
+                    .unwrap_or(rest.len())
+            };
+            items.push(rest[..len].parse()?);
+
+            let after = &rest[len..];
+            rest = after.trim_start_matches(|c: char| c.is_ascii_whitespace());
+            if let Some(after_comma) = rest.strip_prefix(',') {
+                rest = after_comma.trim_start_matches(|c: char| c.is_ascii_whitespace());
+                if rest.is_empty() {
+                    return Err(invalid());
+                }
+            } else if rest.len() == after.len() && !rest.is_empty() {
+                return Err(invalid());
+            }
+        }
+
+        Self::try_from(items).map_err(|_| invalid())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Plus,
+    Minus,
+    Times,
+    DividedBy,
+}
+
+impl Operator {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Operator::Plus => "+",
+            Operator::Minus => "-",
+            Operator::Times => "*",
+            Operator::DividedBy => "/",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Number(Number),
+    Length(Length),
+    Percentage(Percentage),
+    Sum(Box<Node>, Operator, Box<Node>),
+    Product(Box<Node>, Operator, Box<Node>),
+}
+
+impl Node {
+    /// Whether the node resolves to a plain number rather than a length.
+    fn is_number(&self) -> bool {
+        match self {
+            Node::Number(_) => true,
+            Node::Length(_) | Node::Percentage(_) => false,
+            Node::Sum(left, _, _) => left.is_number(),
+            Node::Product(left, _, right) => left.is_number() && right.is_number(),
+        }
+    }
+
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, parenthesize: bool) -> fmt::Result {
+        if parenthesize {
+            write!(f, "({self})")
+        } else {
+            write!(f, "{self}")
+        }
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Node::Number(value) => value.fmt(f),
+            Node::Length(value) => value.fmt(f),
+            Node::Percentage(value) => value.fmt(f),
+            Node::Sum(left, operator, right) => {
+                left.fmt(f)?;
+                write!(f, " {} ", operator.as_str())?;
+                right.fmt_operand(f, matches!(**right, Node::Sum(..)))
+            }
+            Node::Product(left, operator, right) => {
+                left.fmt_operand(f, matches!(**left, Node::Sum(..)))?;
+                write!(f, " {} ", operator.as_str())?;
+                right.fmt_operand(f, matches!(**right, Node::Sum(..) | Node::Product(..)))
+            }
+        }
+    }
+}
+
+/// A `calc()` expression mixing lengths and percentages, such as `calc(100% - 2rem)`.
+///
+/// Expressions are built with [Calc::plus], [Calc::minus], [Calc::times] and
+/// [Calc::divided_by] and are serialized as written; they are never evaluated.
+///
+/// <https://www.w3.org/TR/css-values-4/#calc-func>
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calc(Node);
+
+impl Calc {
+    pub fn plus<T: Into<Calc>>(self, other: T) -> Self {
+        Self(Node::Sum(
+            Box::new(self.0),
+            Operator::Plus,
+            Box::new(other.into().0),
+        ))
+    }
+
+    pub fn minus<T: Into<Calc>>(self, other: T) -> Self {
+        Self(Node::Sum(
+            Box::new(self.0),
+            Operator::Minus,
+            Box::new(other.into().0),
+        ))
+    }
+
+    pub fn times(self, factor: Number) -> Self {
+        Self(Node::Product(
+            Box::new(self.0),
+            Operator::Times,
+            Box::new(Node::Number(factor)),
+        ))
+    }
+
+    pub fn divided_by(self, divisor: Number) -> Result<Self, ValueError> {
+        if divisor.value() == 0.0 {
+            return Err(ValueError::new(&self.to_string(), "Cannot divide by zero."));
+        }
+
+        Ok(Self(Node::Product(
+            Box::new(self.0),
+            Operator::DividedBy,
+            Box::new(Node::Number(divisor)),
+        )))
+    }
+}
+
+impl From<Length> for Calc {
+    fn from(value: Length) -> Self {
+        Self(Node::Length(value))
+    }
+}
+
+impl From<Percentage> for Calc {
+    fn from(value: Percentage) -> Self {
+        Self(Node::Percentage(value))
+    }
+}
+
+impl Display for Calc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "calc({})", self.0)
+    }
+}
+
+impl FromStr for Calc {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid calc() expression.");
+        let mut parser = CalcParser {
+            tokens: tokenize(s).ok_or_else(invalid)?,
+            position: 0,
+        };
+
+        match parser.tokens.first() {
+            Some(Token::Function) => parser.position += 1,
+            _ => return Err(invalid()),
+        }
+        let node = parser.sum().ok_or_else(invalid)?;
+        if parser.next() != Some(Token::Close) || parser.position != parser.tokens.len() {
+            return Err(invalid());
+        }
+        if node.is_number() {
+            return Err(ValueError::new(s, "calc() must resolve to a length."));
+        }
+
+        Ok(Self(node))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Function,
+    Open,
+    Close,
+    Operator(Operator),
+    Value(Node),
+}
+
+fn tokenize(s: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut rest = s.trim();
+
+    while !rest.is_empty() {
+        let (token, len) = if starts_with_calc(rest) {
+            (Token::Function, 5)
+        } else {
+            match rest.as_bytes()[0] {
+                b'(' => (Token::Open, 1),
+                b')' => (Token::Close, 1),
+                b'*' => (Token::Operator(Operator::Times), 1),
+                b'/' => (Token::Operator(Operator::DividedBy), 1),
+                // `+` and `-` are operators only when surrounded by whitespace.
+                b'+' | b'-' if rest[1..].starts_with(|c: char| c.is_ascii_whitespace()) => {
+                    let operator = if rest.starts_with('+') {
+                        Operator::Plus
+                    } else {
+                        Operator::Minus
+                    };
+                    (Token::Operator(operator), 1)
+                }
+                _ => {
+                    let len = rest
+                        .find(|c: char| {
+                            c.is_ascii_whitespace() || matches!(c, '(' | ')' | '*' | '/')
+                        })
+                        .unwrap_or(rest.len());
+                    (Token::Value(value_node(&rest[..len])?), len)
+                }
+            }
+        };
+
+        tokens.push(token);
+        rest = rest[len..].trim_start();
+    }
+
+    Some(tokens)
+}
+
+fn starts_with_calc(s: &str) -> bool {
+    s.get(..5)
+        .map(|start| start.eq_ignore_ascii_case("calc("))
+        .unwrap_or(false)
+}
+
+fn value_node(s: &str) -> Option<Node> {
+    if is_number_token(s) {
+        s.parse().ok().map(Node::Number)
+    } else if s.ends_with('%') {
+        s.parse().ok().map(Node::Percentage)
+    } else {
+        s.parse().ok().map(Node::Length)
+    }
+}
+
+struct CalcParser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl CalcParser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek_operator(&self) -> Option<Operator> {
+        match self.tokens.get(self.position) {
+            Some(Token::Operator(operator)) => Some(*operator),
+            _ => None,
+        }
+    }
+
+    fn sum(&mut self) -> Option<Node> {
+        let mut node = self.product()?;
+        while let Some(operator @ (Operator::Plus | Operator::Minus)) = self.peek_operator() {
+            self.position += 1;
+            let right = self.product()?;
+            // Numbers and lengths cannot be added together.
+            if node.is_number() != right.is_number() {
+                return None;
+            }
+            node = Node::Sum(Box::new(node), operator, Box::new(right));
+        }
+
+        Some(node)
+    }
+
+    fn product(&mut self) -> Option<Node> {
+        let mut node = self.value()?;
+        while let Some(operator @ (Operator::Times | Operator::DividedBy)) = self.peek_operator() {
+            self.position += 1;
+            let right = self.value()?;
+            let is_valid = match operator {
+                Operator::Times => node.is_number() || right.is_number(),
+                _ => right.is_number() && right != Node::Number(Number::default()),
+            };
+            if !is_valid {
+                return None;
+            }
+            node = Node::Product(Box::new(node), operator, Box::new(right));
+        }
+
+        Some(node)
+    }
+
+    fn value(&mut self) -> Option<Node> {
+        match self.next()? {
+            Token::Value(node) => Some(node),
+            Token::Open | Token::Function => {
+                let node = self.sum()?;
+                match self.next()? {
+                    Token::Close => Some(node),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNITS: &[&str] = &[
+        "px", "cm", "mm", "Q", "in", "pt", "pc", "em", "rem", "ex", "rex", "cap", "rcap", "ch",
+        "rch", "ic", "ric", "lh", "rlh", "vw", "vh", "vi", "vb", "vmin", "vmax", "svw", "svh",
+        "svi", "svb", "svmin", "svmax", "lvw", "lvh", "lvi", "lvb", "lvmin", "lvmax", "dvw", "dvh",
+        "dvi", "dvb", "dvmin", "dvmax", "cqw", "cqh", "cqi", "cqb", "cqmin", "cqmax",
+    ];
+
+    fn length(s: &str) -> Length {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn lengths_in_every_unit() {
+        for unit in UNITS {
+            let input = format!("1.5{unit}");
+            let parsed = length(&input);
+            assert_eq!(parsed.unit(), Some(unit.parse().unwrap()));
+            assert_eq!(parsed.value(), Number::new(1.5).unwrap());
+            assert_eq!(parsed.to_string(), input);
+        }
+        assert_eq!(length("2REM").to_string(), "2rem");
+        assert_eq!(length("-.5vw").to_string(), "-0.5vw");
+        assert_eq!(length("10").unit(), None);
+        assert_eq!(Length::vh(100.0).unwrap().to_string(), "100vh");
+        assert_eq!(Length::ch(3.0).unwrap().to_string(), "3ch");
+        for input in ["", "px", "1 px", "1furlong", "1.px", "NaNpx", "1e"] {
+            assert!(input.parse::<Length>().is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn percentages() {
+        let percentage: Percentage = "12.5%".parse().unwrap();
+        assert_eq!(percentage.value(), Number::new(12.5).unwrap());
+        assert_eq!(percentage.to_string(), "12.5%");
+        for input in ["%", "12.5", "12.5 %", "x%"] {
+            assert!(input.parse::<Percentage>().is_err(), "{input:?}");
+        }
+        assert!(matches!(
+            "50%".parse::<LengthPercentage>(),
+            Ok(LengthPercentage::Percentage(_))
+        ));
+        assert!(matches!(
+            "5em".parse::<LengthPercentage>(),
+            Ok(LengthPercentage::Length(_))
+        ));
+    }
+
+    #[test]
+    fn calc_builder() {
+        let a = || Calc::from(Percentage::new(100.0).unwrap());
+        let b = || Length::rem(2.0).unwrap();
+        let c = || Length::px(1.0).unwrap();
+        let two = Number::from(2);
+
+        assert_eq!(a().minus(b()).to_string(), "calc(100% - 2rem)");
+        assert_eq!(
+            a().minus(Calc::from(b()).plus(c())).to_string(),
+            "calc(100% - (2rem + 1px))"
+        );
+        assert_eq!(
+            a().plus(b()).minus(c()).to_string(),
+            "calc(100% + 2rem - 1px)"
+        );
+        assert_eq!(
+            a().plus(b()).times(two).to_string(),
+            "calc((100% + 2rem) * 2)"
+        );
+        assert_eq!(
+            a().times(two).divided_by(two).unwrap().to_string(),
+            "calc(100% * 2 / 2)"
+        );
+        assert!(a().divided_by(Number::default()).is_err());
+
+        let built = a().minus(Calc::from(b()).plus(c()));
+        assert_eq!(built.to_string().parse::<Calc>(), Ok(built));
+    }
+}
@@ -0,0 +1,184 @@
+use super::{TokenList, ValueError};
+use attribute_derive::AttributeValue;
+#[cfg(feature = "yew")]
+use yew::html::IntoPropValue;
+
+/// The elements that accept a `rel` attribute. Which link types are allowed, and what
+/// they do, depends on the element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinkElement {
+    A,
+    Area,
+    Form,
+    Link,
+}
+
+impl LinkElement {
+    pub fn tag_name(&self) -> &'static str {
+        match self {
+            LinkElement::A => "a",
+            LinkElement::Area => "area",
+            LinkElement::Form => "form",
+            LinkElement::Link => "link",
+        }
+    }
+}
+
+/// What a link type does on a given element.
+///
+/// <https://html.spec.whatwg.org/multipage/links.html#linkTypes>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinkTypeEffect {
+    /// The link points to another document, like `next` or `license`.
+    Hyperlink,
+    /// The linked resource is fetched and used by the current document, like
+    /// `stylesheet` or `icon`.
+    ExternalResource,
+    /// The type only changes how the browser treats the element, like `expect`.
+    InternalResource,
+    /// The type changes how the link is followed, like `noopener` or `nofollow`.
+    Annotation,
+}
+
+/// A link type for the `rel` attribute of `a`, `area`, `form` and `link` elements.
+///
+/// Types that aren't defined by HTML, such as those from the microformats registry,
+/// can be used through [LinkType::Extension]. Their effect is unknown, so they are
+/// allowed on every element. An extension that spells one of the types above, such as
+/// `Extension("stylesheet".into())`, is treated as that type.
+///
+/// <https://html.spec.whatwg.org/multipage/links.html#linkTypes>
+#[derive(Debug, AttributeValue)]
+#[attribute_value(case = "kebab-case")]
+pub enum LinkType {
+    Alternate,
+    Author,
+    Bookmark,
+    Canonical,
+    DnsPrefetch,
+    Expect,
+    External,
+    Help,
+    Icon,
+    License,
+    Manifest,
+    #[attribute_value(rename = "modulepreload")]
+    ModulePreload,
+    Next,
+    #[attribute_value(rename = "nofollow")]
+    NoFollow,
+    #[attribute_value(rename = "noopener")]
+    NoOpener,
+    #[attribute_value(rename = "noreferrer")]
+    NoReferrer,
+    Opener,
+    Pingback,
+    Preconnect,
+    Prefetch,
+    Preload,
+    Prev,
+    PrivacyPolicy,
+    Search,
+    Stylesheet,
+    Tag,
+    TermsOfService,
+    #[attribute_value(custom)]
+    Extension(String),
+}
+
+impl LinkType {
+    /// Returns what the type does on `element`, or `None` if the type isn't allowed
+    /// on it. Extension types always return `None` since their effect is unknown; use
+    /// [LinkType::is_allowed_on] to check whether a type may be used.
+    pub fn effect_on(&self, element: LinkElement) -> Option<LinkTypeEffect> {
+        use LinkElement::*;
+        use LinkTypeEffect::*;
+
+        if let Some(known) = self.known() {
+            return known.effect_on(element);
+        }
+
+        let is_link = element == Link;
+        let is_a_or_area = matches!(element, A | Area);
+        match self {
+            LinkType::Alternate
+            | LinkType::Author
+            | LinkType::PrivacyPolicy
+            | LinkType::TermsOfService => (is_link || is_a_or_area).then_some(Hyperlink),
+            LinkType::Help
+            | LinkType::License
+            | LinkType::Next
+            | LinkType::Prev
+            | LinkType::Search => Some(Hyperlink),
+            LinkType::Canonical => is_link.then_some(Hyperlink),
+            LinkType::Bookmark | LinkType::Tag => is_a_or_area.then_some(Hyperlink),
+            LinkType::DnsPrefetch
+            | LinkType::Icon
+            | LinkType::Manifest
+            | LinkType::ModulePreload
+            | LinkType::Pingback
+            | LinkType::Preconnect
+            | LinkType::Prefetch
+            | LinkType::Preload
+            | LinkType::Stylesheet => is_link.then_some(ExternalResource),
+            LinkType::Expect => is_link.then_some(InternalResource),
+            LinkType::External
+            | LinkType::NoFollow
+            | LinkType::NoOpener
+            | LinkType::NoReferrer
+            | LinkType::Opener => (!is_link).then_some(Annotation),
+            LinkType::Extension(_) => None,
+        }
+    }
+
+    /// Checks whether the type may be used in the `rel` attribute of `element`.
+    pub fn is_allowed_on(&self, element: LinkElement) -> bool {
+        match self.known() {
+            Some(known) => known.is_allowed_on(element),
+            None => matches!(self, LinkType::Extension(_)) || self.effect_on(element).is_some(),
+        }
+    }
+
+    /// Returns the type an extension spells, if it is one of the types defined by HTML.
+    fn known(&self) -> Option<LinkType> {
+        match self {
+            LinkType::Extension(name) => name
+                .parse()
+                .ok()
+                .filter(|known| !matches!(known, LinkType::Extension(_))),
+            _ => None,
+        }
+    }
+
+    pub fn is_hyperlink(&self, element: LinkElement) -> bool {
+        self.effect_on(element) == Some(LinkTypeEffect::Hyperlink)
+    }
+
+    pub fn is_external_resource(&self, element: LinkElement) -> bool {
+        self.effect_on(element) == Some(LinkTypeEffect::ExternalResource)
+    }
+
+    /// Checks that every type in `rel` may be used on `element`.
+    pub fn validate(rel: &TokenList<LinkType>, element: LinkElement) -> Result<(), ValueError> {
+        match rel
+            .iter()
+            .find(|link_type| !link_type.is_allowed_on(element))
+        {
+            Some(link_type) => Err(ValueError::new(
+                link_type.as_ref(),
+                &format!(
+                    "This link type is not allowed on <{}> elements.",
+                    element.tag_name()
+                ),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "yew")]
+impl IntoPropValue<String> for LinkType {
+    fn into_prop_value(self) -> String {
+        self.to_string()
+    }
+}
@@ -0,0 +1,71 @@
+//! Value types for DOM attributes whose values follow one of the HTML
+//! microsyntaxes. Each type validates its input when it is constructed, so
+//! an attribute built from one of them always serializes to a valid value.
+
+use std::fmt;
+
+mod attr_value;
+pub mod autocomplete;
+mod comma_list;
+pub mod datetime;
+mod enumerated;
+pub mod href;
+pub mod integrity;
+mod keyword_or;
+pub mod length;
+mod link_type;
+mod number;
+mod token_list;
+
+pub(crate) use attr_value::impl_attr_value;
+pub use attr_value::{FromAttrValue, ToAttrValue};
+pub use autocomplete::{
+    AddressType, AutocompleteValue, Autofill, AutofillField, ContactField, ContactType, FieldName,
+};
+pub use comma_list::CommaList;
+pub use datetime::{
+    Date, DateTimeValue, DateWithOptionalTime, Duration, GlobalDateTime, InputBound,
+    InputDateValue, LocalDateTime, Month, Time, TimeZoneOffset, Week, YearlessDate,
+};
+pub use enumerated::EnumeratedValue;
+pub use href::{Href, HrefKind, MailTo};
+pub use integrity::{HashAlgorithm, Integrity, IntegrityMetadata};
+pub use keyword_or::KeywordOr;
+pub use length::{
+    Calc, Length, LengthPercentage, LengthPercentageList, LengthUnit, NumberOrPercentage,
+    Percentage,
+};
+pub use link_type::{LinkElement, LinkType, LinkTypeEffect};
+pub use number::{format_number, Number, NumberList, NumberOptionalNumber, ViewBoxRect};
+pub use token_list::TokenList;
+
+/// Error indicating that a value could not be used for an attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueError {
+    value: String,
+    message: String,
+}
+
+impl ValueError {
+    /// Creates an error for `value`. `message` is a sentence describing what is wrong
+    /// with it, such as `Invalid number.`
+    pub fn new(value: &str, message: &str) -> Self {
+        Self {
+            value: value.to_owned(),
+            message: message.to_owned(),
+        }
+    }
+
+    /// Returns the rejected input.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} Value: {}", self.message, self.value)
+    }
+}
+
+impl std::error::Error for ValueError {}
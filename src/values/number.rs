@@ -0,0 +1,433 @@
+use super::ValueError;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A finite number, such as the value of `opacity` or `k1`. `NaN` and infinities are
+/// rejected since no attribute accepts them.
+///
+/// <https://www.w3.org/TR/css-values-4/#numbers>
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Number(f64);
+
+impl Number {
+    pub fn new(value: f64) -> Result<Self, ValueError> {
+        if !value.is_finite() {
+            return Err(ValueError::new(
+                &value.to_string(),
+                "Numbers must be finite.",
+            ));
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl TryFrom<f64> for Number {
+    type Error = ValueError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<i32> for Number {
+    fn from(value: i32) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<u32> for Number {
+    fn from(value: u32) -> Self {
+        Self(value.into())
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_number(self.0))
+    }
+}
+
+impl FromStr for Number {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid number.");
+        if !is_number_token(s) {
+            return Err(invalid());
+        }
+
+        s.parse::<f64>()
+            .map_err(|_| invalid())
+            .and_then(|value| Self::new(value).map_err(|_| invalid()))
+    }
+}
+
+/// One or two numbers separated by whitespace or a comma, such as the value of
+/// `baseFrequency` or `kernelUnitLength`. A missing second number takes the value of the
+/// first.
+///
+/// <https://www.w3.org/TR/SVG11/types.html#DataTypeNumberOptionalNumber>
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct NumberOptionalNumber {
+    first: Number,
+    second: Option<Number>,
+}
+
+impl NumberOptionalNumber {
+    pub fn new(first: Number, second: Option<Number>) -> Self {
+        Self { first, second }
+    }
+
+    pub fn first(&self) -> Number {
+        self.first
+    }
+
+    /// Returns the second number, or the first when it was left out.
+    pub fn second(&self) -> Number {
+        self.second.unwrap_or(self.first)
+    }
+}
+
+impl From<Number> for NumberOptionalNumber {
+    fn from(value: Number) -> Self {
+        Self::new(value, None)
+    }
+}
+
+impl From<(Number, Number)> for NumberOptionalNumber {
+    fn from((first, second): (Number, Number)) -> Self {
+        Self::new(first, Some(second))
+    }
+}
+
+impl Display for NumberOptionalNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.first.fmt(f)?;
+        if let Some(second) = self.second {
+            write!(f, " {second}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for NumberOptionalNumber {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid number-optional-number.");
+        let trimmed = s.trim_matches(|c: char| c.is_ascii_whitespace());
+        let (first, second) = match trimmed.split_once(',') {
+            Some((first, second)) => (first.trim_end(), Some(second.trim_start())),
+            None => match trimmed.split_once(|c: char| c.is_ascii_whitespace()) {
+                Some((first, second)) => (first, Some(second.trim_start())),
+                None => (trimmed, None),
+            },
+        };
+
+        let first = first.parse().map_err(|_| invalid())?;
+        let second = second
+            .map(|second| second.parse().map_err(|_| invalid()))
+            .transpose()?;
+        Ok(Self::new(first, second))
+    }
+}
+
+/// A list of one or more numbers separated by whitespace, a comma or both, such as the
+/// value of `points` or `kernelMatrix`. It is serialized separated by spaces.
+///
+/// <https://www.w3.org/TR/SVG11/types.html#DataTypeList>
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberList {
+    items: Vec<Number>,
+}
+
+impl NumberList {
+    pub fn new(first: Number) -> Self {
+        Self { items: vec![first] }
+    }
+
+    /// Adds `item` to the end of the list.
+    pub fn push(&mut self, item: Number) {
+        self.items.push(item);
+    }
+
+    pub fn as_slice(&self) -> &[Number] {
+        &self.items
+    }
+}
+
+impl From<Number> for NumberList {
+    fn from(value: Number) -> Self {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<Vec<Number>> for NumberList {
+    type Error = ValueError;
+
+    fn try_from(items: Vec<Number>) -> Result<Self, Self::Error> {
+        if items.is_empty() {
+            return Err(ValueError::new("", "The list needs at least one number."));
+        }
+
+        Ok(Self { items })
+    }
+}
+
+impl Display for NumberList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, item) in self.items.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            item.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for NumberList {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid list of numbers.");
+        let mut items = Vec::new();
+        // Every comma must be surrounded by numbers, so no group between commas is empty.
+        for group in s.split(',') {
+            let mut numbers = group.split_ascii_whitespace().peekable();
+            if numbers.peek().is_none() {
+                return Err(invalid());
+            }
+            for number in numbers {
+                items.push(number.parse().map_err(|_| invalid())?);
+            }
+        }
+
+        Self::try_from(items).map_err(|_| invalid())
+    }
+}
+
+/// The rectangle of user space that an SVG viewport is mapped to: the value of
+/// `viewBox`, such as `0 0 100 50`. The width and height may not be negative.
+///
+/// <https://www.w3.org/TR/SVG2/coords.html#ViewBoxAttribute>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewBoxRect {
+    min_x: Number,
+    min_y: Number,
+    width: Number,
+    height: Number,
+}
+
+impl ViewBoxRect {
+    pub fn new(
+        min_x: Number,
+        min_y: Number,
+        width: Number,
+        height: Number,
+    ) -> Result<Self, ValueError> {
+        if width.value() < 0.0 || height.value() < 0.0 {
+            return Err(ValueError::new(
+                &format!("{min_x} {min_y} {width} {height}"),
+                "The width and height of a view box may not be negative.",
+            ));
+        }
+
+        Ok(Self {
+            min_x,
+            min_y,
+            width,
+            height,
+        })
+    }
+
+    pub fn min_x(&self) -> Number {
+        self.min_x
+    }
+
+    pub fn min_y(&self) -> Number {
+        self.min_y
+    }
+
+    pub fn width(&self) -> Number {
+        self.width
+    }
+
+    pub fn height(&self) -> Number {
+        self.height
+    }
+}
+
+impl Display for ViewBoxRect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.min_x, self.min_y, self.width, self.height
+        )
+    }
+}
+
+impl FromStr for ViewBoxRect {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValueError::new(s, "Invalid view box.");
+        let numbers: NumberList = s.parse().map_err(|_| invalid())?;
+        match numbers.as_slice() {
+            [min_x, min_y, width, height] => {
+                Self::new(*min_x, *min_y, *width, *height).map_err(|_| invalid())
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Checks `s` against the CSS `<number-token>` grammar, which is stricter than what
+/// `f64::from_str` accepts (no `inf`, `NaN` or trailing `.`).
+///
+/// <https://www.w3.org/TR/css-syntax-3/#consume-number>
+pub(crate) fn is_number_token(s: &str) -> bool {
+    let s = s.strip_prefix(['+', '-']).unwrap_or(s);
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(index) => (&s[..index], Some(&s[index + 1..])),
+        None => (s, None),
+    };
+
+    let all_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    let mantissa_is_valid = match mantissa.split_once('.') {
+        Some((whole, fraction)) => (whole.is_empty() || all_digits(whole)) && all_digits(fraction),
+        None => all_digits(mantissa),
+    };
+    let exponent_is_valid = exponent
+        .map(|exponent| all_digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent)))
+        .unwrap_or(true);
+
+    mantissa_is_valid && exponent_is_valid
+}
+
+/// Serializes `value` the way JavaScript's `Number.prototype.toString()` does, so a value
+/// rendered on the server is byte-identical to the same value set from a script.
+///
+/// `-0` is serialized as `0`, and values with a decimal exponent of 21 or more, or less
+/// than -6, use exponent notation (`1e+21`, `1e-7`). Otherwise the shortest digits that
+/// round-trip are written out in full, so `0.1 + 0.2` becomes `0.30000000000000004`.
+///
+/// <https://tc39.es/ecma262/#sec-numeric-types-number-tostring>
+pub fn format_number(value: f64) -> String {
+    if value.is_nan() {
+        return String::from("NaN");
+    }
+    if value == 0.0 {
+        return String::from("0");
+    }
+    if value.is_infinite() {
+        let sign = if value < 0.0 { "-" } else { "" };
+        return format!("{sign}Infinity");
+    }
+
+    // `{:e}` writes the shortest round-trip digits as `d.ddde-x`.
+    let scientific = format!("{:e}", value.abs());
+    let (mantissa, exponent) = scientific
+        .split_once('e')
+        .expect("scientific notation to have an exponent");
+    let digits = mantissa.replace('.', "");
+    let k = digits.len() as i32;
+    // The position of the decimal point relative to the start of `digits`.
+    let n = exponent
+        .parse::<i32>()
+        .expect("the exponent to be an integer")
+        + 1;
+
+    let mut output = String::new();
+    if value < 0.0 {
+        output.push('-');
+    }
+
+    if k <= n && n <= 21 {
+        output.push_str(&digits);
+        output.push_str(&"0".repeat((n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        let (whole, fraction) = digits.split_at(n as usize);
+        output.push_str(whole);
+        output.push('.');
+        output.push_str(fraction);
+    } else if -6 < n && n <= 0 {
+        output.push_str("0.");
+        output.push_str(&"0".repeat(-n as usize));
+        output.push_str(&digits);
+    } else {
+        let (first, rest) = digits.split_at(1);
+        output.push_str(first);
+        if !rest.is_empty() {
+            output.push('.');
+            output.push_str(rest);
+        }
+        let sign = if n - 1 < 0 { '-' } else { '+' };
+        output.push('e');
+        output.push(sign);
+        output.push_str(&(n - 1).abs().to_string());
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_like_javascript() {
+        for (value, expected) in [
+            (-0.0, "0"),
+            (1e21, "1e+21"),
+            (1e-7, "1e-7"),
+            (0.000001, "0.000001"),
+            (0.1 + 0.2, "0.30000000000000004"),
+            (123456789012345680000.0, "123456789012345680000"),
+            (-1.5e-10, "-1.5e-10"),
+            (100.0, "100"),
+            (f64::NAN, "NaN"),
+            (f64::NEG_INFINITY, "-Infinity"),
+        ] {
+            assert_eq!(format_number(value), expected, "{value:e}");
+        }
+    }
+
+    #[test]
+    fn numbers_are_finite() {
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(Number::new(value).is_err(), "{value}");
+            assert!(Number::try_from(value).is_err(), "{value}");
+        }
+        assert_eq!(Number::new(-0.0).unwrap().to_string(), "0");
+    }
+
+    #[test]
+    fn number_lists() {
+        let list: NumberList = " 1,2 3 ,\t4e1 -5 ".parse().unwrap();
+        assert_eq!(list.to_string(), "1 2 3 40 -5");
+        assert_eq!(list.as_slice().len(), 5);
+        for input in ["", " ", "1,", ",1", "1,,2", "1 , , 2", "1 x", "1,NaN"] {
+            assert!(input.parse::<NumberList>().is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn view_boxes() {
+        let view_box: ViewBoxRect = "-10,0 100.5 50".parse().unwrap();
+        assert_eq!(view_box.min_x(), Number::from(-10));
+        assert_eq!(view_box.height(), Number::from(50));
+        assert_eq!(view_box.to_string(), "-10 0 100.5 50");
+        for input in ["0 0 100", "0 0 100 50 1", "0 0 -1 50", "0 0 100 -0.5"] {
+            assert!(input.parse::<ViewBoxRect>().is_err(), "{input:?}");
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use super::{ToAttrValue, ValueError};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// An ordered set of unique space-separated tokens, such as the value of `rel`, `part`
+/// or `aria-controls`. It behaves like the browser's `DOMTokenList`: adding a token
+/// that is already present does nothing, and parsing drops duplicates while keeping
+/// the order of first occurrence.
+///
+/// Tokens are compared by their serialized value and may not be empty or contain ASCII
+/// whitespace. Any [ToAttrValue] can be a token, such as a keyword enum or a `char` for
+/// the single code points of `accesskey`.
+///
+/// <https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#set-of-space-separated-tokens>
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TokenList<T = String> {
+    tokens: Vec<T>,
+}
+
+impl<T: ToAttrValue> TokenList<T> {
+    pub fn new() -> Self {
+        Self { tokens: Vec::new() }
+    }
+
+    /// Adds `token` to the end of the list unless it is already present.
+    pub fn add(&mut self, token: T) -> Result<(), ValueError> {
+        validate(&token.to_attr_value())?;
+        if !self.contains(token.to_attr_value()) {
+            self.tokens.push(token);
+        }
+
+        Ok(())
+    }
+
+    /// Removes `token` from the list. Returns whether it was present.
+    pub fn remove<S: AsRef<str>>(&mut self, token: S) -> bool {
+        let len = self.tokens.len();
+        self.tokens
+            .retain(|existing| existing.to_attr_value() != token.as_ref());
+        self.tokens.len() != len
+    }
+
+    /// Removes `token` if it is present and adds it otherwise. Returns whether the
+    /// token is present afterwards.
+    pub fn toggle(&mut self, token: T) -> Result<bool, ValueError> {
+        validate(&token.to_attr_value())?;
+        if self.remove(token.to_attr_value()) {
+            Ok(false)
+        } else {
+            self.tokens.push(token);
+            Ok(true)
+        }
+    }
+
+    pub fn contains<S: AsRef<str>>(&self, token: S) -> bool {
+        self.tokens
+            .iter()
+            .any(|existing| existing.to_attr_value() == token.as_ref())
+    }
+
+    /// Replaces `old` with `new`, removing any other occurrence of either. The token
+    /// takes the place of whichever of the two appears first. Returns `false` and
+    /// leaves the list unchanged if `old` is not present.
+    pub fn replace<S: AsRef<str>>(&mut self, old: S, new: T) -> Result<bool, ValueError> {
+        validate(&new.to_attr_value())?;
+        if !self.contains(&old) {
+            return Ok(false);
+        }
+
+        let old = old.as_ref();
+        let new_value = new.to_attr_value();
+        let is_either = |token: &str| token == old || token == new_value;
+        let first = self
+            .tokens
+            .iter()
+            .position(|token| is_either(&token.to_attr_value()))
+            .expect("the list to contain the old token");
+
+        let mut index = 0;
+        self.tokens.retain(|token| {
+            let keep = index == first || !is_either(&token.to_attr_value());
+            index += 1;
+            keep
+        });
+        self.tokens[first] = new;
+
+        Ok(true)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.tokens.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+}
+
+impl<T: ToAttrValue> Default for TokenList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ToAttrValue> TryFrom<Vec<T>> for TokenList<T> {
+    type Error = ValueError;
+
+    fn try_from(tokens: Vec<T>) -> Result<Self, Self::Error> {
+        let mut list = Self::new();
+        for token in tokens {
+            list.add(token)?;
+        }
+
+        Ok(list)
+    }
+}
+
+impl<T: ToAttrValue + FromStr> FromStr for TokenList<T> {
+    type Err = ValueError;
+
+    /// Splits `s` on ASCII whitespace and parses each token, dropping duplicates.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut list = Self::new();
+        for token in s.split_ascii_whitespace() {
+            let token = token
+                .parse()
+                .map_err(|_| ValueError::new(token, "Invalid token."))?;
+            list.add(token)?;
+        }
+
+        Ok(list)
+    }
+}
+
+impl<T: ToAttrValue> Display for TokenList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, token) in self.tokens.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(&token.to_attr_value())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> IntoIterator for TokenList<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tokens.into_iter()
+    }
+}
+
+fn validate(token: &str) -> Result<(), ValueError> {
+    if token.is_empty() {
+        return Err(ValueError::new(token, "Tokens may not be empty."));
+    }
+    if token.contains(|c: char| c.is_ascii_whitespace()) {
+        return Err(ValueError::new(
+            token,
+            "Tokens may not contain ASCII whitespace.",
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(s: &str) -> TokenList {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn adding_a_duplicate_does_nothing() {
+        let mut tokens = list("a b");
+        tokens.add("a".to_owned()).unwrap();
+        assert_eq!(tokens.to_string(), "a b");
+        tokens.add("c".to_owned()).unwrap();
+        assert_eq!(tokens.to_string(), "a b c");
+    }
+
+    #[test]
+    fn rejects_empty_and_whitespace_tokens() {
+        let mut tokens = list("a b");
+        for token in ["", "c d", " c", "c\t"] {
+            assert!(tokens.add(token.to_owned()).is_err(), "{token:?}");
+            assert!(tokens.toggle(token.to_owned()).is_err(), "{token:?}");
+            assert!(tokens.replace("a", token.to_owned()).is_err(), "{token:?}");
+        }
+        assert_eq!(tokens.to_string(), "a b");
+    }
+
+    #[test]
+    fn toggle_returns_whether_the_token_is_present() {
+        let mut tokens = list("a b");
+        assert_eq!(tokens.toggle("a".to_owned()), Ok(false));
+        assert_eq!(tokens.to_string(), "b");
+        assert_eq!(tokens.toggle("a".to_owned()), Ok(true));
+        assert_eq!(tokens.to_string(), "b a");
+    }
+
+    #[test]
+    fn remove_returns_whether_the_token_was_present() {
+        let mut tokens = list("a b");
+        assert!(tokens.remove("a"));
+        assert!(!tokens.remove("a"));
+        assert_eq!(tokens.to_string(), "b");
+    }
+
+    #[test]
+    fn replacing_an_absent_token_does_nothing() {
+        let mut tokens = list("a b");
+        assert_eq!(tokens.replace("c", "d".to_owned()), Ok(false));
+        assert_eq!(tokens.to_string(), "a b");
+    }
+
+    #[test]
+    fn replace_keeps_the_first_position() {
+        let mut tokens = list("a b c");
+        assert_eq!(tokens.replace("b", "d".to_owned()), Ok(true));
+        assert_eq!(tokens.to_string(), "a d c");
+
+        // `new` is already present after `old`: `old` is replaced and `new` dropped.
+        let mut tokens = list("a b c");
+        assert_eq!(tokens.replace("a", "c".to_owned()), Ok(true));
+        assert_eq!(tokens.to_string(), "c b");
+
+        // `new` is already present before `old`: it stays and `old` is dropped.
+        let mut tokens = list("a b c");
+        assert_eq!(tokens.replace("c", "a".to_owned()), Ok(true));
+        assert_eq!(tokens.to_string(), "a b");
+    }
+}
@@ -0,0 +1,20 @@
+//! `accesskey`, a token list whose tokens are single code points.
+
+use domatt::attributes::global::AccessKey;
+use domatt::attributes::Attribute;
+use domatt::values::TokenList;
+
+#[test]
+fn keys_are_single_code_points() {
+    let keys: AccessKey = "s é 0".parse().unwrap();
+    assert_eq!(keys.get_val(), Some("s é 0"));
+    assert!("s ctrl".parse::<AccessKey>().is_err());
+}
+
+#[test]
+fn duplicate_keys_are_dropped() {
+    let keys: TokenList<char> = "a b a".parse().unwrap();
+    assert_eq!(keys.to_string(), "a b");
+    assert!(keys.contains("b"));
+    assert_eq!(AccessKey::new(keys).get_val(), Some("a b"));
+}
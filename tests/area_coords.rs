@@ -0,0 +1,74 @@
+//! The `coords` of image map areas, which are lists of floating-point numbers.
+
+use domatt::attributes::area::{AreaTagShape, Circle, Coord, Coords, Poly, Rect};
+use domatt::attributes::Attribute;
+
+#[test]
+fn parses_fractional_and_negative_coords() {
+    let shape: AreaTagShape = "10.5,-3,20,20".parse().unwrap();
+    assert!(matches!(shape, AreaTagShape::Rect(_)));
+    assert_eq!(shape.to_string(), "10.5,-3,20,20");
+    assert!(shape.contains(15.25, -1.0));
+
+    let coords: Coords = "0,0,1e21".parse().unwrap();
+    assert_eq!(coords.get_val(), Some("0,0,1e+21"));
+}
+
+#[test]
+fn rejects_invalid_coords() {
+    for value in [
+        "",
+        "1,2",
+        "1,2,3,4,5",
+        "1,2,3,4,5,6,7",
+        "0,0,-5",
+        "1,2,NaN,4",
+    ] {
+        assert!(value.parse::<AreaTagShape>().is_err(), "{value:?}");
+    }
+    assert!(Coord::new(f64::NAN, 0.0).is_err());
+    assert!(Circle::new(Coord::from((0, 0)), -1.0).is_err());
+}
+
+#[test]
+fn polygons_need_three_vertices() {
+    let two = vec![Coord::from((0, 0)), Coord::from((10, 0))];
+    assert!(Poly::try_from(two.clone()).is_err());
+
+    let mut three = two;
+    three.push(Coord::from((0, 10)));
+    let poly = Poly::try_from(three).unwrap();
+    assert_eq!(poly.to_string(), "0,0,10,0,0,10");
+}
+
+#[test]
+fn scaling_keeps_fractions() {
+    let rect = Rect::new(Coord::from((0, 0)), Coord::from((25, 25)));
+    let scaled = AreaTagShape::from(rect).scale_to((100.0, 100.0), (50.0, 50.0));
+    assert_eq!(scaled.to_string(), "0,0,12.5,12.5");
+
+    let circle = Circle::new(Coord::from((10, 10)), 5.0).unwrap();
+    let scaled = circle.scale_to((100.0, 200.0), (150.0, 100.0));
+    assert_eq!(scaled.to_string(), "15,5,2.5");
+}
+
+#[test]
+fn coords_are_a_comma_list() {
+    let shape: AreaTagShape = " 1 , 2,3 ,\t4".parse().unwrap();
+    assert_eq!(shape.to_string(), "1,2,3,4");
+    let AreaTagShape::Rect(rect) = shape else {
+        panic!("four coordinates to be a rectangle");
+    };
+    let coords: Vec<f64> = rect.coords().iter().map(|coord| coord.value()).collect();
+    assert_eq!(coords, [1.0, 2.0, 3.0, 4.0]);
+    assert!("1,,2,3,4".parse::<AreaTagShape>().is_err());
+
+    let mut poly = Poly::new(
+        Coord::from((0, 0)),
+        Coord::from((1, 0)),
+        Coord::from((0, 1)),
+    );
+    poly.add_coord(Coord::new(0.5, -0.25).unwrap());
+    assert_eq!(poly.to_string(), "0,0,1,0,0,1,0.5,-0.25");
+    assert_eq!(poly.to_string().parse::<AreaTagShape>(), Ok(poly.into()));
+}
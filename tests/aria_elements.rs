@@ -0,0 +1,40 @@
+//! ARIA attributes apply to every element, since any element can be given a role.
+
+use domatt::attributes::anchor::AnchorAttribute;
+use domatt::attributes::aria::{
+    AriaColcount, AriaColindex, AriaColspan, AriaLabel, AriaRowcount, AriaRowindex, AriaRowspan,
+};
+use domatt::attributes::svg::SvgAttribute;
+
+fn applies_everywhere<T: AnchorAttribute + SvgAttribute>() {}
+
+#[test]
+fn table_attributes_apply_to_every_element() {
+    applies_everywhere::<AriaColcount>();
+    applies_everywhere::<AriaColindex>();
+    applies_everywhere::<AriaColspan>();
+    applies_everywhere::<AriaRowcount>();
+    applies_everywhere::<AriaRowindex>();
+    applies_everywhere::<AriaRowspan>();
+    applies_everywhere::<AriaLabel>();
+}
+
+#[test]
+fn counts_are_minus_one_or_positive() {
+    for value in [-1, 1, 100] {
+        assert!(AriaColcount::try_new(value).is_ok(), "{value}");
+        assert!(AriaRowcount::try_new(value).is_ok(), "{value}");
+    }
+    for value in [-2, 0] {
+        assert!(AriaColcount::try_new(value).is_err(), "{value}");
+        assert!(AriaRowcount::try_new(value).is_err(), "{value}");
+    }
+    assert!("0".parse::<AriaRowcount>().is_err());
+
+    // A rowspan of zero spans every remaining row of the row group.
+    for value in [0, 1, 5] {
+        assert!(AriaRowspan::try_new(value).is_ok(), "{value}");
+    }
+    assert!(AriaRowspan::try_new(-1).is_err());
+    assert!("-1".parse::<AriaRowspan>().is_err());
+}
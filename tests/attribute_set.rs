@@ -0,0 +1,83 @@
+//! Props structs whose fields are attributes, set or rendered as a whole.
+
+use domatt::attributes::anchor::{Download, Target};
+use domatt::attributes::global::{Id, Title};
+use domatt::attributes::{render_attributes, Attribute, Attributes, TargetOption};
+
+#[derive(Debug, Attributes)]
+struct CommonProps {
+    id: Option<Id>,
+    title: Option<Title>,
+}
+
+#[derive(Debug, Attributes)]
+struct LinkProps {
+    #[attr(flatten)]
+    common: CommonProps,
+    target: Target,
+    download: Option<Download>,
+    #[attr(skip)]
+    #[allow(dead_code)]
+    label: String,
+    #[attr(flatten)]
+    tooltip: Option<CommonProps>,
+}
+
+#[derive(Debug, Attributes)]
+struct Pair(Title, #[attr(skip)] u8);
+
+fn link(download: Option<Download>, tooltip: Option<CommonProps>) -> LinkProps {
+    LinkProps {
+        common: CommonProps {
+            id: Some(Id::try_new("home".to_owned()).unwrap()),
+            title: None,
+        },
+        target: Target::new(TargetOption::Blank),
+        download,
+        label: "Home".to_owned(),
+        tooltip,
+    }
+}
+
+#[test]
+fn yields_fields_in_order() {
+    let props = link(Some(Download::new(None)), None);
+    let keys: Vec<&str> = props.attributes().map(Attribute::get_key).collect();
+    assert_eq!(keys, ["id", "target", "download"]);
+    assert_eq!(
+        render_attributes(&props),
+        r#"id="home" target="_blank" download"#
+    );
+}
+
+#[test]
+fn flattens_optional_sets() {
+    let tooltip = CommonProps {
+        id: None,
+        title: Some(Title::new("Go home".to_owned())),
+    };
+    let props = link(None, Some(tooltip));
+    assert_eq!(
+        render_attributes(&props),
+        r#"id="home" target="_blank" title="Go home""#
+    );
+}
+
+#[test]
+fn derives_for_tuple_structs() {
+    let pair = Pair(Title::new("Pair".to_owned()), 2);
+    assert_eq!(pair.1, 2);
+    assert_eq!(render_attributes(&pair), r#"title="Pair""#);
+}
+
+#[test]
+fn renders_boxed_attributes() {
+    let attributes: Vec<Box<dyn Attribute>> = vec![
+        Box::new(Title::new("Boxed".to_owned())),
+        Box::new(Download::new(Some("file.txt".to_owned()))),
+    ];
+    assert_eq!(
+        render_attributes(&attributes),
+        r#"title="Boxed" download="file.txt""#
+    );
+}
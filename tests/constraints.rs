@@ -0,0 +1,81 @@
+//! The `try_new` that the `Attribute` derive generates for declared constraints.
+
+use domatt::attributes::Attribute;
+
+// The generated code must not pick up items named like those of the prelude.
+#[allow(dead_code)]
+type Result = ();
+#[allow(dead_code)]
+struct Ok;
+#[allow(dead_code)]
+struct Err;
+
+#[derive(Debug, Attribute)]
+#[attribute("kebab-case", i32, range(-1..=-1, 1..))]
+struct DataCount(String);
+
+#[derive(Debug, Attribute)]
+#[attribute("kebab-case", f64, range(0.0..=1.0))]
+struct DataRatio(String);
+
+#[derive(Debug, Attribute)]
+#[attribute("kebab-case", u8, range(..10, 20..30))]
+struct DataBand(String);
+
+#[derive(Debug, Attribute)]
+#[attribute(
+    "kebab-case",
+    String,
+    non_empty,
+    no_whitespace,
+    chars(ascii_alphanumeric, '-')
+)]
+struct DataSlug(String);
+
+#[derive(Debug, Attribute)]
+#[attribute("kebab-case", Option<String>, non_empty)]
+struct DataLabel(Option<String>);
+
+#[test]
+fn ranges_include_their_edges() {
+    for value in [-1, 1, 2, i32::MAX] {
+        assert!(DataCount::try_new(value).is_ok(), "{value}");
+    }
+    for value in [i32::MIN, -2, 0] {
+        assert!(DataCount::try_new(value).is_err(), "{value}");
+    }
+
+    for value in [0.0, 0.5, 1.0] {
+        assert!(DataRatio::try_new(value).is_ok(), "{value}");
+    }
+    for value in [-0.1, 1.000001, f64::NAN] {
+        assert!(DataRatio::try_new(value).is_err(), "{value}");
+    }
+
+    for value in [0, 9, 20, 29] {
+        assert!(DataBand::try_new(value).is_ok(), "{value}");
+    }
+    for value in [10, 19, 30, 255] {
+        assert!(DataBand::try_new(value).is_err(), "{value}");
+    }
+}
+
+#[test]
+fn checks_the_serialized_value() {
+    assert!(DataSlug::try_new("a-1".to_owned()).is_ok());
+    for value in ["", "a b", "a_b", "é"] {
+        assert!(DataSlug::try_new(value.to_owned()).is_err(), "{value:?}");
+    }
+
+    assert!(DataLabel::try_new(None).is_ok());
+    assert!(DataLabel::try_new(Some("x".to_owned())).is_ok());
+    assert!(DataLabel::try_new(Some(String::new())).is_err());
+}
+
+#[test]
+fn parsing_checks_constraints() {
+    assert_eq!("-1".parse::<DataCount>(), DataCount::try_new(-1));
+    assert!("0".parse::<DataCount>().is_err());
+    assert!("30".parse::<DataBand>().is_err());
+    assert!("a b".parse::<DataSlug>().is_err());
+}
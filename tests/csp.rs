@@ -0,0 +1,61 @@
+//! Nonces handed out by a `CspContext`, which must match its policy.
+
+use domatt::attributes::global::{Nonce, Title};
+use domatt::attributes::Attribute;
+use domatt::csp::{CspContext, CspNonce};
+
+fn nonce_of(attributes: &[Box<dyn Attribute>]) -> Vec<&str> {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.get_key() == "nonce")
+        .filter_map(|attribute| attribute.get_val())
+        .collect()
+}
+
+#[test]
+fn policy_and_attributes_share_the_nonce() {
+    let context = CspContext::new();
+    let attributes = context.attributes("script", Vec::new());
+    let nonce = nonce_of(&attributes);
+    assert_eq!(nonce.len(), 1);
+    assert_eq!(nonce[0], context.nonce().as_str());
+    assert_eq!(
+        context.policy(),
+        format!("script-src 'nonce-{0}'; style-src 'nonce-{0}'", nonce[0])
+    );
+
+    assert_ne!(CspContext::new().nonce(), context.nonce());
+}
+
+#[test]
+fn stale_nonces_are_replaced() {
+    let context = CspContext::with_nonce(CspNonce::new("Zm9vYmFy").unwrap());
+    for tag in ["script", "STYLE", "link"] {
+        let stale: Vec<Box<dyn Attribute>> = vec![
+            Box::new(Title::new("t".to_owned())),
+            Box::new(Nonce::new("c3RhbGU=".to_owned())),
+        ];
+        let attributes = context.attributes(tag, stale);
+        assert_eq!(nonce_of(&attributes), ["Zm9vYmFy"], "{tag}");
+        assert_eq!(attributes[0].get_key(), "title");
+    }
+}
+
+#[test]
+fn other_elements_keep_their_attributes() {
+    let context = CspContext::with_nonce(CspNonce::new("Zm9vYmFy").unwrap());
+    let attributes: Vec<Box<dyn Attribute>> = vec![Box::new(Nonce::new("b3du".to_owned()))];
+    let attributes = context.attributes("div", attributes);
+    assert_eq!(nonce_of(&attributes), ["b3du"]);
+    assert!(nonce_of(&context.attributes("img", Vec::new())).is_empty());
+}
+
+#[test]
+fn nonces_must_be_base64() {
+    for value in ["Zm9vYmFy", "Zm9vYg==", "a-b_c", "a+b/c="] {
+        assert!(CspNonce::new(value).is_ok(), "{value}");
+    }
+    for value in ["", "===", "Zm9vYg===", "foo bar", "'nonce'", "Zm9v;"] {
+        assert!(CspNonce::new(value).is_err(), "{value}");
+    }
+}
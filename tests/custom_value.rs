@@ -0,0 +1,75 @@
+//! Attributes defined outside of domatt, with a value type of their own.
+
+use domatt::attributes::{Attribute, AttributeValue};
+use domatt::values::{FromAttrValue, ToAttrValue, ValueError};
+use std::str::FromStr;
+
+/// A hex color such as `#ff8800`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rgb(u8, u8, u8);
+
+impl ToAttrValue for Rgb {
+    fn to_attr_value(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+impl FromAttrValue for Rgb {
+    fn from_attr_value(value: &str) -> Result<Self, ValueError> {
+        let invalid = || ValueError::new(value, "Invalid color.");
+        let hex = value.strip_prefix('#').filter(|hex| hex.len() == 6);
+        let hex = hex.ok_or_else(invalid)?;
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid());
+        Ok(Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+#[derive(Debug, Attribute)]
+#[attribute("kebab-case", Rgb)]
+struct DataAccent(String);
+
+#[derive(Debug, Copy, AttributeValue)]
+#[attribute_value(case = "lowercase")]
+enum Tone {
+    Light,
+    Dark,
+}
+
+#[derive(Debug, Attribute)]
+#[attribute("kebab-case", Tone)]
+struct DataTone(Tone);
+
+#[derive(Debug, Attribute)]
+#[attribute("kebab-case", Vec<Rgb>, non_empty)]
+struct DataPalette(String);
+
+#[test]
+fn serializes_custom_value() {
+    let accent = DataAccent::new(Rgb(255, 136, 0));
+    assert_eq!(accent.get_key(), "data-accent");
+    assert_eq!(accent.get_val(), Some("#ff8800"));
+    assert_eq!(accent.to_string(), r##"data-accent="#ff8800""##);
+}
+
+#[test]
+fn parses_custom_value() {
+    let accent = DataAccent::from_str("#0a0b0c").unwrap();
+    assert_eq!(accent, DataAccent::new(Rgb(10, 11, 12)));
+    assert!(DataAccent::from_str("orange").is_err());
+}
+
+#[test]
+fn holds_custom_keyword() {
+    let tone: DataTone = "DARK".parse().unwrap();
+    assert_eq!(tone.get_val(), Some("dark"));
+    assert_eq!(tone, DataTone::from(Tone::Dark));
+}
+
+#[test]
+fn checks_constraints_when_parsing() {
+    let palette = DataPalette::new(vec![Rgb(0, 0, 0), Rgb(255, 255, 255)]);
+    assert_eq!(palette.get_val(), Some("#000000 #ffffff"));
+    assert_eq!("#000000 #ffffff".parse::<DataPalette>(), Ok(palette));
+    assert!(DataPalette::try_new(Vec::new()).is_err());
+    assert!("".parse::<DataPalette>().is_err());
+}
@@ -0,0 +1,47 @@
+//! Option enums with a custom variant, which holds any value that isn't a keyword.
+
+use domatt::attributes::anchor::Target;
+use domatt::attributes::global::{AriaRole, Role};
+use domatt::attributes::{Attribute, TargetOption};
+use std::collections::HashSet;
+
+#[test]
+fn custom_values_serialize_their_payload() {
+    let target = Target::new(TargetOption::Custom("frame1".into()));
+    assert_eq!(target.get_val(), Some("frame1"));
+
+    let role = Role::new(AriaRole::Custom("doc-chapter".into()));
+    assert_eq!(role.get_val(), Some("doc-chapter"));
+    assert_eq!(
+        AriaRole::Custom("doc-chapter".into()).to_string(),
+        "doc-chapter"
+    );
+}
+
+#[test]
+fn keywords_are_parsed_before_custom_values() {
+    assert_eq!("_BLANK".parse::<TargetOption>(), Ok(TargetOption::Blank));
+    assert_eq!(
+        "frame1".parse::<TargetOption>(),
+        Ok(TargetOption::Custom("frame1".into()))
+    );
+    assert!(matches!("Button".parse::<AriaRole>(), Ok(AriaRole::Button)));
+    assert!("".parse::<TargetOption>().is_err());
+    assert!("".parse::<AriaRole>().is_err());
+}
+
+#[test]
+fn custom_values_spelling_a_keyword_equal_it() {
+    assert_eq!(TargetOption::Custom("_blank".into()), TargetOption::Blank);
+    assert_eq!(TargetOption::Blank, TargetOption::Custom("_Blank".into()));
+    assert_ne!(TargetOption::Custom("frame1".into()), TargetOption::Blank);
+    assert_ne!(
+        TargetOption::Custom("frame1".into()),
+        TargetOption::Custom("frame2".into())
+    );
+
+    let set: HashSet<TargetOption> = [TargetOption::Blank, TargetOption::Custom("_blank".into())]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 1);
+}
@@ -0,0 +1,66 @@
+//! The states of enumerated attributes when they are missing or have an invalid value.
+
+use domatt::attributes::aria::AriaInvalidOption;
+use domatt::attributes::global::{
+    ContentEditableOptions, DraggableOption, HiddenOption, SpellcheckOption,
+};
+use domatt::values::EnumeratedValue;
+
+#[test]
+fn hidden() {
+    let state = HiddenOption::from_attribute_value;
+    assert_eq!(state(Some("")), Some(HiddenOption::Hidden));
+    assert_eq!(state(Some("HIDDEN")), Some(HiddenOption::Hidden));
+    assert_eq!(state(Some("until-found")), Some(HiddenOption::UntilFound));
+    assert_eq!(state(Some("Until-Found")), Some(HiddenOption::UntilFound));
+    // Any other value still hides the element.
+    assert_eq!(state(Some("garbage")), Some(HiddenOption::Hidden));
+    assert_eq!(state(None), None);
+}
+
+#[test]
+fn content_editable() {
+    let state = ContentEditableOptions::from_attribute_value;
+    assert_eq!(state(Some("")), Some(ContentEditableOptions::True));
+    assert_eq!(state(Some("TRUE")), Some(ContentEditableOptions::True));
+    assert_eq!(state(Some("false")), Some(ContentEditableOptions::False));
+    assert_eq!(
+        state(Some("plaintext-only")),
+        Some(ContentEditableOptions::PlaintextOnly)
+    );
+    // The inherit state has no keyword.
+    assert_eq!(state(Some("inherit")), None);
+    assert_eq!(state(None), None);
+}
+
+#[test]
+fn draggable() {
+    let state = DraggableOption::from_attribute_value;
+    assert_eq!(state(Some("true")), Some(DraggableOption::True));
+    assert_eq!(state(Some("False")), Some(DraggableOption::False));
+    // The auto state has no keyword, and an empty value is not `true`.
+    assert_eq!(state(Some("")), None);
+    assert_eq!(state(Some("auto")), None);
+    assert_eq!(state(None), None);
+}
+
+#[test]
+fn spellcheck() {
+    let state = SpellcheckOption::from_attribute_value;
+    assert_eq!(state(Some("")), Some(SpellcheckOption::True));
+    assert_eq!(state(Some("false")), Some(SpellcheckOption::False));
+    assert_eq!(state(Some("no")), None);
+    assert_eq!(state(None), None);
+}
+
+#[test]
+fn aria_invalid() {
+    let state = AriaInvalidOption::from_attribute_value;
+    assert_eq!(state(None), Some(AriaInvalidOption::False));
+    assert_eq!(state(Some("")), Some(AriaInvalidOption::False));
+    assert_eq!(state(Some("false")), Some(AriaInvalidOption::False));
+    assert_eq!(state(Some("Grammar")), Some(AriaInvalidOption::Grammar));
+    assert_eq!(state(Some("spelling")), Some(AriaInvalidOption::Spelling));
+    // Unrecognized values are treated as `true`.
+    assert_eq!(state(Some("yes")), Some(AriaInvalidOption::True));
+}
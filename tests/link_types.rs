@@ -0,0 +1,29 @@
+//! Which `rel` link types each element accepts.
+
+use domatt::values::{LinkElement, LinkType, TokenList};
+
+fn rel(types: Vec<LinkType>) -> TokenList<LinkType> {
+    TokenList::try_from(types).unwrap()
+}
+
+#[test]
+fn validates_keywords_per_element() {
+    let stylesheet = rel(vec![LinkType::Stylesheet]);
+    assert!(LinkType::validate(&stylesheet, LinkElement::Link).is_ok());
+    assert!(LinkType::validate(&stylesheet, LinkElement::A).is_err());
+
+    let parsed: TokenList<LinkType> = "noopener STYLESHEET".parse().unwrap();
+    assert!(LinkType::validate(&parsed, LinkElement::A).is_err());
+}
+
+#[test]
+fn extensions_spelling_a_keyword_are_checked_as_it() {
+    let spelled = LinkType::Extension("stylesheet".to_owned());
+    assert!(!spelled.is_allowed_on(LinkElement::A));
+    assert!(spelled.is_external_resource(LinkElement::Link));
+    assert!(LinkType::validate(&rel(vec![spelled]), LinkElement::Area).is_err());
+
+    let unknown = LinkType::Extension("me".to_owned());
+    assert!(unknown.is_allowed_on(LinkElement::A));
+    assert_eq!(unknown.effect_on(LinkElement::A), None);
+}
@@ -0,0 +1,226 @@
+//! Items read back out of a tree with `microdata::extract`, using the examples of the
+//! microdata section of the HTML spec.
+//!
+//! <https://html.spec.whatwg.org/multipage/microdata.html>
+
+use domatt::attributes::global::{Id, ItemProp, ItemRef, ItemScope};
+use domatt::attributes::{anchor, audio, data, time, Attribute};
+use domatt::microdata::{extract, schema_org, ExtractedItem, Item, Node, PropertyValue};
+use domatt::values::{Href, TokenList};
+
+/// `content`, for `meta` elements, which have no module.
+#[derive(Debug, Attribute)]
+#[attribute("lowercase", String)]
+struct Content(String);
+
+fn node(tag: &str) -> Node {
+    Node::new(tag)
+}
+
+fn item(tag: &str) -> Node {
+    node(tag).attribute(&ItemScope)
+}
+
+fn prop(tag: &str, names: &str) -> Node {
+    node(tag).attribute(&names.parse::<ItemProp>().unwrap())
+}
+
+fn id(node: Node, id: &str) -> Node {
+    node.attribute(&Id::try_new(id.to_owned()).unwrap())
+}
+
+fn item_ref(node: Node, ids: &str) -> Node {
+    node.attribute(&ItemRef::new(ids.parse::<TokenList>().unwrap()))
+}
+
+fn text<'a>(item: &'a ExtractedItem, name: &'a str) -> Option<&'a str> {
+    item.property(name).and_then(PropertyValue::as_text)
+}
+
+fn names(item: &ExtractedItem) -> Vec<&str> {
+    item.properties()
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect()
+}
+
+#[test]
+fn top_level_items() {
+    let root = node("body")
+        .child(
+            item("div").child(
+                node("p")
+                    .text("My name is ")
+                    .child(prop("span", "name").text("Elizabeth")),
+            ),
+        )
+        .child(
+            item("div").child(
+                node("p")
+                    .text("My name is ")
+                    .child(prop("span", "name").text("Daniel")),
+            ),
+        );
+
+    let items = extract(&root);
+    assert_eq!(items.len(), 2);
+    assert_eq!(text(&items[0], "name"), Some("Elizabeth"));
+    assert_eq!(text(&items[1], "name"), Some("Daniel"));
+}
+
+#[test]
+fn item_refs_and_nested_items() {
+    let root = node("body")
+        .child(item_ref(id(item("div"), "amanda"), "a b"))
+        .child(
+            id(node("p"), "a")
+                .text("Name: ")
+                .child(prop("span", "name").text("Amanda")),
+        )
+        .child(item_ref(
+            id(prop("div", "band").attribute(&ItemScope), "b"),
+            "c",
+        ))
+        .child(
+            id(node("div"), "c")
+                .child(
+                    node("p")
+                        .text("Band: ")
+                        .child(prop("span", "name").text("Jazz Band")),
+                )
+                .child(
+                    node("p")
+                        .text("Size: ")
+                        .child(prop("span", "size").text("12")),
+                ),
+        );
+
+    let items = extract(&root);
+    assert_eq!(
+        items.len(),
+        1,
+        "the band is a property, not a top-level item"
+    );
+    let amanda = &items[0];
+    assert_eq!(names(amanda), ["name", "band"]);
+    assert_eq!(text(amanda, "name"), Some("Amanda"));
+
+    let band = amanda
+        .property("band")
+        .and_then(PropertyValue::as_item)
+        .unwrap();
+    assert_eq!(text(band, "name"), Some("Jazz Band"));
+    assert_eq!(text(band, "size"), Some("12"));
+}
+
+#[test]
+fn properties_are_in_tree_order() {
+    // The referenced element comes first in the tree although it is crawled last.
+    let root = node("body")
+        .child(id(node("p"), "before").child(prop("span", "first").text("1")))
+        .child(item_ref(item("div"), "before").child(prop("span", "second").text("2")));
+
+    let items = extract(&root);
+    assert_eq!(names(&items[0]), ["first", "second"]);
+}
+
+#[test]
+fn item_ref_cycles_are_errors() {
+    let root = node("body")
+        .child(item_ref(item("div"), "y"))
+        .child(item_ref(
+            id(prop("div", "partner").attribute(&ItemScope), "y"),
+            "z",
+        ))
+        .child(item_ref(
+            id(prop("div", "partner").attribute(&ItemScope), "z"),
+            "y",
+        ));
+
+    let items = extract(&root);
+    let y = items[0]
+        .property("partner")
+        .and_then(PropertyValue::as_item)
+        .unwrap();
+    let z = y
+        .property("partner")
+        .and_then(PropertyValue::as_item)
+        .unwrap();
+    assert_eq!(z.property("partner"), Some(&PropertyValue::Error));
+}
+
+#[test]
+fn values_come_from_the_element() {
+    let href: Href = "https://example.com/".parse().unwrap();
+    let root = item("div")
+        .child(prop("meta", "meta").attribute(&Content::new("from content".to_owned())))
+        .child(prop("audio", "audio").attribute(&audio::Src::new("song.mp3".parse().unwrap())))
+        .child(
+            prop("a", "a")
+                .attribute(&anchor::Href::new(href))
+                .text("text"),
+        )
+        .child(
+            prop("data", "data")
+                .attribute(&data::Value::new("9678AOU879".to_owned()))
+                .text("The Instigator 2000"),
+        )
+        .child(
+            prop("time", "time")
+                .attribute(&"2009-05-10".parse::<time::DateTime>().unwrap())
+                .text("May 10th 2009"),
+        )
+        .child(prop("time", "no-datetime").text("May 10th 2009"))
+        .child(prop("span", "favorite-color favorite-fruit").text("orange"));
+
+    let items = extract(&root);
+    let item = &items[0];
+    assert_eq!(text(item, "meta"), Some("from content"));
+    assert_eq!(text(item, "audio"), Some("song.mp3"));
+    assert_eq!(text(item, "a"), Some("https://example.com/"));
+    assert_eq!(text(item, "data"), Some("9678AOU879"));
+    assert_eq!(text(item, "time"), Some("2009-05-10"));
+    assert_eq!(text(item, "no-datetime"), Some("May 10th 2009"));
+    assert_eq!(text(item, "favorite-color"), Some("orange"));
+    assert_eq!(text(item, "favorite-fruit"), Some("orange"));
+}
+
+#[test]
+fn items_built_with_item_round_trip() {
+    let person = Item::from(schema_org::Type::Person)
+        .id("urn:isbn:0-330-34032-8".parse().unwrap())
+        .unwrap()
+        .item_ref("details")
+        .unwrap();
+    let attributes = person.attributes();
+    let keys: Vec<&str> = attributes
+        .iter()
+        .map(|attribute| attribute.get_key())
+        .collect();
+    assert_eq!(keys, ["itemscope", "itemtype", "itemid", "itemref"]);
+
+    let root = node("body")
+        .child(node("div").attributes(&attributes))
+        .child(
+            id(node("p"), "details").child(
+                node("span")
+                    .attribute(&ItemProp::from(schema_org::NAME))
+                    .text("Jane"),
+            ),
+        );
+
+    let items = extract(&root);
+    assert_eq!(items[0].item_types(), ["https://schema.org/Person"]);
+    assert_eq!(items[0].id(), Some("urn:isbn:0-330-34032-8"));
+    assert_eq!(text(&items[0], "name"), Some("Jane"));
+}
+
+#[test]
+fn item_rules() {
+    assert!(Item::typed("Person").is_err());
+    assert!(Item::new().id("urn:x".parse().unwrap()).is_err());
+
+    let typed = Item::typed("https://schema.org/Person").unwrap();
+    assert!(typed.item_type().is_some());
+    assert!(typed.item_refs().is_none());
+}
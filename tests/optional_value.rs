@@ -0,0 +1,29 @@
+//! Attributes whose value may be left out, such as `download`.
+
+use domatt::attributes::anchor::Download;
+use domatt::attributes::{render_attribute, Attribute};
+
+#[test]
+fn none_is_a_bare_attribute() {
+    let download = Download::new(None);
+    assert_eq!(download.get_val(), None);
+    assert_eq!(render_attribute(&download), "download");
+}
+
+#[test]
+fn some_is_the_value() {
+    let download = Download::new(Some("report.pdf".to_owned()));
+    assert_eq!(download.get_val(), Some("report.pdf"));
+    assert_eq!(download.to_string(), r#"download="report.pdf""#);
+}
+
+#[test]
+fn parsing_round_trips() {
+    for download in [
+        Download::new(None),
+        Download::new(Some("report.pdf".to_owned())),
+    ] {
+        let value = download.get_val().unwrap_or_default();
+        assert_eq!(value.parse::<Download>(), Ok(download));
+    }
+}
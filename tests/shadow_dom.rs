@@ -0,0 +1,38 @@
+//! Declarative shadow DOM: the attributes of `template` and `slot` elements.
+
+use domatt::attributes::slot::Name;
+use domatt::attributes::template::{
+    ShadowRootDelegatesFocus, ShadowRootMode, ShadowRootModeOption,
+};
+use domatt::attributes::{render_attribute, render_attributes, Attribute};
+use domatt::values::EnumeratedValue;
+
+#[test]
+fn renders_a_shadow_root_template() {
+    let attributes: Vec<Box<dyn Attribute>> = vec![
+        Box::new(ShadowRootMode::new(ShadowRootModeOption::Open)),
+        Box::new(ShadowRootDelegatesFocus),
+    ];
+    assert_eq!(
+        render_attributes(&attributes),
+        r#"shadowrootmode="open" shadowrootdelegatesfocus"#
+    );
+}
+
+#[test]
+fn shadow_root_mode_states() {
+    let state = ShadowRootModeOption::from_attribute_value;
+    assert_eq!(state(Some("OPEN")), Some(ShadowRootModeOption::Open));
+    assert_eq!(state(Some("Closed")), Some(ShadowRootModeOption::Closed));
+    // Without a valid mode, the template stays a plain template.
+    assert_eq!(state(Some("")), None);
+    assert_eq!(state(Some("opened")), None);
+    assert_eq!(state(None), None);
+    assert!("open".parse::<ShadowRootMode>().is_ok());
+}
+
+#[test]
+fn renders_a_named_slot() {
+    let name = Name::new("header".to_owned());
+    assert_eq!(render_attribute(&name), r#"name="header""#);
+}
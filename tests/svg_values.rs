@@ -0,0 +1,68 @@
+//! SVG presentation attributes that take keywords as well as a number or length.
+
+use domatt::attributes::svg::{
+    BaseFrequency, BaselineShift, Dx, FontSize, FontSizeKeyword, KernelMatrix, LetterSpacing,
+    Points, StrokeDashoffset, ViewBox, X, Y,
+};
+use domatt::attributes::Attribute;
+use domatt::values::{
+    KeywordOr, Length, LengthPercentageList, Number, NumberOptionalNumber, Percentage,
+};
+
+#[test]
+fn number_optional_number() {
+    let frequency: BaseFrequency = "0.01 0.02".parse().unwrap();
+    assert_eq!(frequency.get_val(), Some("0.01 0.02"));
+    assert!("0.01,0.02".parse::<BaseFrequency>().is_ok());
+    assert!("0.01".parse::<BaseFrequency>().is_ok());
+    assert!("0.01 0.02 0.03".parse::<BaseFrequency>().is_err());
+
+    let value: NumberOptionalNumber = "3".parse().unwrap();
+    assert_eq!(value.second(), Number::from(3));
+}
+
+#[test]
+fn keywords_or_values() {
+    for value in ["sub", "super", "baseline", "-3px", "20%"] {
+        assert!(value.parse::<BaselineShift>().is_ok(), "{value}");
+    }
+    assert!("normal".parse::<LetterSpacing>().is_ok());
+    assert!("auto".parse::<LetterSpacing>().is_err());
+
+    let size = FontSize::new(KeywordOr::Keyword(FontSizeKeyword::XxLarge));
+    assert_eq!(size.get_val(), Some("xx-large"));
+    assert!("1.5em".parse::<FontSize>().is_ok());
+}
+
+#[test]
+fn per_glyph_positions() {
+    let x: X = "10 20,30 , 40%".parse().unwrap();
+    assert_eq!(x.get_val(), Some("10 20 30 40%"));
+    assert!("5px".parse::<Y>().is_ok());
+    assert!("calc(100% - 2em) 3".parse::<Dx>().is_ok());
+    for value in ["", "10,", ",10", "10,,20", "10 auto", "calc(1px)2px"] {
+        assert!(value.parse::<X>().is_err(), "{value:?}");
+    }
+
+    let mut positions = LengthPercentageList::from(Length::px(1.0).unwrap());
+    positions.push(Percentage::new(50.0).unwrap().into());
+    assert_eq!(X::new(positions).get_val(), Some("1px 50%"));
+}
+
+#[test]
+fn number_lists_and_view_boxes() {
+    let points: Points = "0,0 10,0 10,10".parse().unwrap();
+    assert_eq!(points.get_val(), Some("0 0 10 0 10 10"));
+    assert!("1 0 0 0 1 0 0 0 1".parse::<KernelMatrix>().is_ok());
+    assert!("".parse::<Points>().is_err());
+
+    let view_box: ViewBox = "0 0 100 50".parse().unwrap();
+    assert_eq!(view_box.get_val(), Some("0 0 100 50"));
+    assert!("0 0 100".parse::<ViewBox>().is_err());
+    assert!("0 0 -100 50".parse::<ViewBox>().is_err());
+
+    for value in ["5", "2px", "10%"] {
+        assert!(value.parse::<StrokeDashoffset>().is_ok(), "{value}");
+    }
+    assert!("dashed".parse::<StrokeDashoffset>().is_err());
+}